@@ -0,0 +1,102 @@
+//! NumPy array support: format every element of an array in one call.
+//!
+//! Each entry point converts the array to a fixed dtype with `astype`, copies
+//! it into an owned ndarray, formats it with the GIL released, and hands back
+//! an object array of `str` with the same shape.
+
+use numpy::ndarray::ArrayD;
+use numpy::{
+    Element, PyArray, PyArrayDescrMethods, PyArrayDyn, PyArrayMethods, PyUntypedArray,
+    PyUntypedArrayMethods,
+};
+use pyo3::prelude::*;
+use pyo3::types::PyString;
+
+/// Return `obj` as a NumPy array, if it is one.
+///
+/// Checks `sys.modules` first so that NumPy is never imported on behalf of
+/// callers who don't use it (an ndarray can't exist unless NumPy is loaded).
+pub(crate) fn as_ndarray<'a, 'py>(
+    obj: &'a Bound<'py, PyAny>,
+) -> PyResult<Option<&'a Bound<'py, PyUntypedArray>>> {
+    let modules = obj.py().import("sys")?.getattr("modules")?;
+    if !modules.contains("numpy")? {
+        return Ok(None);
+    }
+    Ok(obj.cast::<PyUntypedArray>().ok())
+}
+
+/// Convert `array` to `dtype`, format each element with `f`, and return an
+/// object array of strings with the original shape.
+fn map_array<'py, T, F>(
+    array: &Bound<'py, PyUntypedArray>,
+    dtype: &str,
+    f: F,
+) -> PyResult<Bound<'py, PyAny>>
+where
+    T: Element + Copy + Send + Sync,
+    F: Fn(T) -> String + Send + Sync,
+{
+    let py = array.py();
+    let values: ArrayD<T> = array
+        .call_method1("astype", (dtype,))?
+        .cast_into::<PyArrayDyn<T>>()?
+        .to_owned_array();
+    let strings = py.detach(|| values.map(|v| f(*v)));
+    let objects = strings.map(|s| PyString::new(py, s).into_any().unbind());
+    Ok(PyArray::from_owned_object_array(py, objects).into_any())
+}
+
+/// Vectorized `naturalsize`.
+pub(crate) fn naturalsize<'py>(
+    array: &Bound<'py, PyUntypedArray>,
+    binary: bool,
+    gnu: bool,
+    format: &str,
+) -> PyResult<Bound<'py, PyAny>> {
+    map_array(array, "float64", |v: f64| {
+        speakhuman::naturalsize(v, binary, gnu, format)
+    })
+}
+
+/// Vectorized `intcomma`. Integer arrays are formatted exactly; everything
+/// else goes through float64.
+pub(crate) fn intcomma<'py>(
+    array: &Bound<'py, PyUntypedArray>,
+    ndigits: Option<usize>,
+) -> PyResult<Bound<'py, PyAny>> {
+    match array.dtype().kind() {
+        b'i' | b'b' => map_array(array, "int64", |v: i64| {
+            speakhuman::intcomma(&v.to_string(), ndigits)
+        }),
+        b'u' => map_array(array, "uint64", |v: u64| {
+            speakhuman::intcomma(&v.to_string(), ndigits)
+        }),
+        _ => map_array(array, "float64", |v: f64| {
+            speakhuman::intcomma(&v.to_string(), ndigits)
+        }),
+    }
+}
+
+/// Vectorized `naturaldelta`. `timedelta64` arrays are read as whole
+/// microseconds (NaT becomes "NaT"); numeric arrays are seconds.
+pub(crate) fn naturaldelta<'py>(
+    array: &Bound<'py, PyUntypedArray>,
+    months: bool,
+    minimum_unit: &str,
+) -> PyResult<Bound<'py, PyAny>> {
+    if array.dtype().kind() == b'm' {
+        let micros = array.call_method1("astype", ("timedelta64[us]",))?;
+        let micros = micros.cast::<PyUntypedArray>()?;
+        return map_array(micros, "int64", |us: i64| {
+            if us == i64::MIN {
+                return "NaT".to_string();
+            }
+            let td = speakhuman::time::TimeDelta::from_days_seconds_micros(0, 0, us);
+            speakhuman::time::naturaldelta_td(td, months, minimum_unit)
+        });
+    }
+    map_array(array, "float64", |secs: f64| {
+        speakhuman::naturaldelta(secs, months, minimum_unit)
+    })
+}
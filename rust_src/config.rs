@@ -0,0 +1,234 @@
+//! `Config`: a reusable set of humanizing options.
+//!
+//! Methods call the native formatters directly when the effective locale is
+//! English. Otherwise they go through the Python package with the configured
+//! locale activated, so translations apply exactly as for the module
+//! functions.
+
+use pyo3::exceptions::{PyOverflowError, PyTypeError};
+use pyo3::prelude::*;
+use pyo3::sync::PyOnceLock;
+use pyo3::IntoPyObjectExt;
+
+static IS_ENGLISH_LOCALE: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+static CALL_WITH_LOCALE: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+
+/// Default options for the humanizing functions, applied on every call.
+#[pyclass(module = "speakhuman._speakhuman_rs")]
+pub(crate) struct Config {
+    #[pyo3(get, set)]
+    size_format: String,
+    #[pyo3(get, set)]
+    binary: bool,
+    #[pyo3(get, set)]
+    gnu: bool,
+    #[pyo3(get, set)]
+    intword_format: String,
+    #[pyo3(get, set)]
+    delta_format: String,
+    #[pyo3(get, set)]
+    months: bool,
+    #[pyo3(get, set)]
+    minimum_unit: String,
+    #[pyo3(get, set)]
+    locale: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            size_format: "%.1f".to_string(),
+            binary: false,
+            gnu: false,
+            intword_format: "%.1f".to_string(),
+            delta_format: "%0.2f".to_string(),
+            months: true,
+            minimum_unit: "seconds".to_string(),
+            locale: None,
+        }
+    }
+}
+
+impl Config {
+    /// Whether the native formatters can be used for this call.
+    fn is_english(&self, py: Python<'_>) -> PyResult<bool> {
+        match &self.locale {
+            Some(locale) => Ok(locale.starts_with("en")),
+            None => IS_ENGLISH_LOCALE
+                .import(py, "speakhuman.number", "_is_english_locale")?
+                .call0()?
+                .extract(),
+        }
+    }
+
+    /// Call the package-level function `name` with the configured locale.
+    fn call_python<'py>(
+        &self,
+        py: Python<'py>,
+        name: &str,
+        args: impl IntoPyObject<'py>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        CALL_WITH_LOCALE
+            .import(py, "speakhuman.config", "_call")?
+            .call1((self.locale.as_deref(), name, args))
+    }
+}
+
+#[pymethods]
+impl Config {
+    #[new]
+    #[pyo3(signature = (
+        *,
+        size_format="%.1f".to_string(),
+        binary=false,
+        gnu=false,
+        intword_format="%.1f".to_string(),
+        delta_format="%0.2f".to_string(),
+        months=true,
+        minimum_unit="seconds".to_string(),
+        locale=None,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        size_format: String,
+        binary: bool,
+        gnu: bool,
+        intword_format: String,
+        delta_format: String,
+        months: bool,
+        minimum_unit: String,
+        locale: Option<String>,
+    ) -> Self {
+        Self {
+            size_format,
+            binary,
+            gnu,
+            intword_format,
+            delta_format,
+            months,
+            minimum_unit,
+            locale,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        let py_bool = |b: bool| if b { "True" } else { "False" };
+        format!(
+            "Config(size_format='{}', binary={}, gnu={}, intword_format='{}', \
+             delta_format='{}', months={}, minimum_unit='{}', locale={})",
+            self.size_format,
+            py_bool(self.binary),
+            py_bool(self.gnu),
+            self.intword_format,
+            self.delta_format,
+            py_bool(self.months),
+            self.minimum_unit,
+            match &self.locale {
+                Some(locale) => format!("'{}'", locale),
+                None => "None".to_string(),
+            },
+        )
+    }
+
+    /// Like `naturalsize`, using the configured options.
+    pub(crate) fn naturalsize<'py>(
+        &self,
+        value: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        crate::naturalsize(value, self.binary, self.gnu, &self.size_format)
+    }
+
+    /// Like `intcomma`, using the configured locale.
+    #[pyo3(signature = (value, ndigits=None))]
+    pub(crate) fn intcomma<'py>(
+        &self,
+        value: &Bound<'py, PyAny>,
+        ndigits: Option<usize>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = value.py();
+        if !self.is_english(py)? {
+            return self.call_python(py, "intcomma", (value, ndigits));
+        }
+        crate::intcomma(value, ndigits)
+    }
+
+    /// Like `intword`, using the configured options.
+    pub(crate) fn intword<'py>(&self, value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let py = value.py();
+        if !self.is_english(py)? {
+            return self.call_python(py, "intword", (value, &self.intword_format));
+        }
+        crate::intword(value, &self.intword_format)?.into_bound_py_any(py)
+    }
+
+    /// Like `ordinal`, using the configured locale.
+    #[pyo3(signature = (value, gender="male"))]
+    pub(crate) fn ordinal<'py>(
+        &self,
+        value: &Bound<'py, PyAny>,
+        gender: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = value.py();
+        if !self.is_english(py)? {
+            return self.call_python(py, "ordinal", (value, gender));
+        }
+        crate::ordinal(value, gender)?.into_bound_py_any(py)
+    }
+
+    /// Like `naturaldelta`, using the configured options.
+    pub(crate) fn naturaldelta<'py>(
+        &self,
+        value: &Bound<'py, PyAny>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = value.py();
+        let args = (value, self.months, &self.minimum_unit);
+        if !self.is_english(py)? {
+            return self.call_python(py, "naturaldelta", args);
+        }
+        // Unsupported values take the Python path, as in the module function
+        match crate::naturaldelta(value, self.months, &self.minimum_unit) {
+            Err(e)
+                if e.is_instance_of::<PyTypeError>(py)
+                    || e.is_instance_of::<PyOverflowError>(py) =>
+            {
+                self.call_python(py, "naturaldelta", args)
+            }
+            result => result,
+        }
+    }
+
+    /// Like `naturaltime`, using the configured options.
+    ///
+    /// Always goes through Python, like the module function, so that the
+    /// current time can be mocked.
+    #[pyo3(signature = (value, future=false, when=None))]
+    pub(crate) fn naturaltime<'py>(
+        &self,
+        value: &Bound<'py, PyAny>,
+        future: bool,
+        when: Option<&Bound<'py, PyAny>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.call_python(
+            value.py(),
+            "naturaltime",
+            (value, future, self.months, &self.minimum_unit, when),
+        )
+    }
+
+    /// Like `precisedelta`, using the configured options.
+    ///
+    /// Goes through the module function, which owns the fallbacks for
+    /// `suppress`.
+    #[pyo3(signature = (value, suppress=Vec::new()))]
+    pub(crate) fn precisedelta<'py>(
+        &self,
+        value: &Bound<'py, PyAny>,
+        suppress: Vec<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.call_python(
+            value.py(),
+            "precisedelta",
+            (value, &self.minimum_unit, suppress, &self.delta_format),
+        )
+    }
+}
@@ -0,0 +1,51 @@
+//! `Human`: a value wrapper whose `__format__` humanizes it inline, e.g.
+//! `f"{Human(n):intcomma}"`.
+//!
+//! Format specs name a formatter and use its default options; anything else
+//! is handed to the wrapped value's own `__format__`.
+
+use pyo3::prelude::*;
+use pyo3::IntoPyObjectExt;
+
+use crate::config::Config;
+
+/// Wrap a value so that f-strings can humanize it with a format spec.
+#[pyclass(module = "speakhuman._speakhuman_rs", frozen)]
+pub(crate) struct Human {
+    #[pyo3(get)]
+    value: Py<PyAny>,
+}
+
+#[pymethods]
+impl Human {
+    #[new]
+    fn new(value: Py<PyAny>) -> Self {
+        Self { value }
+    }
+
+    fn __format__<'py>(&self, py: Python<'py>, spec: &str) -> PyResult<Bound<'py, PyAny>> {
+        let value = self.value.bind(py);
+        let config = Config::default();
+        match spec {
+            "naturalsize" => config.naturalsize(value),
+            "intcomma" => config.intcomma(value, None),
+            "intword" => config.intword(value),
+            "ordinal" => config.ordinal(value, "male"),
+            "naturaldelta" => config.naturaldelta(value),
+            "naturaltime" => config.naturaltime(value, false, None),
+            "precisedelta" => config.precisedelta(value, Vec::new()),
+            "fractional" => crate::fractional(value)?.into_bound_py_any(py),
+            "scientific" => crate::scientific(value, 2, "unicode", false)?.into_bound_py_any(py),
+            "metric" => crate::metric(value.extract()?, "", 3, None, false)?.into_bound_py_any(py),
+            _ => value.call_method1("__format__", (spec,)),
+        }
+    }
+
+    fn __str__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(self.value.bind(py).str()?.to_string())
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(format!("Human({})", self.value.bind(py).repr()?))
+    }
+}
@@ -1,13 +1,26 @@
-use chrono::NaiveDate;
+#[cfg(feature = "numpy")]
+mod arrays;
+mod config;
+mod human;
+
+use chrono::{Local, NaiveDate, NaiveDateTime, TimeZone};
 use pyo3::prelude::*;
-use pyo3::types::{PyDelta, PyDeltaAccess, PyList};
+use pyo3::sync::PyOnceLock;
+use pyo3::types::{
+    PyBool, PyDateAccess, PyDateTime, PyDelta, PyDeltaAccess, PyFloat, PyInt, PyList, PyString,
+    PyTimeAccess, PyType,
+};
+
+static DECIMAL_TYPE: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+static FRACTION_TYPE: PyOnceLock<Py<PyType>> = PyOnceLock::new();
 
 // ---------------------------------------------------------------------------
-// Helper: extract total_seconds from a Python timedelta or float
+// Helper: extract a TimeDelta from a Python timedelta, numpy.timedelta64,
+// anything with total_seconds(), or a number of seconds
 // ---------------------------------------------------------------------------
 fn extract_timedelta_or_float(obj: &Bound<'_, PyAny>) -> PyResult<speakhuman::time::TimeDelta> {
     // Try timedelta first
-    if let Ok(delta) = obj.downcast::<PyDelta>() {
+    if let Ok(delta) = obj.cast::<PyDelta>() {
         let days = delta.get_days() as i64;
         let seconds = delta.get_seconds() as i64;
         let microseconds = delta.get_microseconds() as i64;
@@ -17,15 +30,50 @@ fn extract_timedelta_or_float(obj: &Bound<'_, PyAny>) -> PyResult<speakhuman::ti
             microseconds,
         ));
     }
+    // numpy.timedelta64 converts to float as a count of its own unit, so read
+    // it as nanoseconds instead
+    if obj.get_type().fully_qualified_name()? == "numpy.timedelta64" {
+        let nanos: i64 = obj
+            .call_method1("astype", ("timedelta64[ns]",))?
+            .call_method1("astype", ("int64",))?
+            .extract()?;
+        if nanos == i64::MIN {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "Cannot humanize NaT",
+            ));
+        }
+        return Ok(speakhuman::time::TimeDelta::from_days_seconds_micros(
+            0,
+            0,
+            nanos.div_euclid(1000),
+        ));
+    }
+    // Other duration types (pandas.Timedelta subclasses timedelta and is
+    // handled above) usually offer total_seconds()
+    if obj.hasattr("total_seconds")? {
+        let secs: f64 = obj.call_method0("total_seconds")?.extract()?;
+        return seconds_to_timedelta(secs);
+    }
     // Fall back to numeric (float/int)
     let secs: f64 = obj.extract()?;
-    Ok(speakhuman::time::TimeDelta::from_seconds(secs))
+    seconds_to_timedelta(secs)
+}
+
+/// Seconds as a TimeDelta, raising OverflowError for NaN and durations out
+/// of range so the Python wrappers fall back to the pure-Python code.
+fn seconds_to_timedelta(secs: f64) -> PyResult<speakhuman::time::TimeDelta> {
+    speakhuman::time::TimeDelta::try_from_seconds(secs)
+        .map_err(|e| pyo3::exceptions::PyOverflowError::new_err(e.to_string()))
 }
 
 // ---------------------------------------------------------------------------
 // Helper: extract a NaiveDate from a Python date/datetime
 // ---------------------------------------------------------------------------
 fn extract_date(obj: &Bound<'_, PyAny>) -> PyResult<NaiveDate> {
+    // Aware datetimes name the local calendar day of the same instant
+    if let Some(local) = extract_aware_datetime(obj)? {
+        return Ok(local.date());
+    }
     // Access .year, .month, .day attributes (works for date and datetime)
     let year: i32 = obj.getattr("year")?.extract()?;
     let month: u32 = obj.getattr("month")?.extract()?;
@@ -38,19 +86,189 @@ fn extract_date(obj: &Bound<'_, PyAny>) -> PyResult<NaiveDate> {
     })
 }
 
+// ---------------------------------------------------------------------------
+// Helper: datetimes as naive local time (tz-aware ones converted via utcoffset)
+// ---------------------------------------------------------------------------
+fn extract_naive_datetime(dt: &Bound<'_, PyDateTime>) -> PyResult<NaiveDateTime> {
+    NaiveDate::from_ymd_opt(dt.get_year(), dt.get_month().into(), dt.get_day().into())
+        .and_then(|date| {
+            date.and_hms_micro_opt(
+                dt.get_hour().into(),
+                dt.get_minute().into(),
+                dt.get_second().into(),
+                dt.get_microsecond(),
+            )
+        })
+        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("Invalid datetime"))
+}
+
+/// If `obj` is a datetime with a UTC offset, return the same instant as a
+/// naive local datetime (like `datetime.fromtimestamp(value.timestamp())`).
+fn extract_aware_datetime(obj: &Bound<'_, PyAny>) -> PyResult<Option<NaiveDateTime>> {
+    let Ok(dt) = obj.cast::<PyDateTime>() else {
+        return Ok(None);
+    };
+    let offset = dt.call_method0("utcoffset")?;
+    if offset.is_none() {
+        return Ok(None);
+    }
+    let offset = extract_timedelta_or_float(&offset)?;
+    let utc = extract_naive_datetime(dt)? - chrono::TimeDelta::microseconds(offset_micros(offset));
+    Ok(Some(Local.from_utc_datetime(&utc).naive_local()))
+}
+
+/// A datetime as naive local time, converting aware values.
+fn extract_local_datetime(dt: &Bound<'_, PyDateTime>) -> PyResult<NaiveDateTime> {
+    match extract_aware_datetime(dt.as_any())? {
+        Some(local) => Ok(local),
+        None => extract_naive_datetime(dt),
+    }
+}
+
+fn offset_micros(td: speakhuman::time::TimeDelta) -> i64 {
+    (td.days * 86_400 + td.seconds) * 1_000_000 + td.microseconds
+}
+
+// ---------------------------------------------------------------------------
+// Helper: the string handed to the &str-based number formatters
+// ---------------------------------------------------------------------------
+/// Python ints become their exact digits (via i128 when they fit, otherwise
+/// `str()`), which the core formats without going through f64. Bools are left
+/// to `str()` so they keep printing as "True"/"False".
+fn extract_number_str(value: &Bound<'_, PyAny>) -> PyResult<String> {
+    if value.is_instance_of::<PyInt>() && !value.is_instance_of::<PyBool>() {
+        if let Ok(i) = value.extract::<i128>() {
+            return Ok(i.to_string());
+        }
+    }
+    Ok(value.str()?.to_string())
+}
+
+// ---------------------------------------------------------------------------
+// Helper: exact representations of decimal.Decimal and fractions.Fraction
+// ---------------------------------------------------------------------------
+/// Ints and floats, by far the most common input, are checked first so they
+/// skip the Decimal and Fraction lookups.
+fn is_builtin_number(obj: &Bound<'_, PyAny>) -> bool {
+    obj.is_instance_of::<PyInt>() || obj.is_instance_of::<PyFloat>()
+}
+
+fn is_decimal(obj: &Bound<'_, PyAny>) -> PyResult<bool> {
+    obj.is_instance(DECIMAL_TYPE.import(obj.py(), "decimal", "Decimal")?)
+}
+
+fn is_fraction(obj: &Bound<'_, PyAny>) -> PyResult<bool> {
+    obj.is_instance(FRACTION_TYPE.import(obj.py(), "fractions", "Fraction")?)
+}
+
+/// Plain decimal digits for a Decimal (or a Fraction, divided out in the
+/// current decimal context), so the Rust side never rounds through f64.
+fn extract_exact_decimal(obj: &Bound<'_, PyAny>) -> PyResult<Option<String>> {
+    if is_builtin_number(obj) {
+        return Ok(None);
+    }
+    let decimal = if is_decimal(obj)? {
+        obj.clone()
+    } else if is_fraction(obj)? {
+        let decimal_type = DECIMAL_TYPE.import(obj.py(), "decimal", "Decimal")?;
+        let numerator = decimal_type.call1((obj.getattr("numerator")?,))?;
+        numerator.div(obj.getattr("denominator")?)?
+    } else {
+        return Ok(None);
+    };
+    Ok(Some(decimal.call_method1("__format__", ("f",))?.extract()?))
+}
+
+/// Numerator and denominator of a finite Decimal or Fraction, if they fit in i128.
+fn extract_exact_ratio(obj: &Bound<'_, PyAny>) -> PyResult<Option<(i128, i128)>> {
+    if is_builtin_number(obj) || (!is_decimal(obj)? && !is_fraction(obj)?) {
+        return Ok(None);
+    }
+    // Non-finite Decimals raise here; the caller's str() path handles them.
+    Ok(obj
+        .call_method0("as_integer_ratio")
+        .and_then(|ratio| ratio.extract())
+        .ok())
+}
+
+// ---------------------------------------------------------------------------
+// Helpers: raise for bad unit arguments with the same exceptions as the
+// Python version, instead of returning the message as the result
+// ---------------------------------------------------------------------------
+
+/// Unknown units raise KeyError, like `Unit[name.upper()]`; the others
+/// ValueError.
+fn time_error(e: speakhuman::time::TimeError) -> PyErr {
+    use speakhuman::time::TimeError;
+    match e {
+        TimeError::UnknownUnit(name) => pyo3::exceptions::PyKeyError::new_err(name.to_uppercase()),
+        TimeError::OutOfRange => pyo3::exceptions::PyOverflowError::new_err(e.to_string()),
+        _ => pyo3::exceptions::PyValueError::new_err(e.to_string()),
+    }
+}
+
+/// Python's `Unit` has no weeks and only takes full names, in any case, so
+/// raise the `KeyError` that `Unit[name.upper()]` would for anything else.
+fn check_python_unit(name: &str) -> PyResult<()> {
+    use speakhuman::time::Unit;
+    let upper = name.to_uppercase();
+    let known = Unit::all()
+        .iter()
+        .any(|unit| *unit != Unit::Weeks && unit.name().to_uppercase() == upper);
+    if known {
+        Ok(())
+    } else {
+        Err(pyo3::exceptions::PyKeyError::new_err(upper))
+    }
+}
+
+/// For entry points that format many deltas: raise before doing any work
+/// if naturaldelta would reject `minimum_unit`.
+fn check_naturaldelta_unit(minimum_unit: &str) -> PyResult<()> {
+    check_python_unit(minimum_unit)?;
+    let zero = speakhuman::time::TimeDelta::from_seconds(0.0);
+    speakhuman::time::try_naturaldelta_td(zero, true, minimum_unit)
+        .map(drop)
+        .map_err(time_error)
+}
+
+// ---------------------------------------------------------------------------
+// GIL handling: entry points that take sequences extract their inputs into
+// Rust buffers first and then format inside `py.detach`, so long loops
+// don't hold the GIL. The formatters only touch owned data and i18n state
+// that is either thread-local or behind a lock, so this is sound. For the
+// same reason the module is declared safe for free-threaded CPython.
+// ---------------------------------------------------------------------------
+
+/// Extract every item of `values` up front, then format them all with the GIL
+/// released.
+fn format_many<T, E, F>(values: &Bound<'_, PyAny>, extract: E, format: F) -> PyResult<Vec<String>>
+where
+    T: Send,
+    E: Fn(&Bound<'_, PyAny>) -> PyResult<T>,
+    F: Fn(T) -> String + Send + Sync,
+{
+    let inputs: Vec<T> = values
+        .try_iter()?
+        .map(|item| extract(&item?))
+        .collect::<PyResult<_>>()?;
+    let py = values.py();
+    Ok(py.detach(|| inputs.into_iter().map(format).collect()))
+}
+
 // ===========================================================================
 // Lists
 // ===========================================================================
 
 /// Convert a list of items into a human-readable string with commas and 'and'.
 #[pyfunction]
-fn natural_list(items: &Bound<'_, PyList>) -> PyResult<String> {
+fn natural_list(py: Python<'_>, items: &Bound<'_, PyList>) -> PyResult<String> {
     let strs: Vec<String> = items
         .iter()
         .map(|item| item.str().map(|s| s.to_string()))
         .collect::<PyResult<_>>()?;
-    let refs: Vec<&str> = strs.iter().map(|s| s.as_str()).collect();
-    Ok(speakhuman::natural_list(&refs))
+    // Everything is owned Rust data from here on, so let other threads run.
+    Ok(py.detach(|| speakhuman::natural_list(&strs)))
 }
 
 // ===========================================================================
@@ -60,18 +278,48 @@ fn natural_list(items: &Bound<'_, PyList>) -> PyResult<String> {
 /// Format a number of bytes like a human-readable filesize (e.g. 10 kB).
 #[pyfunction]
 #[pyo3(signature = (value, binary=false, gnu=false, format="%.1f"))]
-fn naturalsize(
-    value: &Bound<'_, PyAny>,
+fn naturalsize<'py>(
+    value: &Bound<'py, PyAny>,
     binary: bool,
     gnu: bool,
     format: &str,
-) -> PyResult<String> {
-    let bytes: f64 = value.extract().or_else(|_| {
+) -> PyResult<Bound<'py, PyAny>> {
+    #[cfg(feature = "numpy")]
+    if let Some(array) = arrays::as_ndarray(value)? {
+        return arrays::naturalsize(array, binary, gnu, format);
+    }
+    let bytes = extract_bytes(value)?;
+    let formatted = speakhuman::naturalsize(bytes, binary, gnu, format);
+    Ok(PyString::new(value.py(), &formatted).into_any())
+}
+
+/// Format every value in an iterable with naturalsize, returning a list.
+#[pyfunction]
+#[pyo3(signature = (values, binary=false, gnu=false, format="%.1f"))]
+fn naturalsize_many(
+    values: &Bound<'_, PyAny>,
+    binary: bool,
+    gnu: bool,
+    format: &str,
+) -> PyResult<Vec<String>> {
+    format_many(values, extract_bytes, |bytes| {
+        speakhuman::naturalsize(bytes, binary, gnu, format)
+    })
+}
+
+/// Parse a human-readable filesize (e.g. "1.5 GiB") into a number of bytes.
+#[pyfunction]
+fn naturalsize_parse(value: &str) -> PyResult<i128> {
+    speakhuman::parse_size(value)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+}
+
+fn extract_bytes(value: &Bound<'_, PyAny>) -> PyResult<f64> {
+    value.extract().or_else(|_| {
         let s: String = value.extract()?;
         s.parse::<f64>()
             .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
-    })?;
-    Ok(speakhuman::naturalsize(bytes, binary, gnu, format))
+    })
 }
 
 // ===========================================================================
@@ -79,25 +327,76 @@ fn naturalsize(
 // ===========================================================================
 
 /// Return number in string scientific notation z.wq x 10ⁿ.
+///
+/// `style` is "unicode", "ascii", "latex" or "html"; `engineering` keeps the
+/// exponent a multiple of three.
 #[pyfunction]
-#[pyo3(signature = (value, precision=2))]
-fn scientific(value: &Bound<'_, PyAny>, precision: usize) -> PyResult<String> {
+#[pyo3(signature = (value, precision=2, *, style="unicode", engineering=false))]
+fn scientific(
+    value: &Bound<'_, PyAny>,
+    precision: usize,
+    style: &str,
+    engineering: bool,
+) -> PyResult<String> {
+    use speakhuman::number::ScientificStyle;
+    let style = match style {
+        "unicode" => ScientificStyle::Unicode,
+        "ascii" => ScientificStyle::Ascii,
+        "latex" => ScientificStyle::Latex,
+        "html" => ScientificStyle::Html,
+        _ => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Unknown style '{}'",
+                style
+            )))
+        }
+    };
     let s = value.str()?.to_string();
-    Ok(speakhuman::scientific(&s, precision))
+    Ok(if engineering {
+        speakhuman::number::engineering_styled(&s, precision, style)
+    } else {
+        speakhuman::number::scientific_styled(&s, precision, style)
+    })
 }
 
 /// Convert to fractional number.
 #[pyfunction]
 fn fractional(value: &Bound<'_, PyAny>) -> PyResult<String> {
+    if let Some((numerator, denominator)) = extract_exact_ratio(value)? {
+        return Ok(speakhuman::number::fractional_ratio(numerator, denominator));
+    }
     let s = value.str()?.to_string();
     Ok(speakhuman::fractional(&s))
 }
 
 /// Return a value with a metric SI unit-prefix appended.
+///
+/// `prefixes` restricts the SI prefixes that may be used, e.g. "kM";
+/// `grouped` puts commas in the integer digits those leave.
 #[pyfunction]
-#[pyo3(signature = (value, unit="", precision=3))]
-fn metric(value: f64, unit: &str, precision: usize) -> PyResult<String> {
-    Ok(speakhuman::metric(value, unit, precision))
+#[pyo3(signature = (value, unit="", precision=3, prefixes=None, *, grouped=false))]
+fn metric(
+    value: f64,
+    unit: &str,
+    precision: usize,
+    prefixes: Option<&str>,
+    grouped: bool,
+) -> PyResult<String> {
+    Ok(match (prefixes, grouped) {
+        (Some(prefixes), true) => {
+            speakhuman::number::metric_grouped(value, unit, precision, prefixes)
+        }
+        (None, true) => speakhuman::number::metric_grouped(
+            value,
+            unit,
+            precision,
+            "kMGTPEZYRQm\u{03BC}npfazyrq",
+        ),
+        (Some(prefixes), false) => {
+            speakhuman::metric_with_prefixes(value, unit, precision, prefixes)
+        }
+        (None, false) => speakhuman::metric(value, unit, precision),
+    })
 }
 
 // ===========================================================================
@@ -108,23 +407,51 @@ fn metric(value: f64, unit: &str, precision: usize) -> PyResult<String> {
 #[pyfunction]
 #[pyo3(signature = (value, gender="male"))]
 fn ordinal(value: &Bound<'_, PyAny>, gender: &str) -> PyResult<String> {
-    let s = value.str()?.to_string();
+    let s = extract_number_str(value)?;
     Ok(speakhuman::number::ordinal_gendered(&s, gender))
 }
 
 /// Converts an integer to a string containing commas every three digits.
 #[pyfunction]
 #[pyo3(signature = (value, ndigits=None))]
-fn intcomma(value: &Bound<'_, PyAny>, ndigits: Option<usize>) -> PyResult<String> {
-    let s = value.str()?.to_string();
-    Ok(speakhuman::intcomma(&s, ndigits))
+fn intcomma<'py>(value: &Bound<'py, PyAny>, ndigits: Option<usize>) -> PyResult<Bound<'py, PyAny>> {
+    #[cfg(feature = "numpy")]
+    if let Some(array) = arrays::as_ndarray(value)? {
+        return arrays::intcomma(array, ndigits);
+    }
+    let formatted = match extract_exact_decimal(value)? {
+        Some(s) => speakhuman::number::intcomma_exact(&s, ndigits),
+        None => speakhuman::intcomma(&extract_number_str(value)?, ndigits),
+    };
+    Ok(PyString::new(value.py(), &formatted).into_any())
+}
+
+/// Format every value in an iterable with intcomma, returning a list.
+#[pyfunction]
+#[pyo3(signature = (values, ndigits=None))]
+fn intcomma_many(values: &Bound<'_, PyAny>, ndigits: Option<usize>) -> PyResult<Vec<String>> {
+    // Decimals and Fractions keep their exact path, as in intcomma
+    let extract = |value: &Bound<'_, PyAny>| match extract_exact_decimal(value)? {
+        Some(s) => Ok((s, true)),
+        None => Ok((extract_number_str(value)?, false)),
+    };
+    format_many(values, extract, |(s, exact)| {
+        if exact {
+            speakhuman::number::intcomma_exact(&s, ndigits)
+        } else {
+            speakhuman::intcomma(&s, ndigits)
+        }
+    })
 }
 
 /// Converts a large integer to a friendly text representation.
 #[pyfunction]
 #[pyo3(signature = (value, format="%.1f"))]
 fn intword(value: &Bound<'_, PyAny>, format: &str) -> PyResult<String> {
-    let s = value.str()?.to_string();
+    if let Some(s) = extract_exact_decimal(value)? {
+        return Ok(speakhuman::number::intword_exact(&s, format));
+    }
+    let s = extract_number_str(value)?;
     Ok(speakhuman::intword(&s, format))
 }
 
@@ -142,21 +469,112 @@ fn apnumber(value: &Bound<'_, PyAny>) -> PyResult<String> {
 /// Return a natural representation of a timedelta or number of seconds.
 #[pyfunction]
 #[pyo3(signature = (value, months=true, minimum_unit="seconds"))]
-fn naturaldelta(
+fn naturaldelta<'py>(
+    value: &Bound<'py, PyAny>,
+    months: bool,
+    minimum_unit: &str,
+) -> PyResult<Bound<'py, PyAny>> {
+    #[cfg(feature = "numpy")]
+    if let Some(array) = arrays::as_ndarray(value)? {
+        check_naturaldelta_unit(minimum_unit)?;
+        return arrays::naturaldelta(array, months, minimum_unit);
+    }
+    let td = extract_timedelta_or_float(value)?;
+    check_python_unit(minimum_unit)?;
+    let formatted =
+        speakhuman::time::try_naturaldelta_td(td, months, minimum_unit).map_err(time_error)?;
+    Ok(PyString::new(value.py(), &formatted).into_any())
+}
+
+/// Format every timedelta or number of seconds in an iterable with
+/// naturaldelta, returning a list.
+#[pyfunction]
+#[pyo3(signature = (values, months=true, minimum_unit="seconds"))]
+fn naturaldelta_many(
+    values: &Bound<'_, PyAny>,
+    months: bool,
+    minimum_unit: &str,
+) -> PyResult<Vec<String>> {
+    check_naturaldelta_unit(minimum_unit)?;
+    format_many(values, extract_timedelta_or_float, |td| {
+        speakhuman::time::naturaldelta_td(td, months, minimum_unit)
+    })
+}
+
+/// Parse the output of naturaldelta, precisedelta or naturaltime (e.g.
+/// "2 hours ago") into a timedelta. "from now" gives a negative timedelta.
+#[pyfunction]
+fn naturaldelta_parse<'py>(py: Python<'py>, value: &str) -> PyResult<Bound<'py, PyDelta>> {
+    let td = speakhuman::time::parse_naturaltime(value)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+    let days = i32::try_from(td.days)
+        .map_err(|e| pyo3::exceptions::PyOverflowError::new_err(e.to_string()))?;
+    PyDelta::new(py, days, td.seconds as i32, td.microseconds as i32, true)
+}
+
+/// Return a natural representation of a time in a resolution that makes sense.
+///
+/// Accepts a datetime (naive or tz-aware), a timedelta, or a number of seconds.
+/// Aware datetimes, including `when`, are converted to local time first.
+#[pyfunction]
+#[pyo3(signature = (value, future=false, months=true, minimum_unit="seconds", when=None))]
+fn naturaltime(
     value: &Bound<'_, PyAny>,
+    future: bool,
     months: bool,
     minimum_unit: &str,
+    when: Option<&Bound<'_, PyDateTime>>,
 ) -> PyResult<String> {
-    let td = extract_timedelta_or_float(value)?;
-    Ok(speakhuman::time::naturaldelta_td(td, months, minimum_unit))
+    let (delta, future) = if let Ok(dt) = value.cast::<PyDateTime>() {
+        let now = match when {
+            Some(w) => extract_local_datetime(w)?,
+            None => Local::now().naive_local(),
+        };
+        let date = extract_local_datetime(dt)?;
+        let micros = (now - date).num_microseconds().unwrap_or(i64::MAX);
+        let delta = speakhuman::time::TimeDelta::from_days_seconds_micros(0, 0, micros);
+        (delta, date > now)
+    } else if value.cast::<PyDelta>().is_ok() {
+        let delta = extract_timedelta_or_float(value)?;
+        (delta, delta.days < 0)
+    } else {
+        // Like the Python version, whole seconds only for plain numbers
+        match value.extract::<f64>() {
+            Ok(secs) => (seconds_to_timedelta(secs.round_ties_even())?, future),
+            Err(_) => return Ok(value.str()?.to_string()),
+        }
+    };
+    check_naturaldelta_unit(minimum_unit)?;
+    Ok(speakhuman::time::naturaltime_delta(
+        delta.abs(),
+        future,
+        months,
+        minimum_unit,
+    ))
+}
+
+/// `today` if given, else the local date.
+fn extract_today(today: Option<&Bound<'_, PyAny>>) -> PyResult<NaiveDate> {
+    match today {
+        Some(today) => extract_date(today),
+        None => Ok(Local::now().date_naive()),
+    }
 }
 
 /// Return a natural day.
+///
+/// `today` is the date to compare against, so that callers can pass a
+/// mockable `date.today()`; it defaults to the local date.
 #[pyfunction]
-#[pyo3(signature = (value, format="%b %d"))]
-fn naturalday(value: &Bound<'_, PyAny>, format: &str) -> PyResult<String> {
+#[pyo3(signature = (value, format="%b %d", *, today=None))]
+fn naturalday(
+    value: &Bound<'_, PyAny>,
+    format: &str,
+    today: Option<&Bound<'_, PyAny>>,
+) -> PyResult<String> {
+    let today = extract_today(today)?;
     match extract_date(value) {
-        Ok(date) => Ok(speakhuman::naturalday(date, format)),
+        Ok(date) => Ok(speakhuman::time::naturalday_on(date, format, today)),
         Err(_) => {
             // If we can't extract a date, return str(value) like the Python version
             Ok(value.str()?.to_string())
@@ -166,9 +584,11 @@ fn naturalday(value: &Bound<'_, PyAny>, format: &str) -> PyResult<String> {
 
 /// Like naturalday, but append a year for dates more than ~five months away.
 #[pyfunction]
-fn naturaldate(value: &Bound<'_, PyAny>) -> PyResult<String> {
+#[pyo3(signature = (value, *, today=None))]
+fn naturaldate(value: &Bound<'_, PyAny>, today: Option<&Bound<'_, PyAny>>) -> PyResult<String> {
+    let today = extract_today(today)?;
     match extract_date(value) {
-        Ok(date) => Ok(speakhuman::naturaldate(date)),
+        Ok(date) => Ok(speakhuman::time::naturaldate_on(date, today)),
         Err(_) => Ok(value.str()?.to_string()),
     }
 }
@@ -186,13 +606,38 @@ fn precisedelta(
         Ok(td) => td,
         Err(_) => return Ok(value.str()?.to_string()),
     };
+    for unit in &suppress {
+        check_python_unit(unit)?;
+    }
+    check_python_unit(minimum_unit)?;
     let suppress_refs: Vec<&str> = suppress.iter().map(|s| s.as_str()).collect();
-    Ok(speakhuman::time::precisedelta_td(
-        td,
-        minimum_unit,
-        &suppress_refs,
-        format,
-    ))
+    speakhuman::time::try_precisedelta_td(td, minimum_unit, &suppress_refs, format)
+        .map_err(time_error)
+}
+
+// ===========================================================================
+// I18n
+// ===========================================================================
+
+// The active locale is set through `speakhuman.i18n.activate`, which never
+// reaches the Rust thread-local, so the separators are read from there too.
+
+/// Return the thousands separator for the locale active in `speakhuman.i18n`.
+#[pyfunction]
+fn thousands_separator(py: Python<'_>) -> PyResult<String> {
+    py.import("speakhuman.i18n")?
+        .getattr("thousands_separator")?
+        .call0()?
+        .extract()
+}
+
+/// Return the decimal separator for the locale active in `speakhuman.i18n`.
+#[pyfunction]
+fn decimal_separator(py: Python<'_>) -> PyResult<String> {
+    py.import("speakhuman.i18n")?
+        .getattr("decimal_separator")?
+        .call0()?
+        .extract()
 }
 
 // ===========================================================================
@@ -200,12 +645,14 @@ fn precisedelta(
 // ===========================================================================
 
 /// Native Rust acceleration for speakhuman.
-#[pymodule]
+#[pymodule(gil_used = false)]
 fn _speakhuman_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Lists
     m.add_function(wrap_pyfunction!(natural_list, m)?)?;
     // Filesize
     m.add_function(wrap_pyfunction!(naturalsize, m)?)?;
+    m.add_function(wrap_pyfunction!(naturalsize_many, m)?)?;
+    m.add_function(wrap_pyfunction!(naturalsize_parse, m)?)?;
     // Number (non-i18n)
     m.add_function(wrap_pyfunction!(scientific, m)?)?;
     m.add_function(wrap_pyfunction!(fractional, m)?)?;
@@ -213,12 +660,22 @@ fn _speakhuman_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Number (i18n-aware)
     m.add_function(wrap_pyfunction!(ordinal, m)?)?;
     m.add_function(wrap_pyfunction!(intcomma, m)?)?;
+    m.add_function(wrap_pyfunction!(intcomma_many, m)?)?;
     m.add_function(wrap_pyfunction!(intword, m)?)?;
     m.add_function(wrap_pyfunction!(apnumber, m)?)?;
     // Time
     m.add_function(wrap_pyfunction!(naturaldelta, m)?)?;
+    m.add_function(wrap_pyfunction!(naturaldelta_many, m)?)?;
+    m.add_function(wrap_pyfunction!(naturaldelta_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(naturaltime, m)?)?;
     m.add_function(wrap_pyfunction!(naturalday, m)?)?;
     m.add_function(wrap_pyfunction!(naturaldate, m)?)?;
     m.add_function(wrap_pyfunction!(precisedelta, m)?)?;
+    // I18n
+    m.add_function(wrap_pyfunction!(thousands_separator, m)?)?;
+    m.add_function(wrap_pyfunction!(decimal_separator, m)?)?;
+    // Config
+    m.add_class::<config::Config>()?;
+    m.add_class::<human::Human>()?;
     Ok(())
 }
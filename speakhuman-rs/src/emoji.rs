@@ -0,0 +1,115 @@
+//! Emoji-decorated output, for chat bots and notifications.
+//!
+//! When an [`EmojiTable`] is set on the current thread, durations, file
+//! sizes and trend phrases are prefixed with a pictograph: "⏱ 2 hours",
+//! "📦 3.0 MB", "📈 up 12%". It is off by default and, like the
+//! [`verbal`](crate::verbal) style, set per thread.
+
+use std::cell::RefCell;
+
+/// The pictographs put in front of each kind of value. An empty string
+/// leaves that kind undecorated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmojiTable {
+    /// Durations from `naturaldelta`, `naturaltime` and `precisedelta`.
+    pub duration: String,
+    /// File sizes from `naturalsize`.
+    pub size: String,
+    /// Increases from `natural_change` and `natural_trend`.
+    pub rising: String,
+    /// Decreases from `natural_change` and `natural_trend`.
+    pub falling: String,
+}
+
+impl Default for EmojiTable {
+    fn default() -> Self {
+        Self {
+            duration: "\u{23F1}".to_string(),
+            size: "\u{1F4E6}".to_string(),
+            rising: "\u{1F4C8}".to_string(),
+            falling: "\u{1F4C9}".to_string(),
+        }
+    }
+}
+
+thread_local! {
+    static TABLE: RefCell<Option<EmojiTable>> = const { RefCell::new(None) };
+}
+
+/// Turn emoji decoration on with the given table, or off with `None`, for
+/// the current thread.
+///
+/// # Examples
+/// ```
+/// use speakhuman::emoji::{set_emoji, EmojiTable};
+/// set_emoji(Some(EmojiTable::default()));
+/// assert_eq!(speakhuman::naturalsize(3e6, false, false, "%.1f"), "📦 3.0 MB");
+/// set_emoji(None);
+/// assert_eq!(speakhuman::naturalsize(3e6, false, false, "%.1f"), "3.0 MB");
+/// ```
+pub fn set_emoji(table: Option<EmojiTable>) {
+    TABLE.with(|t| *t.borrow_mut() = table);
+}
+
+/// The emoji table of the current thread, if decoration is on.
+pub fn emoji() -> Option<EmojiTable> {
+    TABLE.with(|t| t.borrow().clone())
+}
+
+/// Prefix `text` with the pictograph `pick` chooses from the current table.
+pub(crate) fn decorate<F>(pick: F, text: String) -> String
+where
+    F: Fn(&EmojiTable) -> &str,
+{
+    TABLE.with(|t| match &*t.borrow() {
+        Some(table) if !pick(table).is_empty() => format!("{} {}", pick(table), text),
+        _ => text,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::{natural_change, natural_trend, TrendThresholds};
+    use crate::time::{naturaldelta_fuzzy, naturalfrequency, TimeDelta};
+    use crate::{naturaldelta, precisedelta};
+
+    #[test]
+    fn test_decorated_formatters() {
+        set_emoji(Some(EmojiTable::default()));
+        assert_eq!(naturaldelta(7200.0, true, "seconds"), "⏱ 2 hours");
+        assert_eq!(
+            precisedelta(90.0, "seconds", &[], "%0.2f"),
+            "⏱ 1 minute and 30 seconds"
+        );
+        let hours = TimeDelta::from_hours(2);
+        assert_eq!(naturaldelta_fuzzy(hours), "⏱ 2 hours");
+        assert_eq!(
+            naturalfrequency(TimeDelta::from_weeks(2)),
+            "⏱ every 2 weeks"
+        );
+        assert_eq!(natural_change(100.0, 97.0, false), "📉 down 3%");
+        assert_eq!(
+            natural_trend(0.02, TrendThresholds::default()),
+            "📈 up slightly (+2%)"
+        );
+        assert_eq!(
+            natural_trend(0.0, TrendThresholds::default()),
+            "roughly flat"
+        );
+        set_emoji(None);
+    }
+
+    #[test]
+    fn test_custom_table() {
+        set_emoji(Some(EmojiTable {
+            duration: String::new(),
+            rising: "^".to_string(),
+            ..EmojiTable::default()
+        }));
+        assert_eq!(naturaldelta(7200.0, true, "seconds"), "2 hours");
+        assert_eq!(natural_change(1.0, 2.0, false), "^ up 100%");
+        set_emoji(None);
+        assert_eq!(emoji(), None);
+    }
+}
@@ -1,12 +1,22 @@
 //! Bits and bytes related humanization.
 
-const SUFFIXES_DECIMAL: &[&str] = &[
+use crate::emoji;
+use crate::parse::{Mode, ParseError};
+use crate::verbal;
+
+pub(crate) const SUFFIXES_DECIMAL: &[&str] = &[
     " kB", " MB", " GB", " TB", " PB", " EB", " ZB", " YB", " RB", " QB",
 ];
-const SUFFIXES_BINARY: &[&str] = &[
+pub(crate) const SUFFIXES_BINARY: &[&str] = &[
     " KiB", " MiB", " GiB", " TiB", " PiB", " EiB", " ZiB", " YiB", " RiB", " QiB",
 ];
-const SUFFIXES_GNU: &[&str] = &["K", "M", "G", "T", "P", "E", "Z", "Y", "R", "Q"];
+pub(crate) const SUFFIXES_GNU: &[&str] = &["K", "M", "G", "T", "P", "E", "Z", "Y", "R", "Q"];
+const VERBAL_DECIMAL: &[&str] = &[
+    "kilo", "mega", "giga", "tera", "peta", "exa", "zetta", "yotta", "ronna", "quetta",
+];
+const VERBAL_BINARY: &[&str] = &[
+    "kibi", "mebi", "gibi", "tebi", "pebi", "exbi", "zebi", "yobi", "robi", "quebi",
+];
 
 /// Format a number of bytes like a human-readable filesize (e.g. 10 kB).
 ///
@@ -20,6 +30,14 @@ const SUFFIXES_GNU: &[&str] = &["K", "M", "G", "T", "P", "E", "Z", "Y", "R", "Q"
 /// assert_eq!(naturalsize(3000.0, true, false, "%.1f"), "2.9 KiB");
 /// ```
 pub fn naturalsize(value: f64, binary: bool, gnu: bool, format: &str) -> String {
+    emoji::decorate(
+        |table| &table.size,
+        naturalsize_styled(value, binary, gnu, format),
+    )
+}
+
+/// `naturalsize` in the current output style, without emoji.
+fn naturalsize_styled(value: f64, binary: bool, gnu: bool, format: &str) -> String {
     let suffix: &[&str] = if gnu {
         SUFFIXES_GNU
     } else if binary {
@@ -31,6 +49,10 @@ pub fn naturalsize(value: f64, binary: bool, gnu: bool, format: &str) -> String
     let base: f64 = if gnu || binary { 1024.0 } else { 1000.0 };
     let abs_bytes = value.abs();
 
+    if verbal::is_verbal() {
+        return verbal_size(value, binary || gnu, format);
+    }
+
     if abs_bytes == 1.0 && !gnu {
         return format!("{} Byte", value as i64);
     }
@@ -49,6 +71,53 @@ pub fn naturalsize(value: f64, binary: bool, gnu: bool, format: &str) -> String
     format!("{}{}", formatted, suffix[exp - 1])
 }
 
+/// `naturalsize` in the verbal output style: "three point one four megabytes".
+fn verbal_size(value: f64, binary: bool, format: &str) -> String {
+    if !value.is_finite() {
+        return verbal::spell_not_finite(value);
+    }
+    let prefixes = if binary {
+        VERBAL_BINARY
+    } else {
+        VERBAL_DECIMAL
+    };
+    let base: f64 = if binary { 1024.0 } else { 1000.0 };
+    let abs_bytes = value.abs();
+
+    if abs_bytes < base {
+        let (words, plural) = verbal::spell_quantity(&(value as i64).to_string());
+        return format!("{} {}", words, if plural { "bytes" } else { "byte" });
+    }
+
+    let exp = (abs_bytes.log(base) as usize).min(prefixes.len());
+    let divided = value / base.powi(exp as i32);
+    let (words, plural) = verbal::spell_quantity(&printf_format(format, divided));
+    let unit = if plural { "bytes" } else { "byte" };
+    format!("{} {}{}", words, prefixes[exp - 1], unit)
+}
+
+/// Parse a human-readable filesize back into a number of bytes.
+///
+/// This is the inverse of [`naturalsize`] and understands the same suffixes:
+/// decimal ("kB", "MB", ...), binary ("KiB", "MiB", ...) and GNU ("K", "M",
+/// ...), as well as bare numbers and "B"/"Byte"/"Bytes". Suffixes are matched
+/// case-insensitively and fractional results are rounded to the nearest byte.
+///
+/// This is [`crate::parse::size`] in lenient mode.
+///
+/// # Examples
+/// ```
+/// use speakhuman::filesize::parse_size;
+/// assert_eq!(parse_size("1.5 GiB"), Ok(1_610_612_736));
+/// assert_eq!(parse_size("3.0 MB"), Ok(3_000_000));
+/// assert_eq!(parse_size("2.9K"), Ok(2970));
+/// assert_eq!(parse_size("300 Bytes"), Ok(300));
+/// assert!(parse_size("3 parsecs").is_err());
+/// ```
+pub fn parse_size(value: &str) -> Result<i128, ParseError> {
+    crate::parse::size(value, Mode::Lenient)
+}
+
 /// Simple printf-style format for a single float value.
 /// Supports formats like "%.1f", "%.2f", "%.3f", "%.0f", "%0.2f".
 fn printf_format(fmt: &str, value: f64) -> String {
@@ -128,4 +197,42 @@ mod tests {
         assert_eq!(naturalsize(-4096.0, true, false, "%.1f"), "-4.0 KiB");
         assert_eq!(naturalsize(-300.0, false, false, "%.1f"), "-300 Bytes");
     }
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("300"), Ok(300));
+        assert_eq!(parse_size("1 Byte"), Ok(1));
+        assert_eq!(parse_size("300B"), Ok(300));
+        assert_eq!(parse_size(" 1.0 kB "), Ok(1000));
+        assert_eq!(parse_size("1kb"), Ok(1000));
+        assert_eq!(parse_size("31.0 KiB"), Ok(31 * 1024));
+        assert_eq!(parse_size("1.5 GiB"), Ok(1_610_612_736));
+        assert_eq!(parse_size("1.0K"), Ok(1024));
+        assert_eq!(parse_size("-4.0 KiB"), Ok(-4096));
+        assert_eq!(parse_size("1.0 QB"), Ok(10i128.pow(30)));
+        assert_eq!(parse_size("0.0005 kB"), Ok(1));
+        assert_eq!(parse_size(".5 KiB"), Ok(512));
+    }
+
+    #[test]
+    fn test_parse_size_invalid() {
+        assert!(parse_size("").is_err());
+        assert!(parse_size("kB").is_err());
+        assert!(parse_size("1.2.3 kB").is_err());
+        assert!(parse_size("12 furlongs").is_err());
+        assert!(parse_size("1 XB").is_err());
+        assert!(parse_size("1 KiBB").is_err());
+        assert!(parse_size("1e3 kB").is_err());
+        let err = parse_size("12 furlongs").unwrap_err();
+        assert_eq!(err.kind(), crate::parse::ParseErrorKind::UnknownUnit);
+        assert_eq!(err.to_string(), "Invalid size: \"12 furlongs\"");
+    }
+
+    #[test]
+    fn test_parse_size_round_trip() {
+        for &bytes in &[300.0, 3000.0, 3_000_000.0, 1e12] {
+            let formatted = naturalsize(bytes, false, false, "%.1f");
+            assert_eq!(parse_size(&formatted), Ok(bytes as i128));
+        }
+    }
 }
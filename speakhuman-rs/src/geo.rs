@@ -0,0 +1,154 @@
+//! Geographic coordinate humanization.
+
+use crate::i18n;
+
+/// How [`natural_coordinate`] writes a latitude/longitude pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoordinateStyle {
+    /// Degrees, minutes and seconds: `40°26′46″N 79°58′56″W`.
+    #[default]
+    Dms,
+    /// Decimal degrees: `40.446° N, 79.982° W`.
+    Decimal,
+}
+
+/// Format a latitude/longitude pair for humans.
+///
+/// `precision` is the number of decimals on the seconds ([`CoordinateStyle::Dms`])
+/// or on the degrees ([`CoordinateStyle::Decimal`]), capped at 9. Values are
+/// rounded before being split into degrees, minutes and seconds, so a value
+/// never shows as 60″. Hemisphere letters are translatable (context
+/// "hemisphere") and the decimal separator follows the active locale.
+///
+/// Returns an error if the latitude is outside ±90° or the longitude outside
+/// ±180° (including NaN).
+///
+/// # Examples
+/// ```
+/// use speakhuman::geo::{natural_coordinate, CoordinateStyle};
+/// assert_eq!(
+///     natural_coordinate(40.446111, -79.982222, CoordinateStyle::Dms, 0).unwrap(),
+///     "40°26′46″N 79°58′56″W"
+/// );
+/// assert_eq!(
+///     natural_coordinate(40.446111, -79.982222, CoordinateStyle::Decimal, 3).unwrap(),
+///     "40.446° N, 79.982° W"
+/// );
+/// assert!(natural_coordinate(91.0, 0.0, CoordinateStyle::Dms, 0).is_err());
+/// ```
+pub fn natural_coordinate(
+    lat: f64,
+    lon: f64,
+    style: CoordinateStyle,
+    precision: usize,
+) -> Result<String, String> {
+    if !(-90.0..=90.0).contains(&lat) {
+        return Err(format!("Latitude out of range: {}", lat));
+    }
+    if !(-180.0..=180.0).contains(&lon) {
+        return Err(format!("Longitude out of range: {}", lon));
+    }
+
+    let lat = format_axis(lat, style, precision, ("N", "S"));
+    let lon = format_axis(lon, style, precision, ("E", "W"));
+    Ok(match style {
+        CoordinateStyle::Dms => format!("{} {}", lat, lon),
+        CoordinateStyle::Decimal => format!("{}, {}", lat, lon),
+    })
+}
+
+/// Format one axis. `hemispheres` holds the letters for positive and
+/// negative values; a value that rounds to zero takes the positive one.
+fn format_axis(
+    value: f64,
+    style: CoordinateStyle,
+    precision: usize,
+    hemispheres: (&str, &str),
+) -> String {
+    let precision = precision.min(9);
+    let scale = 10u64.pow(precision as u32);
+    let per_degree = match style {
+        CoordinateStyle::Dms => 3600 * scale,
+        CoordinateStyle::Decimal => scale,
+    };
+
+    // Work in whole units of the last printed digit so rounding carries over
+    let units = (value.abs() * per_degree as f64).round() as u64;
+    let hemisphere = if value < 0.0 && units > 0 {
+        hemispheres.1
+    } else {
+        hemispheres.0
+    };
+    let hemisphere = i18n::pgettext("hemisphere", hemisphere);
+    let decimal_sep = i18n::decimal_separator();
+
+    match style {
+        CoordinateStyle::Dms => {
+            let degrees = units / per_degree;
+            let minutes = units / (60 * scale) % 60;
+            let seconds = fixed_point(units % (60 * scale), precision, &decimal_sep);
+            format!("{}°{}′{}″{}", degrees, minutes, seconds, hemisphere)
+        }
+        CoordinateStyle::Decimal => {
+            let degrees = fixed_point(units, precision, &decimal_sep);
+            format!("{}° {}", degrees, hemisphere)
+        }
+    }
+}
+
+/// Write `units / 10^precision` with exactly `precision` decimals.
+fn fixed_point(units: u64, precision: usize, decimal_sep: &str) -> String {
+    if precision == 0 {
+        return units.to_string();
+    }
+    let scale = 10u64.pow(precision as u32);
+    format!(
+        "{}{}{:0width$}",
+        units / scale,
+        decimal_sep,
+        units % scale,
+        width = precision
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dms(lat: f64, lon: f64, precision: usize) -> String {
+        natural_coordinate(lat, lon, CoordinateStyle::Dms, precision).unwrap()
+    }
+
+    fn decimal(lat: f64, lon: f64, precision: usize) -> String {
+        natural_coordinate(lat, lon, CoordinateStyle::Decimal, precision).unwrap()
+    }
+
+    #[test]
+    fn test_dms() {
+        assert_eq!(dms(0.0, 0.0, 0), "0°0′0″N 0°0′0″E");
+        assert_eq!(dms(-33.8688, 151.2093, 0), "33°52′8″S 151°12′33″E");
+        assert_eq!(dms(-33.8688, 151.2093, 2), "33°52′7.68″S 151°12′33.48″E");
+        assert_eq!(dms(90.0, -180.0, 0), "90°0′0″N 180°0′0″W");
+    }
+
+    #[test]
+    fn test_dms_rounding_carries() {
+        // 59.9999″ rounds up into the next minute and degree
+        assert_eq!(dms(10.0 - 0.1 / 3600.0, 0.0, 0), "10°0′0″N 0°0′0″E");
+    }
+
+    #[test]
+    fn test_decimal() {
+        assert_eq!(decimal(51.5074, -0.1278, 2), "51.51° N, 0.13° W");
+        assert_eq!(decimal(51.5074, -0.1278, 0), "52° N, 0° E");
+        assert_eq!(decimal(-0.0001, 0.0, 3), "0.000° N, 0.000° E");
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let style = CoordinateStyle::Decimal;
+        assert!(natural_coordinate(-90.5, 0.0, style, 3).is_err());
+        assert!(natural_coordinate(0.0, 180.5, style, 3).is_err());
+        assert!(natural_coordinate(f64::NAN, 0.0, style, 3).is_err());
+    }
+}
@@ -2,12 +2,16 @@
 //!
 //! Provides activate/deactivate for locale selection, and translation functions
 //! that mirror Python's gettext (_gettext, _pgettext, _ngettext).
-//! Uses thread-local state so different threads can have different locales.
+//! The active locale is thread-local so different threads can have different
+//! locales; threads that never choose one follow the process-wide default set
+//! with `activate_global`. Parsed translations are shared by all threads.
 
+use once_cell::sync::Lazy;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, PoisonError, RwLock};
 
 /// Represents a loaded set of translations from a .mo file.
 #[derive(Clone, Debug)]
@@ -69,6 +73,21 @@ impl Translations {
         }
     }
 
+    fn npgettext(&self, context: &str, singular: &str, plural: &str, n: i64) -> String {
+        let key = format!("{}\x04{}", context, singular);
+        if let Some(forms) = self.plurals.get(&key) {
+            let idx = self.plural_index(n);
+            if idx < forms.len() {
+                return forms[idx].clone();
+            }
+        }
+        if n == 1 {
+            singular.to_string()
+        } else {
+            plural.to_string()
+        }
+    }
+
     fn plural_index(&self, n: i64) -> usize {
         // Default English plural rule: 0 if n==1, else 1
         // For Slavic languages this would be more complex, but gettext handles
@@ -105,14 +124,13 @@ fn parse_mo_file(path: &Path) -> Result<Translations, String> {
 
     // Check magic number
     let magic = u32::from_le_bytes([data[0], data[1], data[2], data[3]]);
-    let (read_u32, _is_le): (fn(&[u8], usize) -> u32, bool) =
-        if magic == 0x950412de {
-            (read_u32_le, true)
-        } else if magic == 0xde120495 {
-            (read_u32_be, false)
-        } else {
-            return Err("Invalid .mo file: bad magic number".into());
-        };
+    let read_u32: fn(&[u8], usize) -> u32 = if magic == 0x950412de {
+        read_u32_le
+    } else if magic == 0xde120495 {
+        read_u32_be
+    } else {
+        return Err("Invalid .mo file: bad magic number".into());
+    };
 
     let num_strings = read_u32(&data, 8) as usize;
     let orig_offset = read_u32(&data, 12) as usize;
@@ -220,25 +238,29 @@ fn decimal_separator_map() -> HashMap<&'static str, &'static str> {
     m
 }
 
-/// Thread-local state for the current locale and cached translations.
-struct I18nState {
-    locale: Option<String>,
-    translations: HashMap<Option<String>, Translations>,
-}
+/// Translations parsed so far, keyed by locale and shared by all threads so
+/// each .mo file is only read once.
+static TRANSLATIONS: Lazy<RwLock<HashMap<String, Arc<Translations>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
 
-impl I18nState {
-    fn new() -> Self {
-        let mut translations = HashMap::new();
-        translations.insert(None, Translations::null());
-        Self {
-            locale: None,
-            translations,
-        }
-    }
-}
+/// Process-wide default locale, for threads that haven't chosen their own.
+static GLOBAL_LOCALE: RwLock<Option<String>> = RwLock::new(None);
 
 thread_local! {
-    static I18N_STATE: RefCell<I18nState> = RefCell::new(I18nState::new());
+    /// This thread's locale: `None` follows the global default, `Some(None)`
+    /// means no translation.
+    static THREAD_LOCALE: RefCell<Option<Option<String>>> = const { RefCell::new(None) };
+}
+
+/// The locale in effect on the current thread.
+fn current_locale() -> Option<String> {
+    THREAD_LOCALE.with(|locale| match &*locale.borrow() {
+        Some(locale) => locale.clone(),
+        None => GLOBAL_LOCALE
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone(),
+    })
 }
 
 /// Return the default locale path (relative to the crate, looking for ../locale or similar).
@@ -262,90 +284,126 @@ fn get_default_locale_path() -> Option<PathBuf> {
     None
 }
 
-/// Activate a locale for translations.
+/// Activate a locale for translations on the current thread.
 ///
 /// # Arguments
 /// * `locale` - Language name, e.g. "ru_RU". If None or starts with "en", defaults to no translation.
 /// * `path` - Optional path to the locale directory containing .mo files.
 pub fn activate(locale: Option<&str>, path: Option<&Path>) -> Result<(), String> {
     let locale = match locale {
-        None => {
+        Some(l) if !l.starts_with("en") => l,
+        _ => {
             deactivate();
             return Ok(());
         }
-        Some(l) if l.starts_with("en") => {
-            deactivate();
+    };
+
+    ensure_loaded(locale, path)?;
+    THREAD_LOCALE.with(|current| *current.borrow_mut() = Some(Some(locale.to_string())));
+    Ok(())
+}
+
+/// Set the process-wide default locale, used by every thread that hasn't
+/// called [`activate`] or [`deactivate`] itself.
+///
+/// Arguments are as for [`activate`].
+pub fn activate_global(locale: Option<&str>, path: Option<&Path>) -> Result<(), String> {
+    let locale = match locale {
+        Some(l) if !l.starts_with("en") => l,
+        _ => {
+            deactivate_global();
             return Ok(());
         }
-        Some(l) => l.to_string(),
     };
 
-    I18N_STATE.with(|state| {
-        let mut state = state.borrow_mut();
-
-        if !state.translations.contains_key(&Some(locale.clone())) {
-            let locale_path = match path {
-                Some(p) => p.to_path_buf(),
-                None => get_default_locale_path().ok_or_else(|| {
-                    "Speakhuman cannot determinate the default location of the 'locale' folder. \
-                     You need to pass the path explicitly."
-                        .to_string()
-                })?,
-            };
-
-            let mo_path = locale_path
-                .join(&locale)
-                .join("LC_MESSAGES")
-                .join("speakhuman.mo");
-
-            if !mo_path.exists() {
-                // Try with just the language code (e.g., "fr" from "fr_FR")
-                let lang_code = locale.split('_').next().unwrap_or(&locale);
-                let alt_mo_path = locale_path
-                    .join(lang_code)
-                    .join("LC_MESSAGES")
-                    .join("speakhuman.mo");
-                if alt_mo_path.exists() {
-                    let translations = parse_mo_file(&alt_mo_path)?;
-                    state
-                        .translations
-                        .insert(Some(locale.clone()), translations);
-                } else {
-                    return Err(format!(
-                        "Cannot find .mo file at {:?} or {:?}",
-                        mo_path, alt_mo_path
-                    ));
-                }
-            } else {
-                let translations = parse_mo_file(&mo_path)?;
-                state
-                    .translations
-                    .insert(Some(locale.clone()), translations);
-            }
-        }
+    ensure_loaded(locale, path)?;
+    set_global_locale(Some(locale.to_string()));
+    Ok(())
+}
 
-        state.locale = Some(locale);
-        Ok(())
-    })
+/// Parse the translations for `locale` into the shared cache, if needed.
+fn ensure_loaded(locale: &str, path: Option<&Path>) -> Result<(), String> {
+    let loaded = TRANSLATIONS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .contains_key(locale);
+    if !loaded {
+        // Parse outside the lock; if another thread got there first, keep its copy
+        let translations = Arc::new(load_translations(locale, path)?);
+        TRANSLATIONS
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(locale.to_string())
+            .or_insert(translations);
+    }
+    Ok(())
+}
+
+/// Locate and parse the .mo file for `locale`.
+fn load_translations(locale: &str, path: Option<&Path>) -> Result<Translations, String> {
+    let locale_path = match path {
+        Some(p) => p.to_path_buf(),
+        None => get_default_locale_path().ok_or_else(|| {
+            "Speakhuman cannot determinate the default location of the 'locale' folder. \
+             You need to pass the path explicitly."
+                .to_string()
+        })?,
+    };
+
+    let mo_path = locale_path
+        .join(locale)
+        .join("LC_MESSAGES")
+        .join("speakhuman.mo");
+
+    if mo_path.exists() {
+        return parse_mo_file(&mo_path);
+    }
+
+    // Try with just the language code (e.g., "fr" from "fr_FR")
+    let lang_code = locale.split('_').next().unwrap_or(locale);
+    let alt_mo_path = locale_path
+        .join(lang_code)
+        .join("LC_MESSAGES")
+        .join("speakhuman.mo");
+    if alt_mo_path.exists() {
+        parse_mo_file(&alt_mo_path)
+    } else {
+        Err(format!(
+            "Cannot find .mo file at {:?} or {:?}",
+            mo_path, alt_mo_path
+        ))
+    }
 }
 
-/// Deactivate internationalization (revert to English/no translation).
+/// Deactivate internationalization on the current thread (revert to
+/// English/no translation), regardless of the global default.
 pub fn deactivate() {
-    I18N_STATE.with(|state| {
-        state.borrow_mut().locale = None;
-    });
+    THREAD_LOCALE.with(|current| *current.borrow_mut() = Some(None));
+}
+
+/// Clear the process-wide default locale set by [`activate_global`].
+pub fn deactivate_global() {
+    set_global_locale(None);
+}
+
+fn set_global_locale(locale: Option<String>) {
+    let mut global = GLOBAL_LOCALE
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+    *global = locale;
 }
 
 /// Get the current translations.
-fn get_translation() -> Translations {
-    I18N_STATE.with(|state| {
-        let state = state.borrow();
-        state
-            .translations
-            .get(&state.locale)
-            .cloned()
-            .unwrap_or_else(Translations::null)
-    })
+fn get_translation() -> Arc<Translations> {
+    current_locale()
+        .and_then(|locale| {
+            TRANSLATIONS
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .get(&locale)
+                .cloned()
+        })
+        .unwrap_or_else(|| Arc::new(Translations::null()))
 }
 
 /// Translate a message using the current locale.
@@ -363,28 +421,48 @@ pub fn ngettext(singular: &str, plural: &str, n: i64) -> String {
     get_translation().ngettext(singular, plural, n)
 }
 
+/// Translate with context and plural forms.
+pub fn npgettext(context: &str, singular: &str, plural: &str, n: i64) -> String {
+    get_translation().npgettext(context, singular, plural, n)
+}
+
 /// Return the thousands separator for the current locale (default: ",").
 pub fn thousands_separator() -> String {
-    I18N_STATE.with(|state| {
-        let state = state.borrow();
-        let map = thousands_separator_map();
-        match &state.locale {
-            Some(locale) => map.get(locale.as_str()).unwrap_or(&",").to_string(),
-            None => ",".to_string(),
-        }
-    })
+    let map = thousands_separator_map();
+    match current_locale() {
+        Some(locale) => map.get(locale.as_str()).unwrap_or(&",").to_string(),
+        None => ",".to_string(),
+    }
 }
 
 /// Return the decimal separator for the current locale (default: ".").
 pub fn decimal_separator() -> String {
-    I18N_STATE.with(|state| {
-        let state = state.borrow();
-        let map = decimal_separator_map();
-        match &state.locale {
-            Some(locale) => map.get(locale.as_str()).unwrap_or(&".").to_string(),
-            None => ".".to_string(),
-        }
-    })
+    let map = decimal_separator_map();
+    match current_locale() {
+        Some(locale) => map.get(locale.as_str()).unwrap_or(&".").to_string(),
+        None => ".".to_string(),
+    }
+}
+
+/// Locales that group digits the Indian way (12,34,56,789).
+const INDIAN_GROUPING_LOCALES: &[&str] = &[
+    "bn_BD", "bn_IN", "en_IN", "gu_IN", "hi_IN", "kn_IN", "ml_IN", "mr_IN", "pa_IN", "ta_IN",
+    "te_IN",
+];
+
+/// Whether the current locale groups digits in lakh and crore, with groups of
+/// two above the first three digits.
+pub fn indian_grouping() -> bool {
+    current_locale().is_some_and(|locale| INDIAN_GROUPING_LOCALES.contains(&locale.as_str()))
+}
+
+/// Locales that write the month before the day ("June 3rd, 2024").
+const MONTH_FIRST_LOCALES: &[&str] = &["en_US", "en_PH"];
+
+/// Whether the current locale writes the day before the month in dates
+/// ("3rd June 2024"). With no locale active, dates are month first.
+pub fn day_first() -> bool {
+    current_locale().is_some_and(|locale| !MONTH_FIRST_LOCALES.contains(&locale.as_str()))
 }
 
 #[cfg(test)]
@@ -405,5 +483,49 @@ mod tests {
         assert_eq!(pgettext("ctx", "hello"), "hello");
         assert_eq!(ngettext("1 item", "%d items", 1), "1 item");
         assert_eq!(ngettext("1 item", "%d items", 2), "%d items");
+        assert_eq!(npgettext("ctx", "1 item", "%d items", 2), "%d items");
+    }
+
+    #[test]
+    fn test_missing_locale() {
+        let missing = Path::new("/nonexistent/locale");
+        assert!(activate(Some("xx_XX"), Some(missing)).is_err());
+        assert!(activate_global(Some("xx_XX"), Some(missing)).is_err());
+        assert_eq!(thousands_separator(), ",");
+        assert!(!day_first());
+    }
+
+    #[test]
+    fn test_threads_have_separate_locales() {
+        if get_default_locale_path().is_none() || activate(Some("de_DE"), None).is_err() {
+            // .mo files not generated
+            return;
+        }
+        deactivate();
+
+        let handles: Vec<_> = (0..16)
+            .map(|i| {
+                std::thread::spawn(move || {
+                    let german = i % 2 == 0;
+                    if german {
+                        activate(Some("de_DE"), None).unwrap();
+                    } else {
+                        deactivate();
+                    }
+                    for _ in 0..200 {
+                        let expected = if german { "." } else { "," };
+                        assert_eq!(thousands_separator(), expected);
+                        assert_eq!(
+                            crate::number::intcomma("1234567", None),
+                            format!("1{0}234{0}567", expected)
+                        );
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(thousands_separator(), ",");
     }
 }
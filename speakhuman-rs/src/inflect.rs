@@ -0,0 +1,290 @@
+//! English noun inflection: plural and singular forms.
+//!
+//! Irregular and uncountable nouns come from the tables below; everything
+//! else follows the regular spelling rules ("box" → "boxes", "city" →
+//! "cities", "knife" → "knives", "analysis" → "analyses"). The case of the
+//! input is preserved: "Child" → "Children", "CITY" → "CITIES".
+
+/// Nouns whose plural doesn't follow the spelling rules, as (singular,
+/// plural).
+const IRREGULAR: &[(&str, &str)] = &[
+    ("person", "people"),
+    ("man", "men"),
+    ("woman", "women"),
+    ("child", "children"),
+    ("tooth", "teeth"),
+    ("foot", "feet"),
+    ("mouse", "mice"),
+    ("goose", "geese"),
+    ("ox", "oxen"),
+    ("louse", "lice"),
+    ("die", "dice"),
+    ("criterion", "criteria"),
+    ("phenomenon", "phenomena"),
+    ("datum", "data"),
+    ("medium", "media"),
+    ("bacterium", "bacteria"),
+    ("curriculum", "curricula"),
+    ("cactus", "cacti"),
+    ("fungus", "fungi"),
+    ("nucleus", "nuclei"),
+    ("radius", "radii"),
+    ("stimulus", "stimuli"),
+    ("alumnus", "alumni"),
+    ("appendix", "appendices"),
+    ("index", "indices"),
+    ("matrix", "matrices"),
+    ("vertex", "vertices"),
+    ("axis", "axes"),
+    ("quiz", "quizzes"),
+    ("bus", "buses"),
+    ("gas", "gases"),
+    ("lens", "lenses"),
+    ("atlas", "atlases"),
+    ("virus", "viruses"),
+    ("status", "statuses"),
+    ("campus", "campuses"),
+    ("movie", "movies"),
+    ("cookie", "cookies"),
+    ("zombie", "zombies"),
+    ("roof", "roofs"),
+    ("chief", "chiefs"),
+    ("belief", "beliefs"),
+    ("chef", "chefs"),
+    ("cliff", "cliffs"),
+];
+
+/// Nouns with the same singular and plural form.
+const UNCOUNTABLE: &[&str] = &[
+    "sheep",
+    "fish",
+    "deer",
+    "moose",
+    "series",
+    "species",
+    "news",
+    "information",
+    "equipment",
+    "rice",
+    "money",
+    "software",
+    "hardware",
+    "aircraft",
+    "offspring",
+    "feedback",
+];
+
+/// Nouns ending in "f" or "fe" whose plural ends in "ves", as (singular,
+/// plural).
+const F_TO_VES: &[(&str, &str)] = &[
+    ("leaf", "leaves"),
+    ("loaf", "loaves"),
+    ("wolf", "wolves"),
+    ("half", "halves"),
+    ("calf", "calves"),
+    ("shelf", "shelves"),
+    ("thief", "thieves"),
+    ("elf", "elves"),
+    ("self", "selves"),
+    ("knife", "knives"),
+    ("wife", "wives"),
+    ("life", "lives"),
+];
+
+/// Nouns ending in "o" that take "es" in the plural.
+const O_TO_OES: &[&str] = &[
+    "hero", "potato", "tomato", "echo", "veto", "torpedo", "embargo",
+];
+
+/// Nouns ending in "sis" whose plural isn't caught by the "yses" rule when
+/// singularizing.
+const SIS_WORDS: &[&str] = &[
+    "basis",
+    "crisis",
+    "diagnosis",
+    "ellipsis",
+    "emphasis",
+    "hypothesis",
+    "oasis",
+    "parenthesis",
+    "prognosis",
+    "synopsis",
+    "synthesis",
+    "thesis",
+];
+
+/// Return the plural form of an English noun.
+///
+/// # Examples
+/// ```
+/// use speakhuman::inflect::pluralize;
+/// assert_eq!(pluralize("analysis"), "analyses");
+/// assert_eq!(pluralize("city"), "cities");
+/// assert_eq!(pluralize("Child"), "Children");
+/// assert_eq!(pluralize("sheep"), "sheep");
+/// ```
+pub fn pluralize(word: &str) -> String {
+    let lower = word.to_lowercase();
+    if lower.is_empty() || UNCOUNTABLE.contains(&lower.as_str()) {
+        return word.to_string();
+    }
+    let tables = IRREGULAR.iter().chain(F_TO_VES);
+    if let Some((_, plural)) = tables.clone().find(|(singular, _)| *singular == lower) {
+        return match_case(word, plural);
+    }
+    // Already plural
+    if tables.clone().any(|(_, plural)| *plural == lower) {
+        return word.to_string();
+    }
+
+    let plural = if let Some(stem) = lower.strip_suffix("sis") {
+        format!("{}ses", stem)
+    } else if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|e| lower.ends_with(e))
+    {
+        format!("{}es", lower)
+    } else if let Some(stem) = lower.strip_suffix('y').filter(|s| ends_with_consonant(s)) {
+        format!("{}ies", stem)
+    } else if O_TO_OES.contains(&lower.as_str()) {
+        format!("{}es", lower)
+    } else {
+        format!("{}s", lower)
+    };
+    match_case(word, &plural)
+}
+
+/// Return the singular form of an English noun.
+///
+/// Words that already look singular are returned unchanged.
+///
+/// # Examples
+/// ```
+/// use speakhuman::inflect::singularize;
+/// assert_eq!(singularize("analyses"), "analysis");
+/// assert_eq!(singularize("boxes"), "box");
+/// assert_eq!(singularize("knives"), "knife");
+/// assert_eq!(singularize("People"), "Person");
+/// ```
+pub fn singularize(word: &str) -> String {
+    let lower = word.to_lowercase();
+    if lower.is_empty() || UNCOUNTABLE.contains(&lower.as_str()) {
+        return word.to_string();
+    }
+    let tables = IRREGULAR.iter().chain(F_TO_VES);
+    if let Some((singular, _)) = tables.clone().find(|(_, plural)| *plural == lower) {
+        return match_case(word, singular);
+    }
+    // Already singular
+    if tables.clone().any(|(singular, _)| *singular == lower) {
+        return word.to_string();
+    }
+
+    let sis = SIS_WORDS.iter().find(|w| {
+        w.strip_suffix("is")
+            .is_some_and(|stem| lower == format!("{}es", stem))
+    });
+    let singular = if let Some(sis) = sis {
+        sis.to_string()
+    } else if let Some(stem) = lower.strip_suffix("yses") {
+        format!("{}ysis", stem)
+    } else if let Some(stem) = lower.strip_suffix("ies").filter(|s| s.len() > 1) {
+        format!("{}y", stem)
+    } else if let Some(stem) = lower
+        .strip_suffix("es")
+        .filter(|s| ["ss", "x", "zz", "ch", "sh"].iter().any(|e| s.ends_with(e)))
+    {
+        stem.to_string()
+    } else if let Some(stem) = lower.strip_suffix("es").filter(|s| O_TO_OES.contains(s)) {
+        stem.to_string()
+    } else if ["ss", "us", "is"].iter().any(|e| lower.ends_with(e)) {
+        lower
+    } else if let Some(stem) = lower.strip_suffix('s') {
+        stem.to_string()
+    } else {
+        lower
+    };
+    match_case(word, &singular)
+}
+
+fn ends_with_consonant(stem: &str) -> bool {
+    stem.chars()
+        .last()
+        .is_some_and(|c| c.is_alphabetic() && !"aeiou".contains(c))
+}
+
+/// Give `inflected` the case pattern of `original`: all caps, capitalized,
+/// or as is.
+fn match_case(original: &str, inflected: &str) -> String {
+    let mut letters = original.chars().filter(|c| c.is_alphabetic());
+    let first_upper = letters.next().is_some_and(char::is_uppercase);
+    if first_upper && original.chars().count() > 1 && !original.chars().any(char::is_lowercase) {
+        return inflected.to_uppercase();
+    }
+    if first_upper {
+        let mut chars = inflected.chars();
+        return match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        };
+    }
+    inflected.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAIRS: &[(&str, &str)] = &[
+        ("cat", "cats"),
+        ("bus", "buses"),
+        ("class", "classes"),
+        ("box", "boxes"),
+        ("church", "churches"),
+        ("dish", "dishes"),
+        ("city", "cities"),
+        ("day", "days"),
+        ("hero", "heroes"),
+        ("photo", "photos"),
+        ("leaf", "leaves"),
+        ("knife", "knives"),
+        ("roof", "roofs"),
+        ("analysis", "analyses"),
+        ("crisis", "crises"),
+        ("thesis", "theses"),
+        ("person", "people"),
+        ("criterion", "criteria"),
+        ("index", "indices"),
+        ("quiz", "quizzes"),
+        ("virus", "viruses"),
+        ("movie", "movies"),
+        ("shoe", "shoes"),
+        ("house", "houses"),
+        ("fish", "fish"),
+    ];
+
+    #[test]
+    fn test_round_trip() {
+        for (singular, plural) in PAIRS {
+            assert_eq!(pluralize(singular), *plural, "{}", singular);
+            assert_eq!(singularize(plural), *singular, "{}", plural);
+        }
+    }
+
+    #[test]
+    fn test_idempotent() {
+        assert_eq!(pluralize("people"), "people");
+        assert_eq!(singularize("person"), "person");
+        assert_eq!(singularize("status"), "status");
+        assert_eq!(singularize("glass"), "glass");
+    }
+
+    #[test]
+    fn test_case() {
+        assert_eq!(pluralize("CITY"), "CITIES");
+        assert_eq!(pluralize("Mouse"), "Mice");
+        assert_eq!(singularize("Analyses"), "Analysis");
+        assert_eq!(pluralize("A"), "As");
+        assert_eq!(pluralize(""), "");
+    }
+}
@@ -3,23 +3,54 @@
 //! This library provides functions to convert machine-readable values into
 //! human-readable formats:
 //! - Numbers (ordinals, word representation, fractional, scientific notation, SI units)
+//! - Physical quantities (lengths, masses, temperatures, volumes; metric or imperial)
 //! - Dates/Times (natural language time deltas and dates)
 //! - File sizes (with binary/decimal/GNU formatting options)
+//! - Geographic coordinates (degrees/minutes/seconds or decimal degrees)
 //! - Lists (natural comma-and-and formatting)
+//! - English noun inflection (pluralize/singularize)
+//! - Raw numbers inside running text (byte counts, timestamps, large integers)
 //! - Internationalization support (30+ locales via .mo files)
+//!
+//! The [`verbal`] output style spells numbers and units out for speech, and
+//! [`emoji`] decoration prefixes values with pictographs for chat. The
+//! [`parse`] module goes the other way, turning formatted text back into
+//! values.
+//!
+//! Calendar dates and clock times (`naturalday`, `naturaldate` and friends)
+//! come from the default `chrono` feature; without it the crate has no
+//! dependency on chrono. The optional `jiff` and `time` features add
+//! conversions from those crates' types, and `num-bigint` lets the typed
+//! number functions take arbitrarily large integers.
 
+pub mod emoji;
 pub mod filesize;
+pub mod geo;
 pub mod i18n;
+pub mod inflect;
 pub mod lists;
 pub mod number;
+pub mod parse;
+pub mod quantity;
+pub mod text;
 pub mod time;
+pub mod verbal;
 
 // Re-exports for convenience
-pub use filesize::naturalsize;
-pub use i18n::{activate, deactivate, decimal_separator, thousands_separator};
+pub use filesize::{naturalsize, parse_size};
+pub use geo::{natural_coordinate, CoordinateStyle};
+pub use i18n::{
+    activate, activate_global, deactivate, deactivate_global, decimal_separator,
+    thousands_separator,
+};
 pub use lists::natural_list;
-pub use number::{apnumber, clamp, fractional, intcomma, intword, metric, ordinal, scientific};
+pub use number::{
+    apnumber, clamp, fractional, intcomma, intword, metric, metric_with_prefixes, ordinal,
+    scientific,
+};
 pub use time::{
-    naturaldate, naturalday, naturaldelta, naturaldelta_td, naturaltime_delta, precisedelta,
+    naturaldelta, naturaldelta_td, naturaltime_delta, parse_delta, parse_naturaltime, precisedelta,
     precisedelta_td, TimeDelta, Unit,
 };
+#[cfg(feature = "chrono")]
+pub use time::{naturaldate, naturaldate_ordinal, naturalday};
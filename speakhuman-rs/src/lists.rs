@@ -1,5 +1,8 @@
 //! Lists related humanization.
 
+use crate::i18n;
+#[cfg(feature = "chrono")]
+use chrono::Weekday;
 use std::fmt::Display;
 
 /// Convert a list of items into a human-readable string with commas and "and".
@@ -26,6 +29,116 @@ pub fn natural_list<T: Display>(items: &[T]) -> String {
     }
 }
 
+/// Like [`natural_list`], but joined the way the active locale writes lists.
+///
+/// Items are separated with the translation of ", " (context "list
+/// separator") and the last one is added with the "%s and %s" template. With
+/// `oxford_comma`, the separator also goes before the last item of a list of
+/// three or more: "one, two, and three".
+///
+/// # Examples
+/// ```
+/// use speakhuman::lists::natural_list_localized;
+/// let items = ["one", "two", "three"];
+/// assert_eq!(natural_list_localized(&items, false), "one, two and three");
+/// assert_eq!(natural_list_localized(&items, true), "one, two, and three");
+/// assert_eq!(natural_list_localized(&items[..2], true), "one and two");
+/// ```
+pub fn natural_list_localized<T: Display>(items: &[T], oxford_comma: bool) -> String {
+    let Some((last, head)) = items.split_last() else {
+        return String::new();
+    };
+    if head.is_empty() {
+        return last.to_string();
+    }
+    let separator = i18n::pgettext("list separator", ", ");
+    let mut head = head
+        .iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<_>>()
+        .join(&separator);
+    if oxford_comma && items.len() > 2 {
+        head.push_str(separator.trim_end());
+    }
+    i18n::gettext("%s and %s")
+        .replacen("%s", &head, 1)
+        .replacen("%s", &last.to_string(), 1)
+}
+
+/// Collapse a set of integers into runs, like "1–3, 5 and 7–8".
+///
+/// The values are sorted and duplicates dropped first. Runs of two or more
+/// consecutive values are joined with an en dash.
+///
+/// # Examples
+/// ```
+/// use speakhuman::lists::natural_ranges;
+/// assert_eq!(natural_ranges(&[1, 2, 3, 5, 7, 8]), "1–3, 5 and 7–8");
+/// assert_eq!(natural_ranges(&[10, 4, 4]), "4 and 10");
+/// ```
+pub fn natural_ranges(values: &[i64]) -> String {
+    let mut values = values.to_vec();
+    values.sort_unstable();
+    values.dedup();
+    let ranges: Vec<String> = runs(&values)
+        .into_iter()
+        .map(|(first, last)| format_run(first.to_string(), last.to_string(), first == last))
+        .collect();
+    natural_list(&ranges)
+}
+
+/// Collapse a set of weekdays into runs, like "Mon–Wed and Fri".
+///
+/// Weeks start on Monday and runs don't wrap around the weekend. Day names
+/// are translated with the context "weekday".
+///
+/// # Examples
+/// ```
+/// use chrono::Weekday;
+/// use speakhuman::lists::natural_weekday_ranges;
+/// let days = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Fri];
+/// assert_eq!(natural_weekday_ranges(&days), "Mon–Wed and Fri");
+/// ```
+#[cfg(feature = "chrono")]
+pub fn natural_weekday_ranges(days: &[Weekday]) -> String {
+    let mut values: Vec<i64> = days
+        .iter()
+        .map(|day| day.num_days_from_monday() as i64)
+        .collect();
+    values.sort_unstable();
+    values.dedup();
+    let name = |value: i64| {
+        let day = Weekday::try_from(value as u8).expect("weekday index");
+        i18n::pgettext("weekday", &day.to_string())
+    };
+    let ranges: Vec<String> = runs(&values)
+        .into_iter()
+        .map(|(first, last)| format_run(name(first), name(last), first == last))
+        .collect();
+    natural_list(&ranges)
+}
+
+/// Split sorted, distinct values into (first, last) runs of consecutive
+/// values.
+fn runs(values: &[i64]) -> Vec<(i64, i64)> {
+    let mut runs: Vec<(i64, i64)> = Vec::new();
+    for &value in values {
+        match runs.last_mut() {
+            Some((_, last)) if last.checked_add(1) == Some(value) => *last = value,
+            _ => runs.push((value, value)),
+        }
+    }
+    runs
+}
+
+fn format_run(first: String, last: String, single: bool) -> String {
+    if single {
+        first
+    } else {
+        format!("{}\u{2013}{}", first, last)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -53,6 +166,38 @@ mod tests {
         assert_eq!(natural_list(&[1, 2, 3]), "1, 2 and 3");
     }
 
+    #[test]
+    fn test_natural_ranges() {
+        assert_eq!(natural_ranges(&[]), "");
+        assert_eq!(natural_ranges(&[3]), "3");
+        assert_eq!(natural_ranges(&[-2, -1, 0, 2]), "-2–0 and 2");
+        assert_eq!(
+            natural_ranges(&[i64::MAX, i64::MAX - 1]),
+            format!("{}–{}", i64::MAX - 1, i64::MAX)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_natural_weekday_ranges() {
+        use Weekday::*;
+        assert_eq!(natural_weekday_ranges(&[Sat, Sun, Mon]), "Mon and Sat–Sun");
+        assert_eq!(
+            natural_weekday_ranges(&[Tue, Mon, Wed, Thu, Fri]),
+            "Mon–Fri"
+        );
+    }
+
+    #[test]
+    fn test_natural_list_localized() {
+        assert_eq!(natural_list_localized::<&str>(&[], true), "");
+        assert_eq!(natural_list_localized(&["one"], true), "one");
+        assert_eq!(
+            natural_list_localized(&[1, 2, 3, 4], true),
+            "1, 2, 3, and 4"
+        );
+    }
+
     #[test]
     fn test_natural_list_empty_string() {
         assert_eq!(natural_list(&[""]), "");
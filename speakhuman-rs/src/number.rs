@@ -1,13 +1,41 @@
 //! Humanizing functions for numbers.
 
+use crate::emoji;
 use crate::i18n;
-use once_cell::sync::Lazy;
-use regex::Regex;
+use crate::time::TimeDelta;
+use crate::verbal;
+
+/// A value outside the domain of [`natural_comparison`] or the odds
+/// functions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumberError {
+    /// A magnitude that isn't positive and finite.
+    NotPositive(f64),
+    /// A probability outside `(0, 1]`.
+    ProbabilityOutOfRange(f64),
+    /// Decimal odds below 1, or not finite.
+    OddsOutOfRange(f64),
+}
 
-static THOUSANDS_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(-?\d+)(\d{3})").unwrap());
+impl std::fmt::Display for NumberError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NumberError::NotPositive(value) => {
+                write!(f, "Value must be positive and finite: {}", value)
+            }
+            NumberError::ProbabilityOutOfRange(value) => {
+                write!(f, "Probability out of range: {}", value)
+            }
+            NumberError::OddsOutOfRange(value) => {
+                write!(f, "Decimal odds out of range: {}", value)
+            }
+        }
+    }
+}
 
+impl std::error::Error for NumberError {}
 
-const HUMAN_POWERS_SINGULAR: &[&str] = &[
+pub(crate) const HUMAN_POWERS_SINGULAR: &[&str] = &[
     "thousand",
     "million",
     "billion",
@@ -38,7 +66,7 @@ const HUMAN_POWERS_PLURAL: &[&str] = &[
 ];
 
 /// Handle non-finite float values.
-fn format_not_finite(value: f64) -> Option<String> {
+pub(crate) fn format_not_finite(value: f64) -> Option<String> {
     if value.is_nan() {
         Some("NaN".to_string())
     } else if value.is_infinite() && value < 0.0 {
@@ -68,6 +96,227 @@ pub(crate) fn printf_format(fmt: &str, value: f64) -> String {
     format!("{}", value)
 }
 
+/// Extract N from a "%.Nf"-style format string.
+fn printf_precision(fmt: &str) -> Option<usize> {
+    let after_dot = &fmt[fmt.find('.')? + 1..];
+    let f_pos = after_dot.find('f')?;
+    after_dot[..f_pos].parse().ok()
+}
+
+/// Largest exponent [`ExactDecimal::parse`] expands. It covers every
+/// finite `f64`, subnormals included, while keeping "1e-99999999" from
+/// turning into a hundred megabytes of zeros.
+const MAX_EXACT_EXPONENT: u32 = 400;
+
+/// A decimal number held as digit strings, so it can be rounded and shifted
+/// without going through `f64`.
+#[derive(Debug, Clone, PartialEq)]
+struct ExactDecimal {
+    negative: bool,
+    /// Integer digits without leading zeros ("0" for zero).
+    integer: String,
+    /// Fractional digits, possibly with trailing zeros.
+    fraction: String,
+}
+
+impl ExactDecimal {
+    /// Parse "-123.45", "1.5E+25", ".5" and similar decimal notations.
+    ///
+    /// Gives `None` for a nonzero value whose exponent is beyond
+    /// [`MAX_EXACT_EXPONENT`]; a zero mantissa ignores its exponent.
+    fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let (negative, s) = match s.as_bytes().first()? {
+            b'-' => (true, &s[1..]),
+            b'+' => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let (mantissa, exponent) = match s.find(['e', 'E']) {
+            Some(pos) => (&s[..pos], s[pos + 1..].parse::<i32>().ok()?),
+            None => (s, 0),
+        };
+        let (integer, fraction) = match mantissa.split_once('.') {
+            Some((i, f)) => (i, f),
+            None => (mantissa, ""),
+        };
+        if integer.is_empty() && fraction.is_empty() {
+            return None;
+        }
+        if !integer.bytes().chain(fraction.bytes()).all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let zero = integer.bytes().chain(fraction.bytes()).all(|b| b == b'0');
+        let exponent = match exponent {
+            _ if zero => 0,
+            e if e.unsigned_abs() > MAX_EXACT_EXPONENT => return None,
+            e => e,
+        };
+
+        let decimal = ExactDecimal {
+            negative,
+            integer: integer.to_string(),
+            fraction: fraction.to_string(),
+        };
+        let decimal = if exponent >= 0 {
+            decimal.shift_right(exponent as usize)
+        } else {
+            decimal.shift_left(exponent.unsigned_abs() as usize)
+        };
+        Some(decimal.normalized())
+    }
+
+    /// Parse a plain integer ("-123", "+7", "007"), rejecting anything with
+    /// a decimal point or exponent.
+    fn parse_integer(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let digits = s.strip_prefix(['-', '+']).unwrap_or(s);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let mut int = ExactDecimal {
+            negative: s.starts_with('-'),
+            integer: digits.to_string(),
+            fraction: String::new(),
+        }
+        .normalized();
+        // Python prints -0 as 0
+        int.negative &= int.integer != "0";
+        Some(int)
+    }
+
+    /// Parse a finite decimal of magnitude at least one ("1.5E+25",
+    /// "-120.75") and truncate it toward zero. The flag is false when
+    /// nonzero fractional digits were dropped.
+    fn parse_truncated(s: &str) -> Option<(Self, bool)> {
+        // A finite f64 of at least one bounds the exponent by the length of
+        // the input, so expanding it can't blow up
+        let value: f64 = s.trim().parse().ok()?;
+        if !value.is_finite() || value.abs() < 1.0 {
+            return None;
+        }
+        let mut decimal = ExactDecimal::parse(s)?;
+        let exact = decimal.fraction.bytes().all(|b| b == b'0');
+        decimal.fraction.clear();
+        Some((decimal, exact))
+    }
+
+    /// Round to `figures` significant figures, half to even.
+    fn round_significant(self, figures: usize) -> Self {
+        let figures = figures.max(1) as i64;
+        let leading = self.fraction.bytes().take_while(|&b| b == b'0').count();
+        if self.integer == "0" && leading == self.fraction.len() {
+            return self;
+        }
+        let magnitude = self.magnitude();
+        let decimals = figures - 1 - magnitude;
+        let rounded = if decimals >= 0 {
+            self.round(decimals as usize)
+        } else {
+            let n = decimals.unsigned_abs() as usize;
+            self.shift_left(n).round(0).shift_right(n)
+        };
+        // 9.995 rounds up to 10.00, one figure too many
+        if rounded.magnitude() > magnitude && decimals > 0 {
+            rounded.round(decimals as usize - 1)
+        } else {
+            rounded
+        }
+    }
+
+    /// The power of ten of the leading digit, for a nonzero value.
+    fn magnitude(&self) -> i64 {
+        if self.integer != "0" {
+            self.integer.len() as i64 - 1
+        } else {
+            -(self.fraction.bytes().take_while(|&b| b == b'0').count() as i64) - 1
+        }
+    }
+
+    /// Multiply by 10^n.
+    fn shift_right(mut self, n: usize) -> Self {
+        let mut fraction = std::mem::take(&mut self.fraction);
+        if fraction.len() < n {
+            fraction.push_str(&"0".repeat(n - fraction.len()));
+        }
+        self.integer.push_str(&fraction[..n]);
+        self.fraction = fraction[n..].to_string();
+        self.normalized()
+    }
+
+    /// Divide by 10^n.
+    fn shift_left(mut self, n: usize) -> Self {
+        if self.integer.len() < n {
+            self.integer = format!("{}{}", "0".repeat(n - self.integer.len()), self.integer);
+        }
+        let split = self.integer.len() - n;
+        self.fraction = format!("{}{}", &self.integer[split..], self.fraction);
+        self.integer.truncate(split);
+        self.normalized()
+    }
+
+    /// Round to `ndigits` fractional digits, half to even.
+    fn round(mut self, ndigits: usize) -> Self {
+        if self.fraction.len() <= ndigits {
+            let pad = ndigits - self.fraction.len();
+            self.fraction.push_str(&"0".repeat(pad));
+            return self;
+        }
+
+        let (kept, dropped) = self.fraction.split_at(ndigits);
+        let first_dropped = dropped.as_bytes()[0];
+        let rest_nonzero = dropped[1..].bytes().any(|b| b != b'0');
+        let mut digits: Vec<u8> = format!("{}{}", self.integer, kept).into_bytes();
+        let last_odd = digits.last().is_some_and(|b| (b - b'0') % 2 == 1);
+        let round_up = first_dropped > b'5' || (first_dropped == b'5' && (rest_nonzero || last_odd));
+
+        if round_up {
+            let mut i = digits.len();
+            loop {
+                if i == 0 {
+                    digits.insert(0, b'1');
+                    break;
+                }
+                i -= 1;
+                if digits[i] == b'9' {
+                    digits[i] = b'0';
+                } else {
+                    digits[i] += 1;
+                    break;
+                }
+            }
+        }
+
+        let split = digits.len() - ndigits;
+        let digits = String::from_utf8(digits).unwrap();
+        self.integer = digits[..split].to_string();
+        self.fraction = digits[split..].to_string();
+        self.normalized()
+    }
+
+    fn normalized(mut self) -> Self {
+        let trimmed = self.integer.trim_start_matches('0');
+        self.integer = if trimmed.is_empty() {
+            "0".to_string()
+        } else {
+            trimmed.to_string()
+        };
+        self
+    }
+}
+
+impl std::fmt::Display for ExactDecimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", self.integer)?;
+        if !self.fraction.is_empty() {
+            write!(f, ".{}", self.fraction)?;
+        }
+        Ok(())
+    }
+}
+
 /// Convert a float to a fraction with limited denominator, similar to
 /// Python's `Fraction(f).limit_denominator(max_denom)`.
 fn float_to_fraction(value: f64, max_denom: i64) -> (i64, i64) {
@@ -150,12 +399,29 @@ pub fn ordinal_gendered(value: &str, gender: &str) -> String {
         }
     }
 
-    // Try to parse as integer
-    let int_val: i64 = match value.parse::<f64>() {
-        Ok(f) => f as i64,
-        Err(_) => return value.to_string(),
+    // Integers keep all their digits, and so do other decimals when they
+    // are truncated; only values below one go through f64
+    let int = ExactDecimal::parse_integer(value)
+        .or_else(|| ExactDecimal::parse_truncated(value).map(|(int, _)| int));
+    let (int_str, last_two) = match int {
+        Some(int) => {
+            let tail = &int.integer[int.integer.len().saturating_sub(2)..];
+            (int.to_string(), tail.parse::<u64>().unwrap_or(0))
+        }
+        None => match value.parse::<f64>() {
+            Ok(f) => {
+                let int_val = f as i64;
+                (int_val.to_string(), int_val.unsigned_abs() % 100)
+            }
+            Err(_) => return value.to_string(),
+        },
     };
 
+    format!("{}{}", int_str, ordinal_suffix(last_two, gender))
+}
+
+/// The ordinal suffix for a number whose last two digits are `last_two`.
+fn ordinal_suffix(last_two: u64, gender: &str) -> String {
     let suffixes = if gender == "male" {
         [
             i18n::pgettext("0 (male)", "th"),
@@ -184,14 +450,375 @@ pub fn ordinal_gendered(value: &str, gender: &str) -> String {
         ]
     };
 
-    let abs_val = int_val.unsigned_abs();
-    if abs_val % 100 == 11 || abs_val % 100 == 12 || abs_val % 100 == 13 {
-        format!("{}{}", int_val, suffixes[0])
+    if last_two == 11 || last_two == 12 || last_two == 13 {
+        suffixes[0].clone()
+    } else {
+        suffixes[(last_two % 10) as usize].clone()
+    }
+}
+
+/// Describe a position in a ranking, like "1st of 120".
+///
+/// The total is grouped like [`intcomma`].
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::natural_rank;
+/// assert_eq!(natural_rank(1, 120), "1st of 120");
+/// assert_eq!(natural_rank(23, 1500), "23rd of 1,500");
+/// ```
+pub fn natural_rank(position: u64, total: u64) -> String {
+    let template = i18n::gettext("%s of %s");
+    template
+        .replacen("%s", &ordinal(&position.to_string()), 1)
+        .replacen("%s", &intcomma(&total.to_string(), None), 1)
+}
+
+/// Describe a percentile as the share of the population at or above it,
+/// like "top 5%" for the 95th percentile (`0.95`).
+///
+/// Percentiles below the median are described from the other end ("bottom
+/// 30%" for `0.3`). Shares are rounded up to a whole percent, so anything
+/// above the 99th percentile is "top 1%". Values outside 0..=1 are clamped.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::natural_percentile;
+/// assert_eq!(natural_percentile(0.95), "top 5%");
+/// assert_eq!(natural_percentile(0.999), "top 1%");
+/// assert_eq!(natural_percentile(0.3), "bottom 30%");
+/// ```
+pub fn natural_percentile(percentile: f64) -> String {
+    if percentile.is_nan() {
+        return format_not_finite(percentile).unwrap();
+    }
+    let percentile = percentile.clamp(0.0, 1.0);
+    let (template, share) = if percentile >= 0.5 {
+        (i18n::gettext("top %s"), 1.0 - percentile)
+    } else {
+        (i18n::gettext("bottom %s"), percentile)
+    };
+    // Round away float noise (1 - 0.95 is 0.05000000000000004) before ceil
+    let percent = ((share * 100.0 * 1e9).round() / 1e9).ceil().max(1.0);
+    template.replace("%s", &format!("{}%", percent as u32))
+}
+
+/// Summarize the change from `before` to `after`, like "up 12% (from 1.2k to
+/// 1.3k)".
+///
+/// Percentages are relative to `before` and rounded to a whole percent, or to
+/// one significant digit below 1%. With `show_values`, both values are
+/// appended in compact form ("1.2k", "3.4m", "5b"). Equal values give "no
+/// change"; a change from zero has no percentage.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::natural_change;
+/// assert_eq!(natural_change(1200.0, 1344.0, true), "up 12% (from 1.2k to 1.3k)");
+/// assert_eq!(natural_change(100.0, 97.0, false), "down 3%");
+/// assert_eq!(natural_change(5.0, 5.0, true), "no change");
+/// ```
+pub fn natural_change(before: f64, after: f64, show_values: bool) -> String {
+    for value in [before, after] {
+        if let Some(text) = format_not_finite(value) {
+            return text;
+        }
+    }
+    if before == after {
+        return i18n::gettext("no change");
+    }
+
+    let template = if after > before {
+        i18n::gettext("up %s")
+    } else {
+        i18n::gettext("down %s")
+    };
+    let percent = if before == 0.0 {
+        String::new()
+    } else {
+        let percent = ((after - before) / before.abs() * 100.0).abs();
+        let percent = if percent >= 0.95 {
+            format!("{:.0}", percent)
+        } else {
+            let decimals = (-percent.log10().floor()) as usize;
+            format!("{:.*}", decimals, percent)
+        };
+        format!("{}%", percent.replace('.', &i18n::decimal_separator()))
+    };
+    let summary = template.replace("%s", &percent).trim_end().to_string();
+    let text = if show_values {
+        i18n::gettext("%s (from %s to %s)")
+            .replacen("%s", &summary, 1)
+            .replacen("%s", &compact_count(before), 1)
+            .replacen("%s", &compact_count(after), 1)
+    } else {
+        summary
+    };
+    decorate_trend(after > before, text)
+}
+
+/// Prefix a trend phrase with the rising or falling emoji, if enabled.
+fn decorate_trend(rising: bool, text: String) -> String {
+    if rising {
+        emoji::decorate(|table| &table.rising, text)
+    } else {
+        emoji::decorate(|table| &table.falling, text)
+    }
+}
+
+/// Describe how often something happens, like "1.2k req/s" or "45
+/// events/min".
+///
+/// The rate is given per second when there is at least one a second, else
+/// per minute when there is at least one a minute, else per hour. The count
+/// is written in the compact form of [`natural_change`]. A zero `elapsed`
+/// gives "+Inf" (or "NaN" for a zero count).
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::natural_rate;
+/// use speakhuman::time::TimeDelta;
+/// let minute = TimeDelta::from_seconds(60.0);
+/// assert_eq!(natural_rate(72_000.0, minute, "req"), "1.2k req/s");
+/// assert_eq!(natural_rate(45.0, minute, "events"), "45 events/min");
+/// assert_eq!(natural_rate(3.0, TimeDelta::from_seconds(600.0), "errors"), "18 errors/h");
+/// ```
+pub fn natural_rate(count: f64, elapsed: TimeDelta, noun: &str) -> String {
+    let per_second = count / elapsed.total_seconds();
+    if let Some(text) = format_not_finite(per_second) {
+        return text;
+    }
+    let (rate, period) = if per_second.abs() >= 1.0 {
+        (per_second, "s")
+    } else if per_second.abs() * 60.0 >= 1.0 {
+        (per_second * 60.0, "min")
+    } else {
+        (per_second * 3600.0, "h")
+    };
+    format!("{} {}/{}", compact_count(rate), noun, period)
+}
+
+/// Where [`natural_trend`] switches between its qualitative words. Each
+/// field is the smallest absolute change (as a fraction, `0.05` for 5%) that
+/// earns the next word up.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrendThresholds {
+    /// Below this, the change is "roughly flat".
+    pub flat: f64,
+    /// Below this, the change is "slight".
+    pub slight: f64,
+    /// At or above this, the change is "sharp".
+    pub sharp: f64,
+}
+
+impl Default for TrendThresholds {
+    fn default() -> Self {
+        Self {
+            flat: 0.01,
+            slight: 0.05,
+            sharp: 0.25,
+        }
+    }
+}
+
+/// Describe a relative change with a qualitative word and the percentage,
+/// like "up slightly (+2%)" or "down sharply (−35%)".
+///
+/// `delta_fraction` is the change as a fraction of the old value (`0.02` for
+/// +2%). Changes smaller than `thresholds.flat` are "roughly flat", with no
+/// percentage. Percentages are rounded to a whole percent and written with a
+/// true minus sign.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::{natural_trend, TrendThresholds};
+/// let thresholds = TrendThresholds::default();
+/// assert_eq!(natural_trend(0.02, thresholds), "up slightly (+2%)");
+/// assert_eq!(natural_trend(0.004, thresholds), "roughly flat");
+/// assert_eq!(natural_trend(0.12, thresholds), "up (+12%)");
+/// assert_eq!(natural_trend(-0.35, thresholds), "down sharply (−35%)");
+/// ```
+pub fn natural_trend(delta_fraction: f64, thresholds: TrendThresholds) -> String {
+    if let Some(text) = format_not_finite(delta_fraction) {
+        return text;
+    }
+    let size = delta_fraction.abs();
+    if size < thresholds.flat {
+        return i18n::gettext("roughly flat");
+    }
+
+    let rising = delta_fraction > 0.0;
+    let template = match (rising, size) {
+        (true, s) if s < thresholds.slight => i18n::gettext("up slightly (%s)"),
+        (true, s) if s >= thresholds.sharp => i18n::gettext("up sharply (%s)"),
+        (true, _) => i18n::gettext("up (%s)"),
+        (false, s) if s < thresholds.slight => i18n::gettext("down slightly (%s)"),
+        (false, s) if s >= thresholds.sharp => i18n::gettext("down sharply (%s)"),
+        (false, _) => i18n::gettext("down (%s)"),
+    };
+    let sign = if rising { "+" } else { "\u{2212}" };
+    let text = template.replace("%s", &format!("{}{:.0}%", sign, size * 100.0));
+    decorate_trend(rising, text)
+}
+
+/// Compare two positive magnitudes as a ratio, like "3× larger", "about
+/// twice as large" or "roughly equal".
+///
+/// `a` is described relative to `b`. The ratio (or its inverse, when `a` is
+/// smaller) is rounded to `precision` decimals, trailing zeros dropped, and
+/// the phrase says "about" when rounding changed it. Ratios that round to 1
+/// are "roughly equal". Returns [`NumberError::NotPositive`] unless both
+/// values are positive and finite.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::natural_comparison;
+/// assert_eq!(natural_comparison(300.0, 100.0, 1).unwrap(), "3× larger");
+/// assert_eq!(natural_comparison(10.0, 30.0, 1).unwrap(), "3× smaller");
+/// assert_eq!(natural_comparison(2.04, 1.0, 1).unwrap(), "about twice as large");
+/// assert_eq!(natural_comparison(1.04, 1.0, 1).unwrap(), "roughly equal");
+/// assert_eq!(natural_comparison(4.6, 1.0, 0).unwrap(), "about 5× larger");
+/// ```
+pub fn natural_comparison(a: f64, b: f64, precision: usize) -> Result<String, NumberError> {
+    for value in [a, b] {
+        if !(value > 0.0 && value.is_finite()) {
+            return Err(NumberError::NotPositive(value));
+        }
+    }
+    let larger = a >= b;
+    let ratio = if larger { a / b } else { b / a };
+    let rounded = format!("{:.*}", precision, ratio);
+    let rounded = if rounded.contains('.') {
+        rounded.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &rounded
+    };
+    let about = (rounded.parse::<f64>().unwrap_or(ratio) - ratio).abs() > 1e-9;
+
+    let template = match (rounded, larger, about) {
+        ("1", ..) => return Ok(i18n::gettext("roughly equal")),
+        ("2", true, false) => i18n::gettext("twice as large"),
+        ("2", true, true) => i18n::gettext("about twice as large"),
+        ("2", false, false) => i18n::gettext("half as large"),
+        ("2", false, true) => i18n::gettext("about half as large"),
+        (_, true, false) => i18n::gettext("%s× larger"),
+        (_, true, true) => i18n::gettext("about %s× larger"),
+        (_, false, false) => i18n::gettext("%s× smaller"),
+        (_, false, true) => i18n::gettext("about %s× smaller"),
+    };
+    Ok(template.replace("%s", &rounded.replace('.', &i18n::decimal_separator())))
+}
+
+/// Largest "for" part of fractional odds, as on a bookmaker's board.
+const MAX_ODDS_DENOMINATOR: i64 = 20;
+
+fn check_probability(probability: f64) -> Result<(), NumberError> {
+    if probability > 0.0 && probability <= 1.0 {
+        Ok(())
     } else {
-        format!("{}{}", int_val, suffixes[(abs_val % 10) as usize])
+        Err(NumberError::ProbabilityOutOfRange(probability))
     }
 }
 
+/// Fractional odds against an outcome with the given probability, as
+/// `(against, for)` in lowest terms.
+///
+/// The odds are approximated by a fraction whose "for" part is at most 20.
+/// The probability must be in `(0, 1]`, or the error is
+/// [`NumberError::ProbabilityOutOfRange`].
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::fractional_odds;
+/// assert_eq!(fractional_odds(0.2), Ok((4, 1)));
+/// assert_eq!(fractional_odds(0.3), Ok((7, 3)));
+/// assert_eq!(fractional_odds(0.8), Ok((1, 4)));
+/// assert!(fractional_odds(0.0).is_err());
+/// ```
+pub fn fractional_odds(probability: f64) -> Result<(i64, i64), NumberError> {
+    check_probability(probability)?;
+    let against = (1.0 - probability) / probability;
+    Ok(float_to_fraction(against, MAX_ODDS_DENOMINATOR))
+}
+
+/// Decimal odds for an outcome with the given probability: the total return
+/// per unit staked, `1 / probability`.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::decimal_odds;
+/// assert_eq!(decimal_odds(0.8), Ok(1.25));
+/// assert!(decimal_odds(1.5).is_err());
+/// ```
+pub fn decimal_odds(probability: f64) -> Result<f64, NumberError> {
+    check_probability(probability)?;
+    Ok(1.0 / probability)
+}
+
+/// The probability implied by decimal odds, the inverse of [`decimal_odds`].
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::implied_probability;
+/// assert_eq!(implied_probability(1.25), Ok(0.8));
+/// assert!(implied_probability(0.5).is_err());
+/// ```
+pub fn implied_probability(decimal_odds: f64) -> Result<f64, NumberError> {
+    if decimal_odds >= 1.0 && decimal_odds.is_finite() {
+        Ok(1.0 / decimal_odds)
+    } else {
+        Err(NumberError::OddsOutOfRange(decimal_odds))
+    }
+}
+
+/// Fractional odds against an outcome, like "4 to 1", or "evens" for a
+/// probability of one half.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::natural_fractional_odds;
+/// assert_eq!(natural_fractional_odds(0.2).unwrap(), "4 to 1");
+/// assert_eq!(natural_fractional_odds(0.5).unwrap(), "evens");
+/// assert_eq!(natural_fractional_odds(0.8).unwrap(), "1 to 4");
+/// ```
+pub fn natural_fractional_odds(probability: f64) -> Result<String, NumberError> {
+    let (against, odds_for) = fractional_odds(probability)?;
+    if against == odds_for {
+        return Ok(i18n::gettext("evens"));
+    }
+    Ok(i18n::gettext("%s to %s")
+        .replacen("%s", &intcomma(&against.to_string(), None), 1)
+        .replacen("%s", &intcomma(&odds_for.to_string(), None), 1))
+}
+
+/// Decimal odds with two decimals, like "1.25", using the active locale's
+/// decimal separator.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::natural_decimal_odds;
+/// assert_eq!(natural_decimal_odds(0.8).unwrap(), "1.25");
+/// assert_eq!(natural_decimal_odds(0.2).unwrap(), "5.00");
+/// ```
+pub fn natural_decimal_odds(probability: f64) -> Result<String, NumberError> {
+    let odds = decimal_odds(probability)?;
+    Ok(format!("{:.2}", odds).replace('.', &i18n::decimal_separator()))
+}
+
+/// Short form of a count for summaries: "970", "12.5", "1.2k", "3.4m".
+///
+/// From a thousand up this is [`intword_compact`] with lowercase suffixes;
+/// below it, one decimal is kept where [`intword_compact`] would truncate.
+fn compact_count(value: f64) -> String {
+    let rounded = (value * 10.0).round() / 10.0;
+    if rounded.abs() < 1000.0 {
+        let number = format!("{:.1}", rounded);
+        let number = number.strip_suffix(".0").unwrap_or(&number);
+        return number.replace('.', &i18n::decimal_separator());
+    }
+    intword_compact(&rounded.to_string(), "%.1f", COMPACT_SUFFIXES, true)
+}
+
 /// Converts an integer to a string containing commas every three digits.
 ///
 /// # Examples
@@ -202,6 +829,72 @@ pub fn ordinal_gendered(value: &str, gender: &str) -> String {
 /// assert_eq!(intcomma("1000000", None), "1,000,000");
 /// ```
 pub fn intcomma(value: &str, ndigits: Option<usize>) -> String {
+    intcomma_grouped(value, ndigits, Grouping::for_locale())
+}
+
+/// How the integer digits of a number are grouped by [`intcomma_grouped`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Grouping {
+    /// Groups of three: 123,456,789.
+    Thousands,
+    /// The Indian pattern of a group of three, then groups of two:
+    /// 12,34,56,789.
+    Indian,
+    /// Groups of the given size: `Uniform(4)` gives 1,2345,6789. A size of
+    /// zero leaves the digits ungrouped.
+    Uniform(usize),
+}
+
+impl Grouping {
+    /// The grouping of the current locale, as used by [`intcomma`].
+    pub fn for_locale() -> Self {
+        if i18n::indian_grouping() {
+            Grouping::Indian
+        } else {
+            Grouping::Thousands
+        }
+    }
+
+    /// Sizes of the lowest group and of every group above it.
+    fn sizes(self) -> (usize, usize) {
+        match self {
+            Grouping::Thousands => (3, 3),
+            Grouping::Indian => (3, 2),
+            Grouping::Uniform(size) => (size, size),
+        }
+    }
+}
+
+/// Like [`intcomma`], with an explicit digit grouping instead of the
+/// locale's.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::{intcomma_grouped, Grouping};
+/// assert_eq!(intcomma_grouped("123456789", None, Grouping::Indian), "12,34,56,789");
+/// assert_eq!(intcomma_grouped("-1234567.5", None, Grouping::Indian), "-12,34,567.5");
+/// ```
+pub fn intcomma_grouped(value: &str, ndigits: Option<usize>, grouping: Grouping) -> String {
+    intcomma_custom(value, ndigits, grouping, &i18n::thousands_separator())
+}
+
+/// Like [`intcomma_grouped`], but puts `separator` between the groups
+/// instead of the locale's thousands separator.
+///
+/// Input is still read with the locale's separators.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::{intcomma_custom, Grouping};
+/// assert_eq!(intcomma_custom("1234567", None, Grouping::Thousands, "'"), "1'234'567");
+/// assert_eq!(intcomma_custom("123456789", None, Grouping::Uniform(4), " "), "1 2345 6789");
+/// ```
+pub fn intcomma_custom(
+    value: &str,
+    ndigits: Option<usize>,
+    grouping: Grouping,
+    separator: &str,
+) -> String {
     let thousands_sep = i18n::thousands_separator();
     let decimal_sep = i18n::decimal_separator();
 
@@ -217,7 +910,13 @@ pub fn intcomma(value: &str, ndigits: Option<usize>) -> String {
         _ => {}
     }
 
-    let orig = if let Some(nd) = ndigits {
+    let orig = if let Some(int) = ExactDecimal::parse_integer(&cleaned) {
+        // Keep every digit of integers too large for i64/f64
+        match ndigits {
+            Some(nd) => int.round(nd).to_string(),
+            None => int.to_string(),
+        }
+    } else if let Some(nd) = ndigits {
         let f: f64 = cleaned.parse().unwrap_or(0.0);
         format!("{:.prec$}", f, prec = nd)
     } else if cleaned.contains('.') {
@@ -239,33 +938,118 @@ pub fn intcomma(value: &str, ndigits: Option<usize>) -> String {
             }
         }
     } else {
-        match cleaned.parse::<i64>() {
-            Ok(i) => i.to_string(),
-            Err(_) => {
-                match cleaned.parse::<f64>() {
-                    Ok(f) => format!("{}", f),
-                    Err(_) => return value.to_string(),
-                }
-            }
+        match cleaned.parse::<f64>() {
+            Ok(f) => format!("{}", f),
+            Err(_) => return value.to_string(),
         }
     };
 
     // Replace decimal point with locale-specific separator
     let orig = orig.replace('.', &decimal_sep);
+    group_digits(&orig, separator, grouping)
+}
 
-    // Insert thousands separators using regex (compiled once)
-    let mut result = orig;
-    loop {
-        let new = THOUSANDS_RE
-            .replace(&result, |caps: &regex::Captures| {
-                format!("{}{}{}", &caps[1], thousands_sep, &caps[2])
-            })
-            .to_string();
-        if new == result {
-            return result;
+/// Formats a number in the SI / ISO 31-0 style, grouping digits in threes
+/// with narrow no-break spaces (U+202F) on both sides of the decimal
+/// separator: "1 234 567.891 01".
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::intcomma_si;
+/// assert_eq!(intcomma_si("1234567.89101", None), "1\u{202f}234\u{202f}567.891\u{202f}01");
+/// assert_eq!(intcomma_si("-1000", Some(2)), "-1\u{202f}000.00");
+/// ```
+pub fn intcomma_si(value: &str, ndigits: Option<usize>) -> String {
+    const NARROW_NBSP: &str = "\u{202f}";
+    let grouped = intcomma_custom(value, ndigits, Grouping::Thousands, NARROW_NBSP);
+    let decimal_sep = i18n::decimal_separator();
+    match grouped.split_once(decimal_sep.as_str()) {
+        Some((integer, fraction)) if fraction.bytes().all(|b| b.is_ascii_digit()) => {
+            // The fraction is grouped from the decimal separator outwards
+            let mut out = format!("{}{}", integer, decimal_sep);
+            for (i, digit) in fraction.chars().enumerate() {
+                if i > 0 && i % 3 == 0 {
+                    out.push_str(NARROW_NBSP);
+                }
+                out.push(digit);
+            }
+            out
+        }
+        _ => grouped,
+    }
+}
+
+/// Insert thousands separators into the leading integer digits.
+pub(crate) fn group_thousands(orig: String, thousands_sep: &str) -> String {
+    group_digits(&orig, thousands_sep, Grouping::Thousands)
+}
+
+/// Insert `sep` between the groups of the leading integer digits of `orig`,
+/// leaving any sign and fractional part alone.
+fn group_digits(orig: &str, sep: &str, grouping: Grouping) -> String {
+    let (sign, unsigned) = match orig.strip_prefix('-') {
+        Some(unsigned) => ("-", unsigned),
+        None => ("", orig),
+    };
+    let end = unsigned
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(unsigned.len());
+    let (digits, tail) = unsigned.split_at(end);
+
+    let (lowest, size) = grouping.sizes();
+    if lowest == 0 || size == 0 || digits.len() <= lowest {
+        return orig.to_string();
+    }
+    let mut end = digits.len() - lowest;
+    let mut groups = vec![&digits[end..]];
+    while end > size {
+        groups.push(&digits[end - size..end]);
+        end -= size;
+    }
+    groups.push(&digits[..end]);
+    groups.reverse();
+    format!("{}{}{}", sign, groups.join(sep), tail)
+}
+
+/// Like [`intcomma`], but treats `value` as an exact decimal.
+///
+/// Rounding to `ndigits` is done on the decimal digits (half to even, like
+/// Python's `decimal.Decimal`) instead of going through `f64`, so
+/// high-precision values keep every digit. Exponent notation ("1.5E+25")
+/// is expanded.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::intcomma_exact;
+/// assert_eq!(intcomma_exact("12345678901234567890.125", Some(2)), "12,345,678,901,234,567,890.12");
+/// assert_eq!(intcomma_exact("1.5E+6", None), "1,500,000");
+/// ```
+pub fn intcomma_exact(value: &str, ndigits: Option<usize>) -> String {
+    let thousands_sep = i18n::thousands_separator();
+    let decimal_sep = i18n::decimal_separator();
+
+    let cleaned = value
+        .replace(&thousands_sep, "")
+        .replace(&decimal_sep, ".");
+
+    if let Ok(f) = cleaned.parse::<f64>() {
+        if !f.is_finite() {
+            return format_not_finite(f).unwrap();
         }
-        result = new;
     }
+
+    let decimal = match ExactDecimal::parse(&cleaned) {
+        Some(d) => d,
+        // Garbage, or an exponent too large to expand exactly
+        None => return intcomma(value, ndigits),
+    };
+    let decimal = match ndigits {
+        Some(nd) => decimal.round(nd),
+        None => decimal,
+    };
+
+    let orig = decimal.to_string().replace('.', &decimal_sep);
+    group_digits(&orig, &thousands_sep, Grouping::for_locale())
 }
 
 /// Converts a large integer to a friendly text representation.
@@ -280,105 +1064,727 @@ pub fn intcomma(value: &str, ndigits: Option<usize>) -> String {
 /// assert_eq!(intword("1200000000", "%.1f"), "1.2 billion");
 /// ```
 pub fn intword(value: &str, format: &str) -> String {
-    // Parse as f64, working directly with floats to avoid i128 overflow for
-    // values > 1.7e38 (like googol = 10^100)
-    let f_val: f64 = match value.replace('_', "").parse::<f64>() {
-        Ok(f) => f,
-        Err(_) => return value.to_string(),
+    intword_with(value, format, &SHORT_SCALE)
+}
+
+/// Like [`intword`], but treats `value` as an exact decimal.
+///
+/// The value is scaled by shifting digits and rounded half to even, so no
+/// precision is lost to `f64`. Only "%.Nf"-style formats are handled
+/// exactly; anything else falls back to [`intword`].
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::intword_exact;
+/// assert_eq!(intword_exact("1250000", "%.1f"), "1.2 million");
+/// assert_eq!(intword_exact("999950000", "%.1f"), "1.0 billion");
+/// ```
+pub fn intword_exact(value: &str, format: &str) -> String {
+    let precision = match printf_precision(format) {
+        Some(p) => p,
+        None => return intword(value, format),
+    };
+    let cleaned = value.replace('_', "");
+    if let Ok(f) = cleaned.parse::<f64>() {
+        if !f.is_finite() {
+            return format_not_finite(f).unwrap();
+        }
+    }
+    let decimal = match ExactDecimal::parse(&cleaned) {
+        Some(d) => d,
+        // Garbage, or an exponent too large to expand exactly
+        None => return intword(value, format),
     };
 
-    if !f_val.is_finite() {
-        return format_not_finite(f_val).unwrap();
+    let negative_prefix = if decimal.negative { "-" } else { "" };
+    let magnitude = decimal.integer.len() - 1;
+    if decimal.integer == "0" || magnitude < 3 {
+        return format!("{}{}", negative_prefix, decimal.integer);
     }
 
-    let negative = f_val < 0.0;
-    let abs_f64 = f_val.abs();
-    let negative_prefix = if negative { "-" } else { "" };
+    // Same ladder as `intword`: 10^3 .. 10^33, then googol.
+    let ordinal = if magnitude >= 100 {
+        HUMAN_POWERS_SINGULAR.len() - 1
+    } else {
+        (magnitude / 3 - 1).min(HUMAN_POWERS_SINGULAR.len() - 2)
+    };
+    let power = if ordinal == HUMAN_POWERS_SINGULAR.len() - 1 {
+        100
+    } else {
+        3 * (ordinal + 1)
+    };
 
-    if abs_f64 < 1000.0 {
-        // Display as integer for small values
-        return format!("{}{}", negative_prefix, abs_f64 as i64);
+    let mut rounded = decimal.shift_left(power).round(precision);
+    rounded.negative = false;
+    let mut final_ordinal = ordinal;
+    if ordinal + 1 < HUMAN_POWERS_SINGULAR.len() - 1
+        && rounded.integer == "1000"
+        && rounded.fraction.bytes().all(|b| b == b'0')
+    {
+        final_ordinal = ordinal + 1;
+        rounded.integer = "1".to_string();
     }
 
-    // Use f64 powers to avoid u128 overflow for googol (10^100)
-    let powers_f64: &[f64] = &[
-        1e3, 1e6, 1e9, 1e12, 1e15, 1e18, 1e21, 1e24, 1e27, 1e30, 1e33, 1e100,
-    ];
+    // ngettext wants ceil(value), which only matters for picking a plural form
+    let whole: i64 = rounded.integer.parse().unwrap_or(i64::MAX);
+    let plural_n = if rounded.fraction.bytes().any(|b| b != b'0') {
+        whole.saturating_add(1)
+    } else {
+        whole
+    };
 
-    // Find the right power
-    let ordinal = match powers_f64
-        .iter()
-        .position(|&p| p > abs_f64)
-    {
-        Some(0) => return format!("{}{}", negative_prefix, abs_f64 as i64),
-        Some(i) => i - 1,
-        None => powers_f64.len() - 1,
+    let singular = HUMAN_POWERS_SINGULAR[final_ordinal];
+    let plural = HUMAN_POWERS_PLURAL[final_ordinal];
+    let unit = i18n::ngettext(singular, plural, plural_n);
+    let decimal_sep = i18n::decimal_separator();
+    let number = rounded.to_string().replace('.', &decimal_sep);
+    format!("{}{} {}", negative_prefix, number, unit)
+}
+
+/// A value scaled down to one rung of a ladder of powers of ten.
+struct Scaled {
+    negative: bool,
+    /// Index of the rung in the ladder.
+    rung: usize,
+    /// The value divided by the rung's power, rounded as the format prints it.
+    value: f64,
+}
+
+/// Exactly 10^n as an `f64`, where repeated multiplication would drift.
+fn pow10(n: usize) -> f64 {
+    format!("1e{}", n).parse().unwrap()
+}
+
+/// Scale `value` to the highest of `exponents` (ascending powers of ten)
+/// it reaches, moving up a rung when rounding lands on the next power.
+///
+/// Values below the first rung, non-finite values and unparseable input
+/// come back as `Err` holding the text to return instead.
+fn scale_to_ladder(value: &str, format: &str, exponents: &[usize]) -> Result<Scaled, String> {
+    let cleaned = value.replace('_', "");
+    // Integral values, even in exponent notation, are scaled on their digits
+    let integral = ExactDecimal::parse_integer(&cleaned).or_else(|| {
+        ExactDecimal::parse_truncated(&cleaned).and_then(|(int, exact)| exact.then_some(int))
+    });
+    let (negative, rung, chopped) = if let Some(int) = integral {
+        let magnitude = int.integer.len() - 1;
+        let rung = match exponents.iter().rposition(|&e| e <= magnitude) {
+            Some(r) => r,
+            None => return Err(int.to_string()),
+        };
+        let negative = int.negative;
+        let mut chopped = int.shift_left(exponents[rung]);
+        chopped.negative = false;
+        let chopped: f64 = chopped.to_string().parse().unwrap_or(f64::INFINITY);
+        (negative, rung, chopped)
+    } else {
+        let f_val: f64 = cleaned.parse().map_err(|_| value.to_string())?;
+        if !f_val.is_finite() {
+            return Err(format_not_finite(f_val).unwrap());
+        }
+        let negative_prefix = if f_val < 0.0 { "-" } else { "" };
+        let abs_f64 = f_val.abs();
+        let rung = match exponents.iter().rposition(|&e| abs_f64 >= pow10(e)) {
+            Some(r) => r,
+            None => return Err(format!("{}{}", negative_prefix, abs_f64 as i64)),
+        };
+        (f_val < 0.0, rung, abs_f64 / pow10(exponents[rung]))
     };
 
-    let largest_ordinal = ordinal == powers_f64.len() - 1;
-    let power = powers_f64[ordinal];
-    let chopped = abs_f64 / power;
-    let rounded_value = printf_format(format, chopped)
+    let rounded = printf_format(format, chopped)
         .parse::<f64>()
         .unwrap_or(chopped);
+    if let Some(&next) = exponents.get(rung + 1) {
+        let step = pow10(next - exponents[rung]);
+        if rounded >= step {
+            return Ok(Scaled {
+                negative,
+                rung: rung + 1,
+                value: rounded / step,
+            });
+        }
+    }
+    Ok(Scaled {
+        negative,
+        rung,
+        value: rounded,
+    })
+}
 
-    let (final_ordinal, final_value) = if !largest_ordinal
-        && ordinal + 1 < powers_f64.len()
-        && (rounded_value * power - powers_f64[ordinal + 1]).abs() < 1.0
-    {
-        (ordinal + 1, 1.0)
+/// A table of named powers of ten for [`intword_with`].
+#[derive(Debug, Clone, Copy)]
+pub struct PowerNames<'a> {
+    /// `(exponent, singular, plural)`, in ascending order of exponent.
+    pub powers: &'a [(usize, &'a str, &'a str)],
+    /// Translation context for the names, where the bare msgid would clash
+    /// with another table's.
+    pub context: Option<&'a str>,
+    /// Placed between the number and the name.
+    pub separator: &'a str,
+    /// Values smaller than this in magnitude are left as plain integers,
+    /// as are those below the first power.
+    pub threshold: f64,
+}
+
+/// The short scale used by [`intword`]: a billion is 10^9.
+pub const SHORT_SCALE: PowerNames<'static> = PowerNames {
+    powers: &[
+        (3, "thousand", "thousand"),
+        (6, "million", "million"),
+        (9, "billion", "billion"),
+        (12, "trillion", "trillion"),
+        (15, "quadrillion", "quadrillion"),
+        (18, "quintillion", "quintillion"),
+        (21, "sextillion", "sextillion"),
+        (24, "septillion", "septillion"),
+        (27, "octillion", "octillion"),
+        (30, "nonillion", "nonillion"),
+        (33, "decillion", "decillion"),
+        (100, "googol", "googol"),
+    ],
+    context: None,
+    separator: " ",
+    threshold: 0.0,
+};
+
+/// The long scale of many European languages: 10^9 is a milliard and a
+/// billion is 10^12.
+pub const LONG_SCALE: PowerNames<'static> = PowerNames {
+    powers: &[
+        (3, "thousand", "thousand"),
+        (6, "million", "million"),
+        (9, "milliard", "milliard"),
+        (12, "billion", "billion"),
+        (15, "billiard", "billiard"),
+        (18, "trillion", "trillion"),
+        (21, "trilliard", "trilliard"),
+        (24, "quadrillion", "quadrillion"),
+        (27, "quadrilliard", "quadrilliard"),
+        (30, "quintillion", "quintillion"),
+        (33, "quintilliard", "quintilliard"),
+        (100, "googol", "googol"),
+    ],
+    context: Some("long scale"),
+    separator: " ",
+    threshold: 0.0,
+};
+
+/// The Indian numbering system: a lakh is 10^5 and a crore 10^7. Larger
+/// values are counted in crore.
+pub const INDIAN_SCALE: PowerNames<'static> = PowerNames {
+    powers: &[
+        (3, "thousand", "thousand"),
+        (5, "lakh", "lakh"),
+        (7, "crore", "crore"),
+    ],
+    context: None,
+    separator: " ",
+    threshold: 0.0,
+};
+
+/// Chinese and Japanese grouping by powers of 10^4, written without a
+/// space: "1.23億". The names are the Japanese forms; Chinese locales
+/// translate them (億 becomes 亿 in zh_CN).
+pub const EAST_ASIAN_SCALE: PowerNames<'static> = PowerNames {
+    powers: &[
+        (4, "万", "万"),
+        (8, "億", "億"),
+        (12, "兆", "兆"),
+        (16, "京", "京"),
+    ],
+    context: Some("East Asian scale"),
+    separator: "",
+    threshold: 0.0,
+};
+
+/// Like [`intword`], naming the powers from `names` instead of the short
+/// scale.
+///
+/// Names are translated with the table's context, so a locale can spell
+/// the long-scale "billion" differently from the short-scale one. Callers
+/// can build their own table, starting from one of the constants.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::{intword_with, PowerNames, LONG_SCALE, SHORT_SCALE};
+/// assert_eq!(intword_with("1200000000", "%.1f", &LONG_SCALE), "1.2 milliard");
+/// assert_eq!(intword_with("3000000000000", "%.1f", &LONG_SCALE), "3.0 billion");
+///
+/// let british = PowerNames {
+///     powers: &[
+///         (3, "thousand", "thousand"),
+///         (6, "million", "million"),
+///         (9, "thousand million", "thousand million"),
+///     ],
+///     threshold: 100_000.0,
+///     ..SHORT_SCALE
+/// };
+/// assert_eq!(intword_with("25000", "%.1f", &british), "25000");
+/// assert_eq!(intword_with("250000", "%.1f", &british), "250.0 thousand");
+/// assert_eq!(intword_with("4500000000", "%.1f", &british), "4.5 thousand million");
+/// ```
+pub fn intword_with(value: &str, format: &str, names: &PowerNames) -> String {
+    let below_threshold = value
+        .replace('_', "")
+        .parse::<f64>()
+        .is_ok_and(|f| f.abs() < names.threshold);
+    // An empty ladder leaves the value as it is
+    let exponents: Vec<usize> = if below_threshold {
+        Vec::new()
+    } else {
+        names.powers.iter().map(|&(e, _, _)| e).collect()
+    };
+    let scaled = match scale_to_ladder(value, format, &exponents) {
+        Ok(scaled) => scaled,
+        Err(text) => return text,
+    };
+
+    let (_, singular, plural) = names.powers[scaled.rung];
+    let n = scaled.value.ceil() as i64;
+    let unit = match names.context {
+        Some(context) => i18n::npgettext(context, singular, plural, n),
+        None => i18n::ngettext(singular, plural, n),
+    };
+    let number = printf_format(format, scaled.value).replace('.', &i18n::decimal_separator());
+    format!(
+        "{}{}{}{}",
+        if scaled.negative { "-" } else { "" },
+        number,
+        names.separator,
+        unit
+    )
+}
+
+/// Round `value` to `figures` significant figures, half to even, grouping
+/// the digits like [`intcomma`].
+///
+/// Significant trailing zeros are kept in the fraction ("0.500"). A number
+/// always keeps at least one figure, so `figures` of 0 rounds like 1.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::sigfig;
+/// assert_eq!(sigfig("12345", 3), "12,300");
+/// assert_eq!(sigfig("0.0045612", 3), "0.00456");
+/// assert_eq!(sigfig("0.5", 3), "0.500");
+/// assert_eq!(sigfig("12345", 0), "10,000");
+/// ```
+pub fn sigfig(value: &str, figures: usize) -> String {
+    let figures = figures.max(1);
+    match round_significant(value, figures) {
+        Ok(rounded) => group_digits(
+            &rounded.to_string().replace('.', &i18n::decimal_separator()),
+            &i18n::thousands_separator(),
+            Grouping::for_locale(),
+        ),
+        Err(text) => text,
+    }
+}
+
+/// Like [`intword`], but rounds to `figures` significant figures instead of
+/// a fixed number of decimals: "1.23 thousand", "12.3 thousand". Values
+/// below a thousand are formatted like [`sigfig`]. As there, `figures` of 0
+/// rounds like 1.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::intword_sigfig;
+/// assert_eq!(intword_sigfig("1234", 3), "1.23 thousand");
+/// assert_eq!(intword_sigfig("123456789", 2), "120 million");
+/// assert_eq!(intword_sigfig("0.0045612", 3), "0.00456");
+/// ```
+pub fn intword_sigfig(value: &str, figures: usize) -> String {
+    let figures = figures.max(1);
+    let rounded = match round_significant(value, figures) {
+        Ok(rounded) => rounded,
+        Err(text) => return text,
+    };
+    let magnitude = rounded.magnitude();
+    let Some(&(power, _, _)) = SHORT_SCALE
+        .powers
+        .iter()
+        .rev()
+        .find(|&&(power, _, _)| power as i64 <= magnitude)
+    else {
+        return sigfig(value, figures);
+    };
+    // Show as many decimals as the figures left after the integer digits
+    let integer_digits = magnitude - power as i64 + 1;
+    let decimals = (figures as i64 - integer_digits).max(0);
+    intword(&rounded.to_string(), &format!("%.{}f", decimals))
+}
+
+/// Parse `value` and round it to `figures` significant figures, or give
+/// back the text to return instead.
+fn round_significant(value: &str, figures: usize) -> Result<ExactDecimal, String> {
+    let f: f64 = value.trim().parse().map_err(|_| value.to_string())?;
+    if !f.is_finite() {
+        return Err(format_not_finite(f).unwrap());
+    }
+    if f == 0.0 {
+        return Ok(ExactDecimal::parse_integer("0").unwrap());
+    }
+    ExactDecimal::parse(value)
+        .map(|decimal| decimal.round_significant(figures))
+        .ok_or_else(|| value.to_string())
+}
+
+/// Suffixes for thousand, million, billion and trillion, as used by
+/// [`intword_compact`].
+pub const COMPACT_SUFFIXES: &[&str] = &["K", "M", "B", "T"];
+
+/// Like [`intword`], but abbreviates the power to a suffix: "1.2B", "950K".
+///
+/// `suffixes` name successive powers of 1000, starting at a thousand; values
+/// past the last one stay on it. With `lowercase` the suffix is lowercased
+/// ("1.2k"). Trailing zeros after the decimal point are dropped.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::{intword_compact, COMPACT_SUFFIXES};
+/// assert_eq!(intword_compact("1200000000", "%.1f", COMPACT_SUFFIXES, false), "1.2B");
+/// assert_eq!(intword_compact("950000", "%.1f", COMPACT_SUFFIXES, false), "950K");
+/// assert_eq!(intword_compact("1234", "%.1f", COMPACT_SUFFIXES, true), "1.2k");
+/// ```
+pub fn intword_compact(value: &str, format: &str, suffixes: &[&str], lowercase: bool) -> String {
+    let exponents: Vec<usize> = (1..=suffixes.len()).map(|i| 3 * i).collect();
+    let scaled = match scale_to_ladder(value, format, &exponents) {
+        Ok(scaled) => scaled,
+        Err(text) => return text,
+    };
+
+    let mut number = printf_format(format, scaled.value);
+    if number.contains('.') {
+        number = number
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string();
+    }
+    let suffix = if lowercase {
+        suffixes[scaled.rung].to_lowercase()
     } else {
-        (ordinal, rounded_value)
+        suffixes[scaled.rung].to_string()
+    };
+    format!(
+        "{}{}{}",
+        if scaled.negative { "-" } else { "" },
+        number.replace('.', &i18n::decimal_separator()),
+        suffix
+    )
+}
+
+/// Converts an integer to Associated Press style.
+///
+/// For numbers 0-9, returns the word. Otherwise returns the number as string.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::apnumber;
+/// assert_eq!(apnumber("0"), "zero");
+/// assert_eq!(apnumber("5"), "five");
+/// assert_eq!(apnumber("10"), "10");
+/// ```
+pub fn apnumber(value: &str) -> String {
+    if let Ok(f) = value.parse::<f64>() {
+        if !f.is_finite() {
+            return format_not_finite(f).unwrap();
+        }
+    }
+
+    let int_val: i64 = match value.parse::<f64>() {
+        Ok(f) => f as i64,
+        Err(_) => return value.to_string(),
     };
 
-    let singular = HUMAN_POWERS_SINGULAR[final_ordinal];
-    let plural = HUMAN_POWERS_PLURAL[final_ordinal];
-    let unit = i18n::ngettext(singular, plural, final_value.ceil() as i64);
-    let decimal_sep = i18n::decimal_separator();
-    let number = printf_format(format, final_value).replace('.', &decimal_sep);
-    format!("{}{} {}", negative_prefix, number, unit)
+    if !(0..10).contains(&int_val) {
+        return int_val.to_string();
+    }
+    digit_word(int_val as usize)
+}
+
+/// The word for a single digit, like "five".
+fn digit_word(digit: usize) -> String {
+    let words = [
+        i18n::gettext("zero"),
+        i18n::gettext("one"),
+        i18n::gettext("two"),
+        i18n::gettext("three"),
+        i18n::gettext("four"),
+        i18n::gettext("five"),
+        i18n::gettext("six"),
+        i18n::gettext("seven"),
+        i18n::gettext("eight"),
+        i18n::gettext("nine"),
+    ];
+    words[digit].clone()
+}
+
+/// A Rust number for the typed wrappers ([`ordinal_num`], [`intcomma_num`],
+/// [`intword_num`], [`apnumber_num`] and [`scientific_num`]), which spare
+/// callers formatting the value as text for the `&str` functions to parse
+/// back.
+///
+/// Every primitive integer up to 128 bits converts losslessly, as do `f32`
+/// and `f64`. With the `num-bigint` feature, so do `BigInt` and `BigUint`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumInput(NumRepr);
+
+#[derive(Debug, Clone, PartialEq)]
+enum NumRepr {
+    Int(i128),
+    UInt(u128),
+    Float(f64),
+    /// An integer beyond 128 bits.
+    #[cfg(feature = "num-bigint")]
+    Big(ExactDecimal),
+}
+
+macro_rules! num_input_from {
+    ($variant:ident, $wide:ty: $($t:ty),*) => {
+        $(
+            impl From<$t> for NumInput {
+                fn from(value: $t) -> Self {
+                    NumInput(NumRepr::$variant(<$wide>::from(value)))
+                }
+            }
+        )*
+    };
+}
+
+num_input_from!(Int, i128: i8, i16, i32, i64);
+num_input_from!(UInt, u128: u8, u16, u32, u64);
+
+impl From<i128> for NumInput {
+    fn from(value: i128) -> Self {
+        NumInput(NumRepr::Int(value))
+    }
+}
+
+impl From<u128> for NumInput {
+    fn from(value: u128) -> Self {
+        NumInput(NumRepr::UInt(value))
+    }
+}
+
+impl From<f64> for NumInput {
+    fn from(value: f64) -> Self {
+        NumInput(NumRepr::Float(value))
+    }
+}
+
+impl From<f32> for NumInput {
+    fn from(value: f32) -> Self {
+        // Widen through the shortest decimal, so 0.1f32 stays 0.1
+        let widened = value.to_string().parse().unwrap_or(f64::NAN);
+        NumInput(NumRepr::Float(widened))
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl From<num_bigint::BigInt> for NumInput {
+    fn from(value: num_bigint::BigInt) -> Self {
+        NumInput::from(&value)
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl From<&num_bigint::BigInt> for NumInput {
+    fn from(value: &num_bigint::BigInt) -> Self {
+        let digits = value.to_string();
+        match digits.parse() {
+            Ok(i) => NumInput(NumRepr::Int(i)),
+            Err(_) => NumInput(NumRepr::Big(ExactDecimal::parse_integer(&digits).unwrap())),
+        }
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl From<num_bigint::BigUint> for NumInput {
+    fn from(value: num_bigint::BigUint) -> Self {
+        NumInput::from(&num_bigint::BigInt::from(value))
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl From<&num_bigint::BigUint> for NumInput {
+    fn from(value: &num_bigint::BigUint) -> Self {
+        NumInput::from(value.clone())
+    }
+}
+
+impl NumInput {
+    /// Sign and magnitude, for integers of up to 128 bits.
+    fn integer(&self) -> Option<(bool, u128)> {
+        match self.0 {
+            NumRepr::Int(i) => Some((i < 0, i.unsigned_abs())),
+            NumRepr::UInt(u) => Some((false, u)),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for NumInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            NumRepr::Int(i) => write!(f, "{}", i),
+            NumRepr::UInt(u) => write!(f, "{}", u),
+            NumRepr::Float(x) => write!(f, "{}", x),
+            #[cfg(feature = "num-bigint")]
+            NumRepr::Big(d) => write!(f, "{}", d),
+        }
+    }
+}
+
+/// Like [`ordinal`], for a Rust number.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::ordinal_num;
+/// assert_eq!(ordinal_num(112u8), "112th");
+/// assert_eq!(ordinal_num(-21i64), "-21st");
+/// ```
+pub fn ordinal_num(value: impl Into<NumInput>) -> String {
+    let value = value.into();
+    match value.integer() {
+        Some((negative, magnitude)) => format!(
+            "{}{}{}",
+            if negative { "-" } else { "" },
+            magnitude,
+            ordinal_suffix((magnitude % 100) as u64, "male")
+        ),
+        None => ordinal(&value.to_string()),
+    }
+}
+
+/// Like [`intcomma`], for a Rust number.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::intcomma_num;
+/// assert_eq!(intcomma_num(1234567u32, None), "1,234,567");
+/// assert_eq!(intcomma_num(1234.5f64, Some(2)), "1,234.50");
+/// ```
+pub fn intcomma_num(value: impl Into<NumInput>, ndigits: Option<usize>) -> String {
+    let value = value.into();
+    match (value.integer(), ndigits) {
+        (Some(_), None) => group_digits(
+            &value.to_string(),
+            &i18n::thousands_separator(),
+            Grouping::for_locale(),
+        ),
+        _ => intcomma(&value.to_string(), ndigits),
+    }
+}
+
+/// Like [`intword`], for a Rust number.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::intword_num;
+/// assert_eq!(intword_num(1_200_000_000u64, "%.1f"), "1.2 billion");
+/// ```
+pub fn intword_num(value: impl Into<NumInput>, format: &str) -> String {
+    intword(&value.into().to_string(), format)
+}
+
+/// Like [`apnumber`], for a Rust number.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::apnumber_num;
+/// assert_eq!(apnumber_num(7u8), "seven");
+/// assert_eq!(apnumber_num(12i32), "12");
+/// ```
+pub fn apnumber_num(value: impl Into<NumInput>) -> String {
+    let value = value.into();
+    match (&value.0, value.integer()) {
+        (_, Some((false, digit))) if digit < 10 => digit_word(digit as usize),
+        (NumRepr::Float(_), _) => apnumber(&value.to_string()),
+        _ => value.to_string(),
+    }
 }
 
-/// Converts an integer to Associated Press style.
+/// Like [`scientific`], for a Rust number. Integers are rounded on their
+/// digits, so even ones far beyond `f64` come out exactly.
 ///
-/// For numbers 0-9, returns the word. Otherwise returns the number as string.
+/// # Examples
+/// ```
+/// use speakhuman::number::scientific_num;
+/// assert_eq!(scientific_num(u64::MAX, 3), "1.845 x 10¹⁹");
+/// assert_eq!(scientific_num(0.3f64, 2), "3.00 x 10⁻¹");
+/// ```
+pub fn scientific_num(value: impl Into<NumInput>, precision: usize) -> String {
+    scientific_num_styled(value, precision, ScientificStyle::Unicode)
+}
+
+/// Like [`scientific_styled`], for a Rust number.
 ///
 /// # Examples
 /// ```
-/// use speakhuman::number::apnumber;
-/// assert_eq!(apnumber("0"), "zero");
-/// assert_eq!(apnumber("5"), "five");
-/// assert_eq!(apnumber("10"), "10");
+/// use speakhuman::number::{scientific_num_styled, ScientificStyle};
+/// assert_eq!(scientific_num_styled(u64::MAX, 3, ScientificStyle::Ascii), "1.845e19");
 /// ```
-pub fn apnumber(value: &str) -> String {
-    if let Ok(f) = value.parse::<f64>() {
-        if !f.is_finite() {
-            return format_not_finite(f).unwrap();
-        }
+pub fn scientific_num_styled(
+    value: impl Into<NumInput>,
+    precision: usize,
+    style: ScientificStyle,
+) -> String {
+    match scientific_num_parts(value.into(), precision) {
+        Ok((mantissa, exponent)) => format_scientific(&mantissa, exponent, style),
+        Err(text) => text,
     }
+}
 
-    let int_val: i64 = match value.parse::<f64>() {
-        Ok(f) => f as i64,
-        Err(_) => return value.to_string(),
-    };
+/// Like [`engineering_styled`], for a Rust number.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::{engineering_num_styled, ScientificStyle};
+/// assert_eq!(engineering_num_styled(33500u32, 2, ScientificStyle::Unicode), "33.5 x 10³");
+/// assert_eq!(engineering_num_styled(i128::MAX, 2, ScientificStyle::Ascii), "170e36");
+/// ```
+pub fn engineering_num_styled(
+    value: impl Into<NumInput>,
+    precision: usize,
+    style: ScientificStyle,
+) -> String {
+    match scientific_num_parts(value.into(), precision) {
+        Ok((mantissa, exponent)) => format_engineering(mantissa, &exponent, style),
+        Err(text) => text,
+    }
+}
 
-    if !(0..10).contains(&int_val) {
-        return int_val.to_string();
+/// [`scientific_parts`] for a Rust number, rounding integers on their digits.
+fn scientific_num_parts(value: NumInput, precision: usize) -> Result<(String, String), String> {
+    let text = value.to_string();
+    match (&value.0, ExactDecimal::parse_integer(&text)) {
+        (NumRepr::Float(_), _) | (_, None) => scientific_parts(&text, precision),
+        (_, Some(int)) => Ok(scientific_exact(int, precision)),
     }
+}
 
-    let words = [
-        i18n::gettext("zero"),
-        i18n::gettext("one"),
-        i18n::gettext("two"),
-        i18n::gettext("three"),
-        i18n::gettext("four"),
-        i18n::gettext("five"),
-        i18n::gettext("six"),
-        i18n::gettext("seven"),
-        i18n::gettext("eight"),
-        i18n::gettext("nine"),
-    ];
-    words[int_val as usize].clone()
+/// Spell an integer out in English words, for checks and generated
+/// documents: "123" becomes "one hundred twenty-three".
+///
+/// Digits may be grouped with commas or underscores. Integers too large for
+/// the named scales are read digit by digit, as in the
+/// [verbal](crate::verbal) output style, which uses the same words.
+/// Anything that isn't an integer is returned unchanged. The words are
+/// English whatever the active locale.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::spell;
+/// assert_eq!(spell("123"), "one hundred twenty-three");
+/// assert_eq!(spell("-1,000,001"), "minus one million one");
+/// assert_eq!(spell("1.5"), "1.5");
+/// ```
+pub fn spell(value: &str) -> String {
+    let digits = value.strip_prefix(['-', '+']).unwrap_or(value);
+    if digits.contains('.') {
+        return value.to_string();
+    }
+    verbal::spell_number(value).unwrap_or_else(|| value.to_string())
 }
 
 /// Convert to fractional number.
@@ -417,6 +1823,89 @@ pub fn fractional(value: &str) -> String {
     format!("{} {}/{}", whole_number, numerator, denominator)
 }
 
+/// Convert an exact ratio to a fractional number.
+///
+/// Unlike [`fractional`], no float is involved: the whole part and remainder
+/// are computed on integers, and only remainders with a denominator above
+/// 1000 are approximated (the same limit `fractional` uses).
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::fractional_ratio;
+/// assert_eq!(fractional_ratio(4, 3), "1 1/3");
+/// assert_eq!(fractional_ratio(3, 10), "3/10");
+/// assert_eq!(fractional_ratio(-3, 2), "-1 -1/2");
+/// assert_eq!(fractional_ratio(10, 5), "2");
+/// ```
+pub fn fractional_ratio(numerator: i128, denominator: i128) -> String {
+    if denominator == 0 {
+        return format!("{}/{}", numerator, denominator);
+    }
+    let (numerator, denominator) = if denominator < 0 {
+        (-numerator, -denominator)
+    } else {
+        (numerator, denominator)
+    };
+
+    let whole_number = numerator / denominator;
+    let remainder = numerator % denominator;
+    let (frac_numer, frac_denom) = limit_denominator(remainder, denominator, 1000);
+
+    if whole_number != 0 && frac_numer == 0 {
+        return format!("{}", whole_number);
+    }
+
+    if whole_number == 0 {
+        return format!("{}/{}", frac_numer, frac_denom);
+    }
+
+    format!("{} {}/{}", whole_number, frac_numer, frac_denom)
+}
+
+/// Closest fraction to `numerator/denominator` with a denominator of at most
+/// `max_denom`, mirroring Python's `Fraction.limit_denominator`.
+fn limit_denominator(numerator: i128, denominator: i128, max_denom: i128) -> (i128, i128) {
+    let divisor = gcd(numerator, denominator);
+    let (numerator, denominator) = (numerator / divisor, denominator / divisor);
+    if denominator <= max_denom {
+        return (numerator, denominator);
+    }
+
+    let negative = numerator < 0;
+    let (mut n, mut d) = (numerator.abs(), denominator);
+    let (mut p0, mut q0, mut p1, mut q1) = (0i128, 1i128, 1i128, 0i128);
+    loop {
+        let a = n / d;
+        let q2 = q0 + a * q1;
+        if q2 > max_denom {
+            break;
+        }
+        (p0, q0, p1, q1) = (p1, q1, p0 + a * p1, q2);
+        (n, d) = (d, n - a * d);
+    }
+
+    let k = (max_denom - q0) / q1;
+    // Pick the closer of the two candidate bounds (ties go to p1/q1)
+    let closer_to_p1 = d
+        .checked_mul(2)
+        .and_then(|v| v.checked_mul(q0 + k * q1))
+        .is_some_and(|v| v <= denominator);
+    let (p, q) = if closer_to_p1 {
+        (p1, q1)
+    } else {
+        (p0 + k * p1, q0 + k * q1)
+    };
+    (if negative { -p } else { p }, q)
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.max(1)
+}
+
 /// Return number in string scientific notation z.wq x 10^n.
 ///
 /// Uses Unicode superscript characters for the exponent.
@@ -428,13 +1917,94 @@ pub fn fractional(value: &str) -> String {
 /// assert_eq!(scientific("0.3", 2), "3.00 x 10⁻¹");
 /// ```
 pub fn scientific(value: &str, precision: usize) -> String {
+    scientific_styled(value, precision, ScientificStyle::Unicode)
+}
+
+/// How [`scientific_styled`] and [`engineering_styled`] write the power of
+/// ten.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScientificStyle {
+    /// Unicode superscripts: "3.35 x 10⁴". Spelled out in the verbal style.
+    Unicode,
+    /// Plain ASCII E notation: "3.35e4".
+    Ascii,
+    /// LaTeX math: "3.35 \times 10^{4}".
+    Latex,
+    /// HTML markup: "3.35 × 10<sup>4</sup>".
+    Html,
+}
+
+/// Like [`scientific`], writing the power of ten in the given style.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::{scientific_styled, ScientificStyle};
+/// assert_eq!(scientific_styled("33500", 2, ScientificStyle::Ascii), "3.35e4");
+/// assert_eq!(scientific_styled("33500", 2, ScientificStyle::Latex), "3.35 \\times 10^{4}");
+/// assert_eq!(scientific_styled("0.3", 1, ScientificStyle::Html), "3.0 × 10<sup>-1</sup>");
+/// ```
+pub fn scientific_styled(value: &str, precision: usize, style: ScientificStyle) -> String {
+    match scientific_parts(value, precision) {
+        Ok((mantissa, exponent)) => format_scientific(&mantissa, exponent, style),
+        Err(text) => text,
+    }
+}
+
+/// Express `value` in engineering notation, where the exponent is always a
+/// multiple of three ("33.5 x 10³").
+///
+/// The mantissa keeps the `precision + 1` significant figures that
+/// [`scientific`] would show, so it has up to three integer digits.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::engineering;
+/// assert_eq!(engineering("33500", 2), "33.5 x 10³");
+/// assert_eq!(engineering("0.00042", 1), "420 x 10⁻⁶");
+/// ```
+pub fn engineering(value: &str, precision: usize) -> String {
+    engineering_styled(value, precision, ScientificStyle::Unicode)
+}
+
+/// Like [`engineering`], writing the power of ten in the given style.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::{engineering_styled, ScientificStyle};
+/// assert_eq!(engineering_styled("33500", 2, ScientificStyle::Ascii), "33.5e3");
+/// ```
+pub fn engineering_styled(value: &str, precision: usize, style: ScientificStyle) -> String {
+    match scientific_parts(value, precision) {
+        Ok((mantissa, exponent)) => format_engineering(mantissa, &exponent, style),
+        Err(text) => text,
+    }
+}
+
+/// Move the decimal point of a [`scientific_parts`] mantissa so that the
+/// exponent is a multiple of three, and format the result.
+fn format_engineering(mantissa: String, exponent: &str, style: ScientificStyle) -> String {
+    let exponent: i64 = exponent.parse().unwrap_or(0);
+    let shift = exponent.rem_euclid(3);
+    let mantissa = match ExactDecimal::parse(&mantissa) {
+        Some(decimal) => decimal.shift_right(shift as usize).to_string(),
+        None => mantissa,
+    };
+    format_scientific(&mantissa, (exponent - shift).to_string(), style)
+}
+
+/// Split `value` into the mantissa and exponent [`scientific`] shows, or
+/// give back the text to return instead.
+fn scientific_parts(value: &str, precision: usize) -> Result<(String, String), String> {
     let f: f64 = match value.parse() {
         Ok(v) => v,
-        Err(_) => return value.to_string(),
+        Err(_) => return Err(value.to_string()),
     };
 
     if !f.is_finite() {
-        return format_not_finite(f).unwrap();
+        if verbal::is_verbal() {
+            return Err(verbal::spell_not_finite(f));
+        }
+        return Err(format_not_finite(f).unwrap());
     }
 
     let formatted = format!("{:.prec$e}", f, prec = precision);
@@ -442,24 +2012,63 @@ pub fn scientific(value: &str, precision: usize) -> String {
     // Rust formats as "1.00e2" or "1.00e-2", we need to split on 'e'
     let parts: Vec<&str> = formatted.split('e').collect();
     if parts.len() != 2 {
-        return formatted;
+        return Err(formatted);
     }
 
     let mantissa = parts[0];
     let mut exp_str = parts[1].to_string();
 
     // Remove leading '+' and leading zeros (but keep at least one digit)
-    if exp_str.starts_with('+') {
-        exp_str = exp_str[1..].to_string();
+    if let Some(rest) = exp_str.strip_prefix('+') {
+        exp_str = rest.to_string();
     }
     // Remove leading zeros but keep the sign
-    let (sign, digits) = if exp_str.starts_with('-') {
-        ("-", exp_str[1..].trim_start_matches('0'))
+    let (sign, digits) = if let Some(rest) = exp_str.strip_prefix('-') {
+        ("-", rest.trim_start_matches('0'))
     } else {
         ("", exp_str.trim_start_matches('0'))
     };
     let digits = if digits.is_empty() { "0" } else { digits };
     let exp_clean = format!("{}{}", sign, digits);
+    Ok((mantissa.to_string(), exp_clean))
+}
+
+/// Mantissa and exponent of an integer in scientific notation, rounding
+/// the mantissa on its digits.
+fn scientific_exact(int: ExactDecimal, precision: usize) -> (String, String) {
+    let negative_prefix = if int.negative { "-" } else { "" };
+    let mut exponent = int.integer.len() - 1;
+    let mut mantissa = ExactDecimal {
+        negative: false,
+        ..int
+    }
+    .shift_left(exponent)
+    .round(precision);
+    // 9.99 can round up to 10.0
+    if mantissa.integer == "10" {
+        mantissa = mantissa.shift_left(1).round(precision);
+        exponent += 1;
+    }
+    (
+        format!("{}{}", negative_prefix, mantissa),
+        exponent.to_string(),
+    )
+}
+
+/// Join a mantissa and a decimal exponent as "z.wq x 10ⁿ".
+fn format_scientific(mantissa: &str, exp_clean: String, style: ScientificStyle) -> String {
+    match style {
+        ScientificStyle::Unicode => {}
+        ScientificStyle::Ascii => return format!("{}e{}", mantissa, exp_clean),
+        ScientificStyle::Latex => return format!("{} \\times 10^{{{}}}", mantissa, exp_clean),
+        ScientificStyle::Html => return format!("{} × 10<sup>{}</sup>", mantissa, exp_clean),
+    }
+
+    if verbal::is_verbal() {
+        let (mantissa, _) = verbal::spell_quantity(mantissa);
+        let exponent = verbal::spell_number(&exp_clean).unwrap_or(exp_clean);
+        return format!("{} times ten to the power of {}", mantissa, exponent);
+    }
 
     let exponent_map: std::collections::HashMap<char, char> = [
         ('0', '\u{2070}'),
@@ -553,8 +2162,6 @@ pub fn clamp(
                     0
                 };
                 format!("{:.prec$}%", clamped * 100.0, prec = precision)
-            } else if fmt == "{:}" {
-                format!("{}", clamped)
             } else {
                 format!("{}", clamped)
             }
@@ -565,8 +2172,34 @@ pub fn clamp(
     Some(format!("{}{}", token, formatted))
 }
 
+/// SI prefixes as (power of 1000, symbol), largest first.
+const METRIC_PREFIXES: [(i32, char); 20] = [
+    (10, 'Q'),
+    (9, 'R'),
+    (8, 'Y'),
+    (7, 'Z'),
+    (6, 'E'),
+    (5, 'P'),
+    (4, 'T'),
+    (3, 'G'),
+    (2, 'M'),
+    (1, 'k'),
+    (-1, 'm'),
+    (-2, '\u{03BC}'),
+    (-3, 'n'),
+    (-4, 'p'),
+    (-5, 'f'),
+    (-6, 'a'),
+    (-7, 'z'),
+    (-8, 'y'),
+    (-9, 'r'),
+    (-10, 'q'),
+];
+
 /// Return a value with a metric SI unit-prefix appended.
 ///
+/// The number uses the locale's decimal separator ("1,50 kV" in de_DE).
+///
 /// # Examples
 /// ```
 /// use speakhuman::number::metric;
@@ -574,7 +2207,51 @@ pub fn clamp(
 /// assert_eq!(metric(200_000.0, "", 3), "200 k");
 /// ```
 pub fn metric(value: f64, unit: &str, precision: usize) -> String {
+    metric_with_prefixes(value, unit, precision, "kMGTPEZYRQm\u{03BC}npfazyrq")
+}
+
+/// Like [`metric`], but only use the SI prefixes listed in `prefixes`.
+///
+/// The value is scaled to the largest allowed prefix that doesn't exceed its
+/// magnitude (the bare unit is always allowed), so restricting to `"kM"`
+/// renders 5 GW as "5000 MW". Prefixes are matched by symbol; "u" is accepted
+/// for micro.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::metric_with_prefixes;
+/// assert_eq!(metric_with_prefixes(5e9, "W", 3, "kM"), "5000 MW");
+/// assert_eq!(metric_with_prefixes(0.0025, "m", 3, "k"), "0.00250 m");
+/// assert_eq!(metric_with_prefixes(2.5e-6, "F", 3, "mu"), "2.50 \u{03BC}F");
+/// ```
+pub fn metric_with_prefixes(value: f64, unit: &str, precision: usize, prefixes: &str) -> String {
+    metric_formatted(value, unit, precision, prefixes, false)
+}
+
+/// Like [`metric_with_prefixes`], but groups the integer digits of the
+/// number like [`intcomma`], for prefix sets that leave it large.
+///
+/// # Examples
+/// ```
+/// use speakhuman::number::metric_grouped;
+/// assert_eq!(metric_grouped(2e8, "B", 3, ""), "200,000,000 B");
+/// assert_eq!(metric_grouped(5e9, "W", 3, "kM"), "5,000 MW");
+/// ```
+pub fn metric_grouped(value: f64, unit: &str, precision: usize, prefixes: &str) -> String {
+    metric_formatted(value, unit, precision, prefixes, true)
+}
+
+fn metric_formatted(
+    value: f64,
+    unit: &str,
+    precision: usize,
+    prefixes: &str,
+    grouped: bool,
+) -> String {
     if !value.is_finite() {
+        if verbal::is_verbal() {
+            return verbal::spell_not_finite(value);
+        }
         return format_not_finite(value).unwrap();
     }
 
@@ -584,44 +2261,68 @@ pub fn metric(value: f64, unit: &str, precision: usize) -> String {
         0
     };
 
-    if exponent >= 33 || exponent < -30 {
+    if !(-30..33).contains(&exponent) {
         let s = scientific(&value.to_string(), precision.saturating_sub(1));
+        if verbal::is_verbal() && !unit.is_empty() {
+            return format!("{} {}", s, verbal::unit_name(unit).1);
+        }
         return format!("{}{}", s, unit);
     }
 
-    // Python uses floor division for negative numbers: -4 // 3 = -2, not -1
-    let exp_div_3 = if exponent >= 0 {
-        exponent / 3
+    let mut allowed: Vec<(i32, String)> = METRIC_PREFIXES
+        .iter()
+        .filter(|&&(_, symbol)| {
+            prefixes.contains(symbol) || (symbol == '\u{03BC}' && prefixes.contains('u'))
+        })
+        .map(|&(power, symbol)| (power, symbol.to_string()))
+        .collect();
+    allowed.push((0, String::new()));
+    // Python-style floor division, so that 0.05 lands on milli
+    let natural = exponent.div_euclid(3);
+    // Largest allowed prefix that fits, or the smallest one if none does
+    let (power, ordinal) = allowed
+        .iter()
+        .filter(|(power, _)| *power <= natural)
+        .max_by_key(|(power, _)| *power)
+        .or_else(|| allowed.iter().min_by_key(|(power, _)| *power))
+        .cloned()
+        .unwrap_or_default();
+    let scaled = value / 10f64.powi(power * 3);
+
+    let digits = exponent - power * 3 + 1;
+    let formatted = if digits > 3 && digits > precision as i32 {
+        // The allowed prefixes leave more integer digits than `precision`;
+        // round in decimal so the float noise past them isn't printed.
+        let rounded = format!("{:.*e}", precision.saturating_sub(1), scaled);
+        ExactDecimal::parse(&rounded).map_or(rounded, |d| d.to_string())
     } else {
-        (exponent - 2) / 3 // floor division for negatives
+        let prec = (precision as i32 - digits).max(0) as usize;
+        format!("{:.prec$}", scaled, prec = prec)
     };
-    let scaled = value / 10f64.powi(exp_div_3 * 3);
 
-    let ordinal = if exponent >= 3 {
-        let idx = (exponent / 3 - 1) as usize;
-        let prefixes = ['k', 'M', 'G', 'T', 'P', 'E', 'Z', 'Y', 'R', 'Q'];
-        if idx < prefixes.len() {
-            prefixes[idx].to_string()
-        } else {
-            String::new()
-        }
-    } else if exponent < 0 {
-        let idx = ((-exponent - 1) / 3) as usize;
-        let prefixes = ['m', '\u{03BC}', 'n', 'p', 'f', 'a', 'z', 'y', 'r', 'q'];
-        if idx < prefixes.len() {
-            prefixes[idx].to_string()
+    if verbal::is_verbal() {
+        let (words, plural) = verbal::spell_quantity(&formatted);
+        let (singular, plural_name) = verbal::unit_name(unit);
+        let name = if plural { plural_name } else { singular };
+        let unit_words = format!("{}{}", verbal::prefix_name(&ordinal), name);
+        return if unit_words.is_empty() {
+            words
         } else {
-            String::new()
-        }
+            format!("{} {}", words, unit_words)
+        };
+    }
+
+    let formatted = formatted.replace('.', &i18n::decimal_separator());
+    let formatted = if grouped {
+        group_digits(
+            &formatted,
+            &i18n::thousands_separator(),
+            Grouping::for_locale(),
+        )
     } else {
-        String::new()
+        formatted
     };
 
-    let exp_mod_3 = ((exponent % 3) + 3) % 3; // Python-style modulo (always non-negative)
-    let prec = precision as i32 - exp_mod_3 - 1;
-    let prec = prec.max(0) as usize;
-    let formatted = format!("{:.prec$}", scaled, prec = prec);
-
     let space = if (!unit.is_empty() || !ordinal.is_empty())
         && unit != "°" && unit != "′" && unit != "″"
     {
@@ -637,6 +2338,130 @@ pub fn metric(value: f64, unit: &str, precision: usize) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_spell() {
+        assert_eq!(spell("0"), "zero");
+        assert_eq!(spell("-0"), "zero");
+        assert_eq!(spell("+7"), "seven");
+        assert_eq!(spell("1_000_000_000"), "one billion");
+        assert_eq!(spell("2024"), "two thousand twenty-four");
+        assert_eq!(spell("9.0"), "9.0");
+        assert_eq!(spell("1e3"), "1e3");
+        assert_eq!(spell(""), "");
+        assert_eq!(spell("twelve"), "twelve");
+    }
+
+    #[test]
+    fn test_natural_rank() {
+        assert_eq!(natural_rank(2, 2), "2nd of 2");
+        assert_eq!(natural_rank(111, 1_000_000), "111th of 1,000,000");
+    }
+
+    #[test]
+    fn test_natural_percentile() {
+        assert_eq!(natural_percentile(0.5), "top 50%");
+        assert_eq!(natural_percentile(0.951), "top 5%");
+        assert_eq!(natural_percentile(1.0), "top 1%");
+        assert_eq!(natural_percentile(0.0), "bottom 1%");
+        assert_eq!(natural_percentile(0.05), "bottom 5%");
+        assert_eq!(natural_percentile(7.0), "top 1%");
+        assert_eq!(natural_percentile(f64::NAN), "NaN");
+    }
+
+    #[test]
+    fn test_natural_change() {
+        assert_eq!(natural_change(0.0, 5.0, true), "up (from 0 to 5)");
+        assert_eq!(natural_change(0.0, -5.0, false), "down");
+        assert_eq!(natural_change(-10.0, -5.0, false), "up 50%");
+        assert_eq!(natural_change(1000.0, 1004.0, false), "up 0.4%");
+        assert_eq!(natural_change(1000.0, 1000.04, false), "up 0.004%");
+        assert_eq!(
+            natural_change(2.5e9, 999_960.0, true),
+            "down 100% (from 2.5b to 1m)"
+        );
+        assert_eq!(natural_change(f64::NAN, 1.0, true), "NaN");
+    }
+
+    #[test]
+    fn test_natural_rate() {
+        let second = TimeDelta::from_seconds(1.0);
+        assert_eq!(natural_rate(1.0, second, "req"), "1 req/s");
+        assert_eq!(natural_rate(0.5, second, "req"), "30 req/min");
+        assert_eq!(natural_rate(0.0, second, "req"), "0 req/h");
+        assert_eq!(natural_rate(3.5e9, second, "ops"), "3.5b ops/s");
+        assert_eq!(
+            natural_rate(1.0, TimeDelta::from_seconds(0.0), "req"),
+            "+Inf"
+        );
+    }
+
+    #[test]
+    fn test_natural_comparison() {
+        assert_eq!(natural_comparison(5.0, 5.0, 2).unwrap(), "roughly equal");
+        assert_eq!(natural_comparison(1.0, 2.0, 1).unwrap(), "half as large");
+        assert_eq!(
+            natural_comparison(1.0, 1.96, 1).unwrap(),
+            "about half as large"
+        );
+        assert_eq!(natural_comparison(1.25, 1.0, 2).unwrap(), "1.25× larger");
+        assert_eq!(natural_comparison(1.0, 7.5, 1).unwrap(), "7.5× smaller");
+        assert_eq!(
+            natural_comparison(1.0, 7.46, 1).unwrap(),
+            "about 7.5× smaller"
+        );
+        assert_eq!(
+            natural_comparison(0.0, 1.0, 1),
+            Err(NumberError::NotPositive(0.0))
+        );
+        assert!(natural_comparison(1.0, f64::NAN, 1).is_err());
+    }
+
+    #[test]
+    fn test_natural_trend() {
+        let defaults = TrendThresholds::default();
+        assert_eq!(natural_trend(0.0, defaults), "roughly flat");
+        assert_eq!(natural_trend(-0.03, defaults), "down slightly (\u{2212}3%)");
+        assert_eq!(natural_trend(-0.1, defaults), "down (\u{2212}10%)");
+        assert_eq!(natural_trend(2.5, defaults), "up sharply (+250%)");
+        assert_eq!(natural_trend(f64::INFINITY, defaults), "+Inf");
+
+        let strict = TrendThresholds {
+            flat: 0.001,
+            ..defaults
+        };
+        assert_eq!(natural_trend(0.006, strict), "up slightly (+1%)");
+    }
+
+    #[test]
+    fn test_compact_count() {
+        assert_eq!(compact_count(970.0), "970");
+        assert_eq!(compact_count(12.5), "12.5");
+        assert_eq!(compact_count(999.96), "1k");
+        assert_eq!(compact_count(999_950.0), "1m");
+        assert_eq!(compact_count(-1234.0), "-1.2k");
+        assert_eq!(compact_count(4.2e15), "4200t");
+    }
+
+    #[test]
+    fn test_odds() {
+        assert_eq!(natural_fractional_odds(1.0 / 3.0).unwrap(), "2 to 1");
+        assert_eq!(natural_fractional_odds(4.0 / 15.0).unwrap(), "11 to 4");
+        assert_eq!(natural_fractional_odds(0.001).unwrap(), "999 to 1");
+        assert_eq!(natural_fractional_odds(1.0).unwrap(), "0 to 1");
+        assert!(natural_fractional_odds(f64::NAN).is_err());
+        assert_eq!(
+            natural_decimal_odds(-0.1),
+            Err(NumberError::ProbabilityOutOfRange(-0.1))
+        );
+        assert_eq!(
+            implied_probability(0.5).unwrap_err().to_string(),
+            "Decimal odds out of range: 0.5"
+        );
+        assert_eq!(natural_decimal_odds(1.0).unwrap(), "1.00");
+        let p = implied_probability(decimal_odds(0.37).unwrap()).unwrap();
+        assert!((p - 0.37).abs() < 1e-12);
+    }
+
     #[test]
     fn test_ordinal() {
         assert_eq!(ordinal("1"), "1st");
@@ -672,6 +2497,70 @@ mod tests {
         assert_eq!(intcomma("1234567", Some(1)), "1,234,567.0");
     }
 
+    #[test]
+    fn test_intcomma_grouped() {
+        let indian = Grouping::Indian;
+        assert_eq!(intcomma_grouped("999", None, indian), "999");
+        assert_eq!(intcomma_grouped("1000", None, indian), "1,000");
+        assert_eq!(intcomma_grouped("100000", None, indian), "1,00,000");
+        assert_eq!(intcomma_grouped("123456789", None, indian), "12,34,56,789");
+        assert_eq!(
+            intcomma_grouped("-1234567.5", Some(1), indian),
+            "-12,34,567.5"
+        );
+        assert_eq!(
+            intcomma_grouped("123456789", None, Grouping::Thousands),
+            "123,456,789"
+        );
+        assert_eq!(intcomma_grouped("nan", None, indian), "NaN");
+
+        if i18n::activate(Some("bn_BD"), None).is_err() {
+            // .mo files not generated
+            return;
+        }
+        assert_eq!(intcomma("123456789", None), "12,34,56,789");
+        assert_eq!(intcomma_exact("123456789.5", None), "12,34,56,789.5");
+        i18n::deactivate();
+    }
+
+    #[test]
+    fn test_intcomma_custom() {
+        let swiss = |v| intcomma_custom(v, None, Grouping::Thousands, "'");
+        assert_eq!(swiss("1234567.89"), "1'234'567.89");
+        assert_eq!(swiss("-1000"), "-1'000");
+        assert_eq!(swiss("1,234"), "1'234");
+
+        let fours = Grouping::Uniform(4);
+        assert_eq!(intcomma_custom("1234", None, fours, " "), "1234");
+        assert_eq!(intcomma_custom("12345", None, fours, " "), "1 2345");
+        assert_eq!(
+            intcomma_custom("123456789", Some(1), fours, " "),
+            "1 2345 6789.0"
+        );
+        assert_eq!(
+            intcomma_custom("123456789", None, Grouping::Uniform(0), " "),
+            "123456789"
+        );
+        assert_eq!(
+            intcomma_custom("123456", None, Grouping::Indian, "_"),
+            "1_23_456"
+        );
+    }
+
+    #[test]
+    fn test_intcomma_si() {
+        assert_eq!(intcomma_si("123", None), "123");
+        assert_eq!(intcomma_si("1234", None), "1\u{202f}234");
+        assert_eq!(intcomma_si("0.1234", None), "0.123\u{202f}4");
+        assert_eq!(
+            intcomma_si("1234567.891", None),
+            "1\u{202f}234\u{202f}567.891"
+        );
+        assert_eq!(intcomma_si("-0.5", Some(6)), "-0.500\u{202f}000");
+        assert_eq!(intcomma_si("inf", None), "+Inf");
+        assert_eq!(intcomma_si("abc", None), "abc");
+    }
+
     #[test]
     fn test_intword() {
         assert_eq!(intword("100", "%.1f"), "100");
@@ -692,6 +2581,182 @@ mod tests {
         assert_eq!(intword("999499", "%.0f"), "999 thousand");
     }
 
+    #[test]
+    fn test_big_integers() {
+        // 2**80 + 1 doesn't survive a round trip through f64
+        assert_eq!(
+            intcomma("1208925819614629174706177", None),
+            "1,208,925,819,614,629,174,706,177"
+        );
+        assert_eq!(
+            intcomma("-1208925819614629174706177", Some(1)),
+            "-1,208,925,819,614,629,174,706,177.0"
+        );
+        assert_eq!(ordinal("1208925819614629174706177"), "1208925819614629174706177th");
+        assert_eq!(ordinal("100000000000000000000011"), "100000000000000000000011th");
+        assert_eq!(ordinal("100000000000000000000021"), "100000000000000000000021st");
+        assert_eq!(ordinal("-0"), "0th");
+        assert_eq!(intword("1208925819614629174706176", "%.1f"), "1.2 septillion");
+        assert_eq!(intword("999999999999999999999999", "%.1f"), "1.0 septillion");
+        assert_eq!(intword("-9223372036854775808", "%.2f"), "-9.22 quintillion");
+        assert_eq!(intword(&format!("1{}", "0".repeat(100)), "%.1f"), "1.0 googol");
+        assert_eq!(intword("1_000_000", "%.1f"), "1.0 million");
+    }
+
+    #[test]
+    fn test_intcomma_exact() {
+        assert_eq!(intcomma_exact("1000", None), "1,000");
+        assert_eq!(
+            intcomma_exact("12345678901234567890.123456789", None),
+            "12,345,678,901,234,567,890.123456789"
+        );
+        assert_eq!(
+            intcomma_exact("12345678901234567890.5", Some(0)),
+            "12,345,678,901,234,567,890"
+        );
+        assert_eq!(intcomma_exact("1.015", Some(2)), "1.02");
+        assert_eq!(intcomma_exact("999.995", Some(2)), "1,000.00");
+        assert_eq!(intcomma_exact("-0.5", Some(0)), "-0");
+        assert_eq!(intcomma_exact("1234", Some(2)), "1,234.00");
+        assert_eq!(intcomma_exact("1.2345E+3", None), "1,234.5");
+        assert_eq!(intcomma_exact("12E-3", None), "0.012");
+        assert_eq!(intcomma_exact("NaN", None), "NaN");
+        assert_eq!(intcomma_exact("-Infinity", None), "-Inf");
+        assert_eq!(intcomma_exact("foo", None), "foo");
+        assert_eq!(intcomma_exact("0E-999999999", None), "0");
+        assert_eq!(
+            intcomma_exact("1e-99999999", None),
+            intcomma("1e-99999999", None)
+        );
+        assert_eq!(intcomma_exact("1e-99999999", Some(2)), "0.00");
+    }
+
+    #[test]
+    fn test_intword_exact() {
+        assert_eq!(intword_exact("100", "%.1f"), "100");
+        assert_eq!(intword_exact("1000000", "%.1f"), "1.0 million");
+        assert_eq!(intword_exact("-1200000000", "%.1f"), "-1.2 billion");
+        assert_eq!(intword_exact("999500", "%.0f"), "1 million");
+        assert_eq!(intword_exact("999499", "%.0f"), "999 thousand");
+        assert_eq!(
+            intword_exact("1208925819614629174706176", "%.3f"),
+            "1.209 septillion"
+        );
+        assert_eq!(intword_exact("1E+100", "%.1f"), "1.0 googol");
+        assert_eq!(intword_exact("1234567", "%d"), intword("1234567", "%d"));
+        assert_eq!(intword_exact("nan", "%.1f"), "NaN");
+        assert_eq!(intword_exact("1e-99999999", "%.1f"), "0");
+    }
+
+    #[test]
+    fn test_intword_with() {
+        let long = &LONG_SCALE;
+        assert_eq!(intword_with("999", "%.1f", long), "999");
+        assert_eq!(intword_with("1000000", "%.1f", long), "1.0 million");
+        assert_eq!(intword_with("1200000000", "%.1f", long), "1.2 milliard");
+        assert_eq!(intword_with("999960000000", "%.1f", long), "1.0 billion");
+        assert_eq!(intword_with("-4.5e15", "%.1f", long), "-4.5 billiard");
+        assert_eq!(intword_with("2e9", "%.1f", &SHORT_SCALE), "2.0 billion");
+
+        let indian = &INDIAN_SCALE;
+        assert_eq!(intword_with("12345", "%.1f", indian), "12.3 thousand");
+        assert_eq!(intword_with("120000", "%.1f", indian), "1.2 lakh");
+        assert_eq!(intword_with("35000000", "%.1f", indian), "3.5 crore");
+        assert_eq!(intword_with("9996000", "%.1f", indian), "1.0 crore");
+        assert_eq!(intword_with("12500000000", "%.0f", indian), "1250 crore");
+
+        let east_asian = &EAST_ASIAN_SCALE;
+        assert_eq!(intword_with("9999", "%.1f", east_asian), "9999");
+        assert_eq!(intword_with("25000", "%.1f", east_asian), "2.5万");
+        assert_eq!(intword_with("123450000", "%.2f", east_asian), "1.23億");
+        assert_eq!(intword_with("99999999", "%.1f", east_asian), "1.0億");
+        assert_eq!(intword_with("3e12", "%.0f", east_asian), "3兆");
+
+        let custom = PowerNames {
+            powers: &[(2, "hundred", "hundreds"), (6, "million", "millions")],
+            context: None,
+            separator: " ",
+            threshold: 0.0,
+        };
+        assert_eq!(intword_with("100", "%.0f", &custom), "1 hundred");
+        assert_eq!(intword_with("250", "%.1f", &custom), "2.5 hundreds");
+        assert_eq!(intword_with("999999", "%.1f", &custom), "1.0 million");
+
+        let above_100k = PowerNames {
+            threshold: 100_000.0,
+            ..SHORT_SCALE
+        };
+        assert_eq!(intword_with("99999", "%.1f", &above_100k), "99999");
+        assert_eq!(intword_with("-99999.9", "%.1f", &above_100k), "-99999");
+        assert_eq!(intword_with("1_000_000", "%d", &above_100k), "1 million");
+        assert_eq!(intword_with("nan", "%.1f", &above_100k), "NaN");
+
+        if i18n::activate(Some("de_DE"), None).is_err() {
+            // .mo files not generated
+            return;
+        }
+        assert_eq!(intword_with("1200000000", "%.1f", long), "1,2 Milliarden");
+        assert_eq!(intword_with("1000000000000", "%.1f", long), "1,0 Billion");
+        assert_eq!(intword("1000000000000", "%.1f"), "1,0 Billion");
+        i18n::deactivate();
+
+        if i18n::activate(Some("zh_CN"), None).is_err() {
+            return;
+        }
+        assert_eq!(intword_with("123450000", "%.2f", east_asian), "1.23亿");
+        i18n::deactivate();
+    }
+
+    #[test]
+    fn test_sigfig() {
+        assert_eq!(sigfig("1234.5", 3), "1,230");
+        assert_eq!(sigfig("-99960", 3), "-100,000");
+        assert_eq!(sigfig("9.995", 3), "10.0");
+        assert_eq!(sigfig("0.99996", 3), "1.00");
+        assert_eq!(sigfig("1.5e-7", 2), "0.00000015");
+        assert_eq!(sigfig("1234", 0), sigfig("1234", 1));
+        assert_eq!(sigfig("0.0456", 0), "0.05");
+        assert_eq!(sigfig("0", 3), "0");
+        assert_eq!(sigfig("nan", 3), "NaN");
+        assert_eq!(sigfig("abc", 3), "abc");
+
+        assert_eq!(intword_sigfig("12345", 3), "12.3 thousand");
+        assert_eq!(intword_sigfig("999960", 3), "1.00 million");
+        assert_eq!(intword_sigfig("-2500000000", 4), "-2.500 billion");
+        assert_eq!(intword_sigfig("456.7", 3), "457");
+        assert_eq!(intword_sigfig("-inf", 3), "-Inf");
+        assert_eq!(intword_sigfig("1560000", 0), "2 million");
+        assert_eq!(intword_sigfig("56", 0), "60");
+    }
+
+    #[test]
+    fn test_intword_compact() {
+        let s = COMPACT_SUFFIXES;
+        let custom = &["k", "m", "bn"];
+        assert_eq!(intword_compact("950", "%.1f", s, false), "950");
+        assert_eq!(intword_compact("950000", "%.1f", s, false), "950K");
+        assert_eq!(intword_compact("3400000", "%.1f", s, false), "3.4M");
+        assert_eq!(intword_compact("-1200000000", "%.1f", s, false), "-1.2B");
+        assert_eq!(intword_compact("999960", "%.1f", s, false), "1M");
+        assert_eq!(intword_compact("1234", "%.2f", s, true), "1.23k");
+        assert_eq!(intword_compact("5e15", "%.1f", s, false), "5000T");
+        assert_eq!(intword_compact("2500000", "%.1f", custom, false), "2.5m");
+        assert_eq!(intword_compact("7000000000", "%.0f", custom, false), "7bn");
+        assert_eq!(intword_compact("inf", "%.1f", s, false), "+Inf");
+        assert_eq!(intword_compact("abc", "%.1f", s, false), "abc");
+    }
+
+    #[test]
+    fn test_fractional_ratio() {
+        assert_eq!(fractional_ratio(1, 3), "1/3");
+        assert_eq!(fractional_ratio(4, 3), "1 1/3");
+        assert_eq!(fractional_ratio(6, -4), "-1 -1/2");
+        assert_eq!(fractional_ratio(0, 7), "0/1");
+        assert_eq!(fractional_ratio(7, 1), "7");
+        // 0.1234567 -> same approximation Python's limit_denominator(1000) picks
+        assert_eq!(fractional_ratio(1_234_567, 10_000_000), "10/81");
+    }
+
     #[test]
     fn test_apnumber() {
         assert_eq!(apnumber("0"), "zero");
@@ -703,6 +2768,95 @@ mod tests {
         assert_eq!(apnumber("nan"), "NaN");
     }
 
+    #[test]
+    fn test_integral_decimals() {
+        assert_eq!(ordinal("18446744073709551615.0"), "18446744073709551615th");
+        assert_eq!(ordinal("-1e20"), "-100000000000000000000th");
+        assert_eq!(ordinal("12345678901234567892.7"), "12345678901234567892nd");
+        assert_eq!(ordinal("-0.5"), "0th");
+        assert_eq!(intword("1.2345e21", "%.4f"), "1.2345 sextillion");
+        assert_eq!(intword("999999999999999999999.0", "%.1f"), "1.0 sextillion");
+        assert_eq!(intword("1e-999999", "%.1f"), "0");
+        assert_eq!(intcomma_num(u64::MAX, None), "18,446,744,073,709,551,615");
+        assert_eq!(intword_num(u128::MAX, "%.3f"), "340282.367 decillion");
+    }
+
+    #[test]
+    fn test_typed_numbers() {
+        assert_eq!(ordinal_num(1u8), "1st");
+        assert_eq!(ordinal_num(13i16), "13th");
+        assert_eq!(ordinal_num(u128::MAX), format!("{}th", u128::MAX));
+        assert_eq!(ordinal_num(i128::MIN), format!("{}th", i128::MIN));
+        assert_eq!(ordinal_num(22.7f64), "22nd");
+        assert_eq!(ordinal_num(f64::NAN), "NaN");
+
+        assert_eq!(intcomma_num(-1000i32, None), "-1,000");
+        assert_eq!(intcomma_num(1000u16, Some(1)), "1,000.0");
+        assert_eq!(intcomma_num(0.1f32, None), "0.1");
+        assert_eq!(intcomma_num(f64::INFINITY, None), "+Inf");
+
+        assert_eq!(intword_num(999i64, "%.1f"), "999");
+        assert_eq!(intword_num(-2_500_000i32, "%.1f"), "-2.5 million");
+        assert_eq!(intword_num(1.5e9f64, "%.1f"), "1.5 billion");
+
+        assert_eq!(apnumber_num(0u64), "zero");
+        assert_eq!(apnumber_num(-3i8), "-3");
+        assert_eq!(apnumber_num(4.0f32), "four");
+    }
+
+    #[test]
+    fn test_scientific_num() {
+        assert_eq!(scientific_num(0u8, 2), "0.00 x 10⁰");
+        assert_eq!(scientific_num(1000i32, 2), scientific("1000", 2));
+        assert_eq!(scientific_num(-99960i64, 2), "-1.00 x 10⁵");
+        assert_eq!(scientific_num(12345u32, 0), "1 x 10⁴");
+        assert_eq!(scientific_num(i128::MIN, 4), "-1.7014 x 10³⁸");
+        assert_eq!(scientific_num(-0.0005f64, 1), scientific("-0.0005", 1));
+
+        let (ascii, latex) = (ScientificStyle::Ascii, ScientificStyle::Latex);
+        assert_eq!(scientific_num_styled(-99960i64, 2, ascii), "-1.00e5");
+        assert_eq!(
+            scientific_num_styled(i128::MIN, 4, latex),
+            "-1.7014 \\times 10^{38}"
+        );
+        assert_eq!(scientific_num_styled(0.3f64, 2, ascii), "3.00e-1");
+        assert_eq!(engineering_num_styled(12345u32, 2, ascii), "12.3e3");
+        assert_eq!(
+            engineering_num_styled(u128::MAX, 3, ScientificStyle::Html),
+            "340.3 × 10<sup>36</sup>"
+        );
+        assert_eq!(
+            engineering_num_styled(0.00042f64, 1, ScientificStyle::Unicode),
+            engineering("0.00042", 1)
+        );
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[test]
+    fn test_bigint() {
+        use num_bigint::{BigInt, BigUint};
+
+        let googol = BigUint::from(10u8).pow(100);
+        assert_eq!(intword_num(&googol, "%.1f"), "1.0 googol");
+        assert_eq!(scientific_num(&googol, 2), "1.00 x 10¹⁰⁰");
+
+        let big: BigInt = "-123456789012345678901234567890123456789012"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            intcomma_num(&big, None),
+            "-123,456,789,012,345,678,901,234,567,890,123,456,789,012"
+        );
+        assert_eq!(
+            ordinal_num(&big),
+            "-123456789012345678901234567890123456789012th"
+        );
+        assert_eq!(intword_num(big.clone(), "%.2f"), "-123456789.01 decillion");
+        assert_eq!(scientific_num(big, 3), "-1.235 x 10⁴¹");
+        assert_eq!(apnumber_num(BigInt::from(7)), "seven");
+        assert_eq!(apnumber_num(googol), format!("1{}", "0".repeat(100)));
+    }
+
     #[test]
     fn test_fractional() {
         assert_eq!(fractional("1"), "1");
@@ -724,6 +2878,44 @@ mod tests {
         assert_eq!(scientific("nan", 2), "NaN");
     }
 
+    #[test]
+    fn test_engineering() {
+        assert_eq!(engineering("1000", 2), "1.00 x 10³");
+        assert_eq!(engineering("33500", 2), "33.5 x 10³");
+        assert_eq!(engineering("-335000", 2), "-335 x 10³");
+        assert_eq!(engineering("99960", 2), "100 x 10³");
+        assert_eq!(engineering("999600", 2), "1.00 x 10⁶");
+        assert_eq!(engineering("5.5", 0), "6 x 10⁰");
+        assert_eq!(engineering("0.05", 1), "50 x 10⁻³");
+        assert_eq!(engineering("foo", 2), "foo");
+        assert_eq!(engineering("-inf", 2), "-Inf");
+    }
+
+    #[test]
+    fn test_scientific_styled() {
+        let styles = [
+            ScientificStyle::Unicode,
+            ScientificStyle::Ascii,
+            ScientificStyle::Latex,
+            ScientificStyle::Html,
+        ];
+        let expected = [
+            "-1.20 x 10⁻⁵",
+            "-1.20e-5",
+            "-1.20 \\times 10^{-5}",
+            "-1.20 × 10<sup>-5</sup>",
+        ];
+        for (style, expected) in styles.into_iter().zip(expected) {
+            assert_eq!(scientific_styled("-0.000012", 2, style), expected);
+        }
+        assert_eq!(scientific_styled("7", 0, ScientificStyle::Ascii), "7e0");
+        assert_eq!(scientific_styled("foo", 2, ScientificStyle::Latex), "foo");
+        assert_eq!(
+            engineering_styled("0.00042", 1, ScientificStyle::Html),
+            "420 × 10<sup>-6</sup>"
+        );
+    }
+
     #[test]
     fn test_metric() {
         assert_eq!(metric(1500.0, "V", 3), "1.50 kV");
@@ -732,8 +2924,62 @@ mod tests {
         assert_eq!(metric(0.0, "", 3), "0.00");
     }
 
+    #[test]
+    fn test_metric_grouped() {
+        assert_eq!(metric_grouped(1500.0, "V", 3, "kM"), "1.50 kV");
+        assert_eq!(metric_grouped(-2.5e7, "W", 4, "k"), "-25,000 kW");
+        assert_eq!(metric_grouped(1.23456e6, "", 7, ""), "1,234,560");
+        assert_eq!(
+            metric_grouped(1e30, "V", 3, "kM"),
+            "1,000,000,000,000,000,000,000,000 MV"
+        );
+
+        if i18n::activate(Some("de_DE"), None).is_err() {
+            // .mo files not generated
+            return;
+        }
+        assert_eq!(metric(1500.0, "V", 3), "1,50 kV");
+        assert_eq!(metric(220e-6, "F", 3), "220 \u{03BC}F");
+        assert_eq!(metric_grouped(1.23456e6, "", 7, ""), "1.234.560");
+        i18n::deactivate();
+    }
+
     #[test]
     fn test_metric_no_space_for_degree() {
         assert_eq!(metric(1.0, "°", 3), "1.00°");
     }
+
+    #[test]
+    fn test_metric_with_prefixes() {
+        assert_eq!(metric_with_prefixes(1500.0, "V", 3, "kM"), "1.50 kV");
+        assert_eq!(metric_with_prefixes(5e9, "W", 3, "kM"), "5000 MW");
+        assert_eq!(metric_with_prefixes(2e8, "B", 3, ""), "200000000 B");
+        assert_eq!(metric_with_prefixes(0.0025, "m", 3, "k"), "0.00250 m");
+        assert_eq!(metric_with_prefixes(220e-6, "F", 3, "m"), "0.220 mF");
+        assert_eq!(metric_with_prefixes(220e-6, "F", 3, "u"), "220 \u{03BC}F");
+        assert_eq!(
+            metric_with_prefixes(1e-12, "F", 3, "\u{03BC}"),
+            "0.00000100 \u{03BC}F"
+        );
+        assert_eq!(metric_with_prefixes(1e40, "", 3, "k"), metric(1e40, "", 3));
+    }
+
+    #[test]
+    fn test_metric_with_prefixes_above_largest() {
+        assert_eq!(
+            metric_with_prefixes(1e30, "V", 3, "kM"),
+            "1000000000000000000000000 MV"
+        );
+        assert_eq!(
+            metric_with_prefixes(1.23456e20, "V", 3, "kM"),
+            "123000000000000 MV"
+        );
+        assert_eq!(
+            metric_with_prefixes(-9.9996e12, "W", 4, "k"),
+            "-10000000000 kW"
+        );
+        assert_eq!(metric_with_prefixes(12345.0, "B", 2, ""), "12000 B");
+        // Within the first three digits nothing is dropped, as before
+        assert_eq!(metric_with_prefixes(10.5123, "", 1, ""), "11");
+    }
 }
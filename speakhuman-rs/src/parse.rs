@@ -0,0 +1,581 @@
+//! Dehumanizing: turn the output of the formatters back into values.
+//!
+//! Every parser takes a [`Mode`]. [`Mode::Strict`] accepts exactly what the
+//! matching formatter produces (case, spacing and digit grouping included),
+//! which makes it suitable for validating round-trips. [`Mode::Lenient`]
+//! accepts what a person is likely to type: surrounding and repeated
+//! whitespace, any case, and ungrouped or loosely grouped digits.
+//!
+//! Units and words are understood in English only; numbers use the active
+//! locale's separators where the formatter does.
+
+use crate::filesize::{SUFFIXES_BINARY, SUFFIXES_DECIMAL, SUFFIXES_GNU};
+use crate::i18n;
+use crate::number::HUMAN_POWERS_SINGULAR;
+use crate::time::TimeDelta;
+use std::borrow::Cow;
+use std::fmt;
+
+/// How forgiving a parser is about its input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    /// Only accept the exact output of the matching formatter.
+    #[default]
+    Strict,
+    /// Ignore case and extra whitespace, and accept ungrouped numbers.
+    Lenient,
+}
+
+/// What went wrong while parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input was empty (or only whitespace).
+    Empty,
+    /// The numeric part could not be read.
+    InvalidNumber,
+    /// The unit, suffix or word is not one the formatter produces.
+    UnknownUnit,
+    /// The value does not fit in the result type.
+    Overflow,
+}
+
+/// Error returned by the parsers in this module.
+///
+/// Displays as `Invalid <what>: "<input>"`, e.g. `Invalid size: "3 parsecs"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    what: &'static str,
+    input: String,
+}
+
+impl ParseError {
+    fn new(kind: ParseErrorKind, what: &'static str, input: &str) -> Self {
+        Self {
+            kind,
+            what,
+            input: input.to_string(),
+        }
+    }
+
+    /// Report the error against the caller's original input.
+    fn with_input(mut self, input: &str) -> Self {
+        self.input = input.to_string();
+        self
+    }
+
+    /// Why parsing failed.
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// The string that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid {}: {:?}", self.what, self.input)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Mode {
+    /// Normalize `value` for parsing: lenient mode trims it and collapses
+    /// runs of whitespace into single spaces.
+    fn normalize(self, value: &str) -> Cow<'_, str> {
+        match self {
+            Mode::Strict => Cow::Borrowed(value),
+            Mode::Lenient => Cow::Owned(value.split_whitespace().collect::<Vec<_>>().join(" ")),
+        }
+    }
+
+    /// Compare a piece of input against a word the formatter writes.
+    fn matches(self, text: &str, word: &str) -> bool {
+        match self {
+            Mode::Strict => text == word,
+            Mode::Lenient => text.eq_ignore_ascii_case(word),
+        }
+    }
+}
+
+// ===========================================================================
+// Numbers
+// ===========================================================================
+
+/// A decimal read exactly: `±digits / 10^scale`.
+struct Decimal {
+    negative: bool,
+    digits: i128,
+    scale: u32,
+}
+
+impl Decimal {
+    /// Read an optionally signed decimal with the given separators.
+    ///
+    /// In strict mode the thousands separator must group the integer part in
+    /// threes, as `intcomma` does; in lenient mode it (and `_`) may appear
+    /// anywhere in the integer part, or not at all.
+    fn read(text: &str, thousands_sep: &str, decimal_sep: &str, mode: Mode) -> Option<Self> {
+        let (negative, text) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        let (integer, fraction) = text.split_once(decimal_sep).unwrap_or((text, ""));
+        let integer = match mode {
+            Mode::Strict if !thousands_sep.is_empty() && integer.contains(thousands_sep) => {
+                let mut groups = integer.split(thousands_sep);
+                let head = groups.next()?;
+                if head.is_empty() || head.len() > 3 || !groups.all(|g| g.len() == 3) {
+                    return None;
+                }
+                integer.replace(thousands_sep, "")
+            }
+            Mode::Strict => integer.to_string(),
+            Mode::Lenient if thousands_sep.is_empty() => integer.replace('_', ""),
+            Mode::Lenient => integer.replace(thousands_sep, "").replace('_', ""),
+        };
+        if (integer.is_empty() && fraction.is_empty())
+            || !integer
+                .bytes()
+                .chain(fraction.bytes())
+                .all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+        let digits = format!("{}{}", integer, fraction).parse().ok()?;
+        Some(Self {
+            negative,
+            digits,
+            scale: fraction.len() as u32,
+        })
+    }
+
+    /// `self * multiplier`, rounded half away from zero.
+    fn times(&self, multiplier: i128) -> Option<i128> {
+        let scale = 10i128.checked_pow(self.scale)?;
+        let scaled = self.digits.checked_mul(multiplier)?;
+        let rounded = (scaled + scale / 2) / scale;
+        Some(if self.negative { -rounded } else { rounded })
+    }
+
+    /// `self * 10^exp` as the nearest `f64`.
+    fn to_f64(&self, exp: i32) -> f64 {
+        let sign = if self.negative { "-" } else { "" };
+        format!("{}{}e{}", sign, self.digits, exp - self.scale as i32)
+            .parse()
+            .unwrap_or(f64::INFINITY)
+    }
+}
+
+/// Split `value` into its leading number and the rest.
+fn split_number(value: &str) -> (&str, &str) {
+    let end = value
+        .find(|c: char| !(c.is_ascii_digit() || ".,_+-'".contains(c)))
+        .unwrap_or(value.len());
+    value.split_at(end)
+}
+
+/// Parse a number formatted with the active locale's separators, as written
+/// by `intcomma`.
+///
+/// # Examples
+/// ```
+/// use speakhuman::parse::{number, Mode};
+/// assert_eq!(number("1,234,567.5", Mode::Strict), Ok(1_234_567.5));
+/// assert!(number("12,34", Mode::Strict).is_err());
+/// assert_eq!(number(" 1234567 ", Mode::Lenient), Ok(1_234_567.0));
+/// ```
+pub fn number(value: &str, mode: Mode) -> Result<f64, ParseError> {
+    let error = |kind| ParseError::new(kind, "number", value);
+    let text = mode.normalize(value);
+    if text.is_empty() {
+        return Err(error(ParseErrorKind::Empty));
+    }
+    let thousands_sep = i18n::thousands_separator();
+    let decimal_sep = i18n::decimal_separator();
+    Decimal::read(&text, &thousands_sep, &decimal_sep, mode)
+        .map(|d| d.to_f64(0))
+        .ok_or_else(|| error(ParseErrorKind::InvalidNumber))
+}
+
+/// Parse the output of `intword` ("1.2 million") back into a number.
+///
+/// Numbers without a power word are accepted too, since `intword` leaves
+/// values under a thousand alone.
+///
+/// # Examples
+/// ```
+/// use speakhuman::parse::{intword, Mode};
+/// assert_eq!(intword("1.2 million", Mode::Strict), Ok(1_200_000.0));
+/// assert_eq!(intword("100", Mode::Strict), Ok(100.0));
+/// assert_eq!(intword("3 Billion", Mode::Lenient), Ok(3e9));
+/// assert!(intword("3 Billion", Mode::Strict).is_err());
+/// ```
+pub fn intword(value: &str, mode: Mode) -> Result<f64, ParseError> {
+    let error = |kind| ParseError::new(kind, "intword", value);
+    let text = mode.normalize(value);
+    if text.is_empty() {
+        return Err(error(ParseErrorKind::Empty));
+    }
+    let (amount, word) = text.split_once(' ').unwrap_or((&text, ""));
+    let exp = if word.is_empty() {
+        0
+    } else {
+        match HUMAN_POWERS_SINGULAR
+            .iter()
+            .position(|p| mode.matches(word, p))
+        {
+            Some(i) if i == HUMAN_POWERS_SINGULAR.len() - 1 => 100,
+            Some(i) => 3 * (i as i32 + 1),
+            None => return Err(error(ParseErrorKind::UnknownUnit)),
+        }
+    };
+    let decimal_sep = i18n::decimal_separator();
+    let amount = Decimal::read(amount, "", &decimal_sep, mode)
+        .ok_or_else(|| error(ParseErrorKind::InvalidNumber))?;
+    Ok(amount.to_f64(exp))
+}
+
+/// Parse the output of `ordinal` ("3rd") back into an integer.
+///
+/// Strict mode requires the suffix `ordinal` would give the number in the
+/// active locale; lenient mode accepts any alphabetic suffix, or none.
+///
+/// # Examples
+/// ```
+/// use speakhuman::parse::{ordinal, Mode};
+/// assert_eq!(ordinal("3rd", Mode::Strict), Ok(3));
+/// assert!(ordinal("3th", Mode::Strict).is_err());
+/// assert_eq!(ordinal("3TH", Mode::Lenient), Ok(3));
+/// ```
+pub fn ordinal(value: &str, mode: Mode) -> Result<i128, ParseError> {
+    let error = |kind| ParseError::new(kind, "ordinal", value);
+    let text = mode.normalize(value);
+    if text.is_empty() {
+        return Err(error(ParseErrorKind::Empty));
+    }
+    let (integer, suffix) = split_number(&text);
+    let number = Decimal::read(integer, "", ".", Mode::Strict)
+        .filter(|d| d.scale == 0)
+        .ok_or_else(|| error(ParseErrorKind::InvalidNumber))?;
+    let number = if number.negative {
+        -number.digits
+    } else {
+        number.digits
+    };
+    let suffix_ok = match mode {
+        Mode::Strict => crate::number::ordinal(&number.to_string()) == text,
+        Mode::Lenient => suffix.chars().all(char::is_alphabetic),
+    };
+    if !suffix_ok {
+        return Err(error(ParseErrorKind::UnknownUnit));
+    }
+    Ok(number)
+}
+
+// ===========================================================================
+// File sizes
+// ===========================================================================
+
+/// Parse the output of `naturalsize` back into a number of bytes.
+///
+/// Decimal ("kB", "MB", ...), binary ("KiB", "MiB", ...) and GNU ("K", "M",
+/// ...) suffixes are understood, as well as "Byte"/"Bytes" and GNU's "B".
+/// Lenient mode also accepts bare numbers, any case, and a space or not
+/// before any suffix. Fractional results are rounded to the nearest byte.
+///
+/// # Examples
+/// ```
+/// use speakhuman::parse::{size, Mode};
+/// assert_eq!(size("1.5 GiB", Mode::Strict), Ok(1_610_612_736));
+/// assert_eq!(size("2.9K", Mode::Strict), Ok(2970));
+/// assert_eq!(size("300 Bytes", Mode::Strict), Ok(300));
+/// assert!(size("3.0 mb", Mode::Strict).is_err());
+/// assert_eq!(size("3.0 mb", Mode::Lenient), Ok(3_000_000));
+/// ```
+pub fn size(value: &str, mode: Mode) -> Result<i128, ParseError> {
+    let error = |kind| ParseError::new(kind, "size", value);
+    let text = mode.normalize(value);
+    if text.is_empty() {
+        return Err(error(ParseErrorKind::Empty));
+    }
+    let (amount, suffix) = split_number(&text);
+    let amount = Decimal::read(amount, "", ".", Mode::Strict)
+        .ok_or_else(|| error(ParseErrorKind::InvalidNumber))?;
+    let multiplier = match mode {
+        Mode::Strict => strict_size_multiplier(suffix),
+        Mode::Lenient => lenient_size_multiplier(suffix.trim()),
+    }
+    .ok_or_else(|| error(ParseErrorKind::UnknownUnit))?;
+    amount
+        .times(multiplier)
+        .ok_or_else(|| error(ParseErrorKind::Overflow))
+}
+
+/// Bytes per unit of a suffix exactly as `naturalsize` writes it.
+fn strict_size_multiplier(suffix: &str) -> Option<i128> {
+    if matches!(suffix, " Byte" | " Bytes" | "B") {
+        return Some(1);
+    }
+    let (exp, base) = if let Some(i) = SUFFIXES_DECIMAL.iter().position(|s| *s == suffix) {
+        (i, 1000i128)
+    } else if let Some(i) = SUFFIXES_BINARY.iter().position(|s| *s == suffix) {
+        (i, 1024)
+    } else {
+        (SUFFIXES_GNU.iter().position(|s| *s == suffix)?, 1024)
+    };
+    base.checked_pow(exp as u32 + 1)
+}
+
+/// Bytes per unit of a suffix in any case, with GNU-style letters counting as
+/// binary units.
+fn lenient_size_multiplier(suffix: &str) -> Option<i128> {
+    let suffix = suffix.to_ascii_lowercase();
+    if matches!(suffix.as_str(), "" | "b" | "byte" | "bytes") {
+        return Some(1);
+    }
+    let mut chars = suffix.chars();
+    let prefix = chars.next()?;
+    let exp = SUFFIXES_GNU
+        .iter()
+        .position(|p| p.eq_ignore_ascii_case(&prefix.to_string()))? as u32
+        + 1;
+    match chars.as_str() {
+        "" | "ib" => 1024i128.checked_pow(exp),
+        "b" => 1000i128.checked_pow(exp),
+        _ => None,
+    }
+}
+
+// ===========================================================================
+// Time
+// ===========================================================================
+
+/// Parse the output of `naturaldelta` or `precisedelta` back into a
+/// [`TimeDelta`].
+///
+/// Understands "a moment", "a minute", "2 hours", "1,000 years" and
+/// comma/"and"-separated combinations like "1 day, 2 hours and 3.50 seconds".
+/// A month is 30.5 days and a year 365 days, as in the formatters, and
+/// fractional amounts are rounded to the nearest microsecond.
+///
+/// # Examples
+/// ```
+/// use speakhuman::parse::{delta, Mode};
+/// assert_eq!(delta("2 hours", Mode::Strict).unwrap().seconds, 7200);
+/// assert_eq!(delta("a year, 2 days", Mode::Strict).unwrap().days, 367);
+/// assert_eq!(delta("2  HOURS", Mode::Lenient).unwrap().seconds, 7200);
+/// assert!(delta("2 fortnights", Mode::Lenient).is_err());
+/// ```
+pub fn delta(value: &str, mode: Mode) -> Result<TimeDelta, ParseError> {
+    parse_delta_as(value, mode, "delta")
+}
+
+/// Parse the output of `naturaltime` back into a [`TimeDelta`].
+///
+/// "X ago" gives a positive delta and "X from now" a negative one, matching
+/// the sign of the timedelta `naturaltime` formats; "now" is zero. Text
+/// without a tense is parsed like [`delta`].
+///
+/// # Examples
+/// ```
+/// use speakhuman::parse::{naturaltime, Mode};
+/// assert_eq!(naturaltime("30 seconds ago", Mode::Strict).unwrap().seconds, 30);
+/// let future = naturaltime("an hour from now", Mode::Strict).unwrap();
+/// assert_eq!(future.total_seconds(), -3600.0);
+/// assert_eq!(naturaltime("Now", Mode::Lenient).unwrap().total_seconds(), 0.0);
+/// ```
+pub fn naturaltime(value: &str, mode: Mode) -> Result<TimeDelta, ParseError> {
+    let text = mode.normalize(value);
+    if mode.matches(&text, "now") {
+        return Ok(TimeDelta::from_days_seconds_micros(0, 0, 0));
+    }
+    if let Some(past) = strip_word_suffix(&text, " ago", mode) {
+        return parse_delta_as(past, mode, "naturaltime").map_err(|e| e.with_input(value));
+    }
+    match strip_word_suffix(&text, " from now", mode) {
+        Some(future) => {
+            let delta =
+                parse_delta_as(future, mode, "naturaltime").map_err(|e| e.with_input(value))?;
+            let total_us = -(delta.days as i128 * 86_400_000_000
+                + delta.seconds as i128 * 1_000_000
+                + delta.microseconds as i128);
+            timedelta_from_micros(total_us)
+                .ok_or_else(|| ParseError::new(ParseErrorKind::Overflow, "naturaltime", value))
+        }
+        None => parse_delta_as(&text, mode, "naturaltime").map_err(|e| e.with_input(value)),
+    }
+}
+
+/// Strip a trailing phrase like " ago", ignoring case in lenient mode.
+fn strip_word_suffix<'a>(text: &'a str, suffix: &str, mode: Mode) -> Option<&'a str> {
+    let split = text.len().checked_sub(suffix.len())?;
+    let (head, tail) = (text.get(..split)?, text.get(split..)?);
+    mode.matches(tail, suffix).then_some(head)
+}
+
+fn parse_delta_as(value: &str, mode: Mode, what: &'static str) -> Result<TimeDelta, ParseError> {
+    let error = |kind| ParseError::new(kind, what, value);
+    let text = mode.normalize(value);
+    if text.is_empty() {
+        return Err(error(ParseErrorKind::Empty));
+    }
+    if mode.matches(&text, "a moment") {
+        return Ok(TimeDelta::from_days_seconds_micros(0, 0, 0));
+    }
+
+    let mut total_us: i128 = 0;
+    for part in text.split(", ").flat_map(|p| p.split(" and ")) {
+        let (amount, unit) = part
+            .split_once(' ')
+            .ok_or_else(|| error(ParseErrorKind::UnknownUnit))?;
+        let unit_us =
+            delta_unit_micros(unit, mode).ok_or_else(|| error(ParseErrorKind::UnknownUnit))?;
+        let us = if mode.matches(amount, "a") || mode.matches(amount, "an") {
+            unit_us
+        } else {
+            Decimal::read(amount, ",", ".", mode)
+                .filter(|d| !d.negative && !amount.starts_with('+'))
+                .ok_or_else(|| error(ParseErrorKind::InvalidNumber))?
+                .times(unit_us)
+                .ok_or_else(|| error(ParseErrorKind::Overflow))?
+        };
+        total_us = total_us
+            .checked_add(us)
+            .ok_or_else(|| error(ParseErrorKind::Overflow))?;
+    }
+
+    timedelta_from_micros(total_us).ok_or_else(|| error(ParseErrorKind::Overflow))
+}
+
+/// Microseconds in one `unit` as written by the formatters ("hour", "days").
+fn delta_unit_micros(unit: &str, mode: Mode) -> Option<i128> {
+    const UNITS: [(&str, i128); 9] = [
+        ("microsecond", 1),
+        ("millisecond", 1_000),
+        ("second", 1_000_000),
+        ("minute", 60_000_000),
+        ("hour", 3_600_000_000),
+        ("day", 86_400_000_000),
+        ("week", 604_800_000_000),
+        ("month", 2_635_200_000_000),
+        ("year", 31_536_000_000_000),
+    ];
+    let singular = match mode {
+        Mode::Strict => unit.strip_suffix('s'),
+        Mode::Lenient => unit.strip_suffix(['s', 'S']),
+    }
+    .unwrap_or(unit);
+    UNITS
+        .iter()
+        .find(|(name, _)| mode.matches(singular, name))
+        .map(|&(_, us)| us)
+}
+
+/// Build a normalized `TimeDelta` from a microsecond count, if its days fit.
+fn timedelta_from_micros(total_us: i128) -> Option<TimeDelta> {
+    let days = i64::try_from(total_us.div_euclid(86_400_000_000)).ok()?;
+    let remaining = total_us.rem_euclid(86_400_000_000) as i64;
+    Some(TimeDelta::from_days_seconds_micros(days, 0, remaining))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_kinds() {
+        let kind = |r: Result<i128, ParseError>| r.unwrap_err().kind();
+        assert_eq!(kind(size("", Mode::Strict)), ParseErrorKind::Empty);
+        assert_eq!(
+            kind(size("1.2.3 kB", Mode::Strict)),
+            ParseErrorKind::InvalidNumber
+        );
+        assert_eq!(
+            kind(size("12 furlongs", Mode::Lenient)),
+            ParseErrorKind::UnknownUnit
+        );
+        let huge = format!("{} QB", "9".repeat(20));
+        assert_eq!(kind(size(&huge, Mode::Lenient)), ParseErrorKind::Overflow);
+
+        let err = size("3 parsecs", Mode::Strict).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid size: \"3 parsecs\"");
+        assert_eq!(err.input(), "3 parsecs");
+    }
+
+    #[test]
+    fn test_size_modes() {
+        assert_eq!(size("1 Byte", Mode::Strict), Ok(1));
+        assert_eq!(size("300B", Mode::Strict), Ok(300));
+        assert_eq!(size("-3.0 kB", Mode::Strict), Ok(-3000));
+        assert_eq!(size("1.0 QB", Mode::Strict), Ok(10i128.pow(30)));
+        for loose in [" 3.0 MB", "3.0  MB", "3.0MB", "3.0 mb", "3000000", "3.0 K"] {
+            assert!(size(loose, Mode::Strict).is_err(), "{:?}", loose);
+            assert!(size(loose, Mode::Lenient).is_ok(), "{:?}", loose);
+        }
+    }
+
+    #[test]
+    fn test_delta_modes() {
+        let td = delta("1,000 years", Mode::Strict).unwrap();
+        assert_eq!(td.days, 365_000);
+        assert!(delta("1000,0 years", Mode::Strict).is_err());
+        assert_eq!(delta("10,00 years", Mode::Lenient).unwrap().days, 365_000);
+
+        let td = delta("2 days, 1 hour and 33.12 seconds", Mode::Strict).unwrap();
+        assert_eq!((td.days, td.seconds, td.microseconds), (2, 3633, 120_000));
+        for loose in ["An hour", "2 Hours", " 2 hours", "2 hours ", "2  hours"] {
+            assert!(delta(loose, Mode::Strict).is_err(), "{:?}", loose);
+            assert!(delta(loose, Mode::Lenient).is_ok(), "{:?}", loose);
+        }
+    }
+
+    #[test]
+    fn test_naturaltime_errors_report_input() {
+        let err = naturaltime("2 fortnights ago", Mode::Strict).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::UnknownUnit);
+        assert_eq!(
+            err.to_string(),
+            "Invalid naturaltime: \"2 fortnights ago\""
+        );
+        assert!(naturaltime("2 HOURS AGO", Mode::Strict).is_err());
+        assert_eq!(
+            naturaltime("2 HOURS AGO", Mode::Lenient).unwrap().seconds,
+            7200
+        );
+    }
+
+    #[test]
+    fn test_intword() {
+        assert_eq!(intword("1.0 googol", Mode::Strict), Ok(1e100));
+        assert_eq!(intword("-1.5 thousand", Mode::Strict), Ok(-1500.0));
+        assert!(intword("1.2 millions", Mode::Strict).is_err());
+        assert!(intword("1.2", Mode::Strict).is_ok());
+        assert!(intword("", Mode::Lenient).is_err());
+    }
+
+    #[test]
+    fn test_ordinal() {
+        for n in [1, 2, 3, 4, 11, 12, 13, 21, 101, 111] {
+            let text = crate::number::ordinal(&n.to_string());
+            assert_eq!(ordinal(&text, Mode::Strict), Ok(n));
+        }
+        assert!(ordinal("1.5th", Mode::Lenient).is_err());
+        assert_eq!(ordinal(" 42 ", Mode::Lenient), Ok(42));
+        assert!(ordinal("42", Mode::Strict).is_err());
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number("1,000", Mode::Strict), Ok(1000.0));
+        assert_eq!(number("-0.5", Mode::Strict), Ok(-0.5));
+        assert!(number("1,0000", Mode::Strict).is_err());
+        assert!(number(",100", Mode::Strict).is_err());
+        assert_eq!(number("1_000_000", Mode::Lenient), Ok(1e6));
+        assert!(number("one", Mode::Lenient).is_err());
+    }
+}
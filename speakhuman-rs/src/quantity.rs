@@ -0,0 +1,249 @@
+//! Physical quantities: lengths, masses, temperatures, volumes, speeds and
+//! paces.
+//!
+//! Every function takes the value in a metric base unit and renders it in
+//! either the metric or the imperial (US customary) system, picking the unit
+//! that suits its size. `precision` is the number of significant digits, as
+//! in [`metric`](crate::number::metric), and the decimal separator follows
+//! the active locale. Non-finite values render as "NaN", "+Inf" or "-Inf"
+//! without a unit, as `metric` does.
+
+use crate::i18n;
+use crate::inflect::pluralize;
+use crate::number::{format_not_finite, intcomma, metric_with_prefixes};
+use crate::time::{stopwatch, TimeDelta};
+use crate::verbal;
+
+/// Which system of units to render a quantity in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum System {
+    /// SI units with metric prefixes ("1.5 km", "300 g").
+    #[default]
+    Metric,
+    /// US customary units ("0.93 mi", "11 oz").
+    Imperial,
+}
+
+/// Imperial units as (symbol, size in the metric base unit, smallest amount
+/// of this unit worth showing), largest first. The last entry is the
+/// fallback.
+const IMPERIAL_LENGTHS: &[(&str, f64, f64)] = &[
+    ("mi", METERS_PER_MILE, 0.1),
+    ("ft", 0.3048, 1.0),
+    ("in", 0.0254, 0.0),
+];
+const IMPERIAL_MASSES: &[(&str, f64, f64)] = &[("lb", 453.59237, 1.0), ("oz", 28.349523125, 0.0)];
+const METERS_PER_MILE: f64 = 1609.344;
+const IMPERIAL_VOLUMES: &[(&str, f64, f64)] =
+    &[("gal", 3.785411784, 1.0), ("fl oz", 0.0295735295625, 0.0)];
+
+/// Format a length given in meters.
+///
+/// # Examples
+/// ```
+/// use speakhuman::quantity::{length, System};
+/// assert_eq!(length(1500.0, System::Metric, 2), "1.5 km");
+/// assert_eq!(length(0.0025, System::Metric, 3), "2.50 mm");
+/// assert_eq!(length(1500.0, System::Imperial, 2), "0.93 mi");
+/// assert_eq!(length(1.8, System::Imperial, 2), "5.9 ft");
+/// ```
+pub fn length(meters: f64, system: System, precision: usize) -> String {
+    match system {
+        System::Metric => metric_with_prefixes(meters, "m", precision, "k\u{03BC}mn"),
+        System::Imperial => scaled_imperial(meters, precision, IMPERIAL_LENGTHS),
+    }
+}
+
+/// Format a mass given in grams.
+///
+/// # Examples
+/// ```
+/// use speakhuman::quantity::{mass, System};
+/// assert_eq!(mass(300.0, System::Metric, 3), "300 g");
+/// assert_eq!(mass(72_500.0, System::Metric, 3), "72.5 kg");
+/// assert_eq!(mass(300.0, System::Imperial, 3), "10.6 oz");
+/// assert_eq!(mass(72_500.0, System::Imperial, 3), "160 lb");
+/// ```
+pub fn mass(grams: f64, system: System, precision: usize) -> String {
+    match system {
+        System::Metric => metric_with_prefixes(grams, "g", precision, "k\u{03BC}m"),
+        System::Imperial => scaled_imperial(grams, precision, IMPERIAL_MASSES),
+    }
+}
+
+/// Format a volume given in liters.
+///
+/// # Examples
+/// ```
+/// use speakhuman::quantity::{volume, System};
+/// assert_eq!(volume(0.25, System::Metric, 2), "250 mL");
+/// assert_eq!(volume(20.0, System::Imperial, 2), "5.3 gal");
+/// assert_eq!(volume(0.25, System::Imperial, 2), "8.5 fl oz");
+/// ```
+pub fn volume(liters: f64, system: System, precision: usize) -> String {
+    match system {
+        System::Metric => metric_with_prefixes(liters, "L", precision, "\u{03BC}m"),
+        System::Imperial => scaled_imperial(liters, precision, IMPERIAL_VOLUMES),
+    }
+}
+
+/// Format a temperature given in degrees Celsius.
+///
+/// # Examples
+/// ```
+/// use speakhuman::quantity::{temperature, System};
+/// assert_eq!(temperature(21.5, System::Metric, 3), "21.5 °C");
+/// assert_eq!(temperature(-20.0, System::Imperial, 1), "-4 °F");
+/// ```
+pub fn temperature(celsius: f64, system: System, precision: usize) -> String {
+    match system {
+        System::Metric => metric_with_prefixes(celsius, "°C", precision, ""),
+        System::Imperial => {
+            let fahrenheit = celsius * 9.0 / 5.0 + 32.0;
+            format_not_finite(fahrenheit)
+                .unwrap_or_else(|| format!("{} °F", significant(fahrenheit, precision)))
+        }
+    }
+}
+
+/// Format a speed given in meters per second, in km/h or mph.
+///
+/// # Examples
+/// ```
+/// use speakhuman::quantity::{natural_speed, System};
+/// assert_eq!(natural_speed(3.3333, System::Metric, 2), "12 km/h");
+/// assert_eq!(natural_speed(3.3333, System::Imperial, 2), "7.5 mph");
+/// ```
+pub fn natural_speed(meters_per_second: f64, system: System, precision: usize) -> String {
+    let (value, unit) = match system {
+        System::Metric => (meters_per_second * 3.6, "km/h"),
+        System::Imperial => (meters_per_second * 3600.0 / METERS_PER_MILE, "mph"),
+    };
+    format_not_finite(value)
+        .unwrap_or_else(|| format!("{} {}", significant(value, precision), unit))
+}
+
+/// Format a pace, given as the time taken per kilometer, in minutes per
+/// kilometer or per mile.
+///
+/// # Examples
+/// ```
+/// use speakhuman::quantity::{natural_pace, System};
+/// use speakhuman::time::TimeDelta;
+/// let per_km = TimeDelta::from_seconds(330.0);
+/// assert_eq!(natural_pace(per_km, System::Metric), "5:30 min/km");
+/// assert_eq!(natural_pace(per_km, System::Imperial), "8:51 min/mi");
+/// ```
+pub fn natural_pace(per_kilometer: TimeDelta, system: System) -> String {
+    match system {
+        System::Metric => format!("{} min/km", stopwatch(per_kilometer)),
+        System::Imperial => {
+            let per_mile = per_kilometer.total_seconds() * METERS_PER_MILE / 1000.0;
+            format!("{} min/mi", stopwatch(TimeDelta::from_seconds(per_mile)))
+        }
+    }
+}
+
+/// Count things, pluralizing the singular English noun as needed: "1
+/// analysis", "3 analyses", "1,200 people".
+///
+/// # Examples
+/// ```
+/// use speakhuman::quantity::count;
+/// assert_eq!(count(1, "analysis"), "1 analysis");
+/// assert_eq!(count(3, "analysis"), "3 analyses");
+/// assert_eq!(count(1200, "person"), "1,200 people");
+/// ```
+pub fn count(n: i64, noun: &str) -> String {
+    let noun = if n.abs() == 1 {
+        noun.to_string()
+    } else {
+        pluralize(noun)
+    };
+    let n = n.to_string();
+    let number = if verbal::is_verbal() {
+        verbal::spell_number(&n).unwrap_or(n)
+    } else {
+        intcomma(&n, None)
+    };
+    format!("{} {}", number, noun)
+}
+
+/// Convert `value` to the first of `units` it fills and format it.
+fn scaled_imperial(value: f64, precision: usize, units: &[(&str, f64, f64)]) -> String {
+    if let Some(text) = format_not_finite(value) {
+        return text;
+    }
+    let (symbol, size) = units
+        .iter()
+        .find(|(_, size, minimum)| (value / size).abs() >= *minimum)
+        .or(units.last())
+        .map(|&(symbol, size, _)| (symbol, size))
+        .expect("unit table is not empty");
+    format!("{} {}", significant(value / size, precision), symbol)
+}
+
+/// Format `value` with `precision` significant digits (but never fewer than
+/// its integer digits), using the active locale's decimal separator.
+fn significant(value: f64, precision: usize) -> String {
+    let exponent = if value != 0.0 {
+        value.abs().log10().floor() as i32
+    } else {
+        0
+    };
+    let decimals = (precision as i32 - exponent - 1).max(0) as usize;
+    format!("{:.*}", decimals, value).replace('.', &i18n::decimal_separator())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metric_scaling() {
+        assert_eq!(length(0.0, System::Metric, 3), "0.00 m");
+        assert_eq!(length(5e-7, System::Metric, 2), "500 nm");
+        assert_eq!(length(4.2e7, System::Metric, 2), "42000 km");
+        assert_eq!(mass(0.002, System::Metric, 1), "2 mg");
+        assert_eq!(volume(3.0, System::Metric, 2), "3.0 L");
+    }
+
+    #[test]
+    fn test_imperial_units() {
+        assert_eq!(length(0.05, System::Imperial, 2), "2.0 in");
+        assert_eq!(length(160.0, System::Imperial, 3), "525 ft");
+        assert_eq!(length(170.0, System::Imperial, 2), "0.11 mi");
+        assert_eq!(length(-1500.0, System::Imperial, 2), "-0.93 mi");
+        assert_eq!(mass(453.59237, System::Imperial, 1), "1 lb");
+        assert_eq!(volume(0.0, System::Imperial, 1), "0 fl oz");
+    }
+
+    #[test]
+    fn test_speed_and_pace() {
+        assert_eq!(natural_speed(0.0, System::Metric, 2), "0.0 km/h");
+        assert_eq!(natural_speed(343.0, System::Imperial, 3), "767 mph");
+        assert_eq!(natural_speed(f64::NAN, System::Metric, 2), "NaN");
+        let slow = TimeDelta::from_seconds(3900.0);
+        assert_eq!(natural_pace(slow, System::Metric), "1:05:00 min/km");
+    }
+
+    #[test]
+    fn test_count() {
+        assert_eq!(count(0, "box"), "0 boxes");
+        assert_eq!(count(-1, "degree"), "-1 degree");
+        assert_eq!(count(2, "sheep"), "2 sheep");
+    }
+
+    #[test]
+    fn test_temperature() {
+        assert_eq!(temperature(100.0, System::Imperial, 3), "212 °F");
+        assert_eq!(temperature(-40.0, System::Metric, 2), "-40 °C");
+    }
+
+    #[test]
+    fn test_non_finite() {
+        assert_eq!(length(f64::INFINITY, System::Imperial, 2), "+Inf");
+        assert_eq!(mass(f64::NEG_INFINITY, System::Metric, 2), "-Inf");
+        assert_eq!(temperature(f64::NAN, System::Imperial, 2), "NaN");
+    }
+}
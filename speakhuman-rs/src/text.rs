@@ -0,0 +1,156 @@
+//! Humanizing numbers inside free-form text, such as log lines and error
+//! messages.
+
+use crate::filesize::naturalsize;
+use crate::number::intword;
+use crate::time::{naturaltime_delta, TimeDelta};
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A kind of raw number [`humanize_inline`] looks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Detector {
+    /// A count followed by "bytes" or "B" ("1048576 bytes"), rewritten with
+    /// `naturalsize` ("1.0 MB").
+    ByteCounts,
+    /// A ten-digit Unix timestamp in seconds ("1718000000"), rewritten with
+    /// `naturaltime` relative to now ("3 days ago").
+    EpochTimestamps,
+    /// An integer of a million or more ("12500000"), rewritten with `intword`
+    /// ("12.5 million").
+    LargeIntegers,
+}
+
+/// Every detector, in the order they are tried.
+pub const ALL_DETECTORS: &[Detector] = &[
+    Detector::ByteCounts,
+    Detector::EpochTimestamps,
+    Detector::LargeIntegers,
+];
+
+/// Whole numbers that aren't part of a decimal or a word, with an optional
+/// byte unit after them.
+static NUMBER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(^|[^\w.,])(\d+)( ?(?:bytes|B))?\b").unwrap());
+
+/// Replace raw numbers in `text` with their humanized form.
+///
+/// Each number is rewritten by the first of `detectors` that recognizes it
+/// and left alone if none does. Numbers that are part of a word, a decimal or
+/// a comma-grouped figure are never touched.
+///
+/// # Examples
+/// ```
+/// use speakhuman::text::{humanize_inline, Detector, ALL_DETECTORS};
+/// assert_eq!(
+///     humanize_inline("upload of 1048576 bytes failed", ALL_DETECTORS),
+///     "upload of 1.0 MB failed"
+/// );
+/// assert_eq!(
+///     humanize_inline("processed 12500000 rows in 3 s", &[Detector::LargeIntegers]),
+///     "processed 12.5 million rows in 3 s"
+/// );
+/// ```
+pub fn humanize_inline(text: &str, detectors: &[Detector]) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0.0, |d| d.as_secs_f64());
+    humanize_inline_at(text, detectors, now)
+}
+
+fn humanize_inline_at(text: &str, detectors: &[Detector], now: f64) -> String {
+    NUMBER
+        .replace_all(text, |caps: &Captures| {
+            // The regex crate has no lookahead, so reject decimals here
+            let rest = &text.as_bytes()[caps.get(0).unwrap().end()..];
+            if matches!(rest, [b'.' | b',', next, ..] if next.is_ascii_digit()) {
+                return caps[0].to_string();
+            }
+            let digits = &caps[2];
+            let unit = caps.get(3);
+            let humanized = detectors
+                .iter()
+                .find_map(|detector| detect(*detector, digits, unit.is_some(), now));
+            match humanized {
+                Some(humanized) => format!("{}{}", &caps[1], humanized),
+                None => caps[0].to_string(),
+            }
+        })
+        .into_owned()
+}
+
+/// The humanized form of `digits` if `detector` recognizes it.
+fn detect(detector: Detector, digits: &str, has_unit: bool, now: f64) -> Option<String> {
+    match detector {
+        Detector::ByteCounts if has_unit => {
+            Some(naturalsize(digits.parse().ok()?, false, false, "%.1f"))
+        }
+        Detector::EpochTimestamps if !has_unit && digits.len() == 10 => {
+            let seconds = now - digits.parse::<f64>().ok()?;
+            let delta = TimeDelta::from_seconds(seconds.abs());
+            Some(naturaltime_delta(delta, seconds < 0.0, true, "seconds"))
+        }
+        Detector::LargeIntegers if !has_unit => {
+            let digits = digits.trim_start_matches('0');
+            (digits.len() >= 7).then(|| intword(digits, "%.1f"))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2024-06-10 06:13:20 UTC
+    const NOW: f64 = 1_718_000_000.0;
+
+    #[test]
+    fn test_timestamps() {
+        assert_eq!(
+            humanize_inline_at("since 1717996400", ALL_DETECTORS, NOW),
+            "since an hour ago"
+        );
+        assert_eq!(
+            humanize_inline_at("expires 1718086400", ALL_DETECTORS, NOW),
+            "expires a day from now"
+        );
+        // Too short to be a timestamp, too small for intword
+        assert_eq!(
+            humanize_inline_at("pid 171800", ALL_DETECTORS, NOW),
+            "pid 171800"
+        );
+    }
+
+    #[test]
+    fn test_detector_order() {
+        let text = "got 5000000 B and 5000000 items";
+        assert_eq!(
+            humanize_inline_at(text, ALL_DETECTORS, NOW),
+            "got 5.0 MB and 5.0 million items"
+        );
+        assert_eq!(
+            humanize_inline_at(text, &[Detector::LargeIntegers], NOW),
+            "got 5000000 B and 5.0 million items"
+        );
+        assert_eq!(humanize_inline_at(text, &[], NOW), text);
+    }
+
+    #[test]
+    fn test_skips_embedded_numbers() {
+        for text in [
+            "v12345678",
+            "3.14159265",
+            "1,234,567,890",
+            "12345678.9",
+            "0xFF1234567",
+        ] {
+            assert_eq!(humanize_inline_at(text, ALL_DETECTORS, NOW), text);
+        }
+        assert_eq!(
+            humanize_inline_at("12345678 and 87654321", ALL_DETECTORS, NOW),
+            "12.3 million and 87.7 million"
+        );
+    }
+}
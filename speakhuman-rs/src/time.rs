@@ -2,13 +2,36 @@
 //!
 //! These are largely borrowed from Django's `contrib.humanize`.
 
+use crate::emoji;
 use crate::i18n;
-use crate::number::{intcomma, printf_format};
-use chrono::{Local, NaiveDate};
+use crate::lists::natural_list_localized;
+use crate::number::{group_thousands, intcomma, printf_format};
+#[cfg(feature = "chrono")]
+use crate::number::{intword, ordinal};
+use crate::parse::{Mode, ParseError};
+use crate::verbal;
+#[cfg(feature = "chrono")]
+use chrono::{
+    DateTime, Datelike, Days, Local, Month, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeZone,
+    Timelike, Utc, Weekday,
+};
+use std::cell::Cell;
+use std::cmp::Ordering;
 use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
 /// Unit enum for time precision, ordered from smallest to largest.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+///
+/// `Weeks` came later than the others, so it takes the next free
+/// discriminant; units are compared by [`Unit::rank`], which puts it
+/// between days and months.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Unit {
     Microseconds = 0,
     Milliseconds = 1,
@@ -16,27 +39,46 @@ pub enum Unit {
     Minutes = 3,
     Hours = 4,
     Days = 5,
+    Weeks = 8,
     Months = 6,
     Years = 7,
 }
 
 impl Unit {
-    /// Parse a unit name (case-insensitive).
-    pub fn from_str(s: &str) -> Result<Self, String> {
-        match s.to_uppercase().as_str() {
-            "MICROSECONDS" => Ok(Unit::Microseconds),
-            "MILLISECONDS" => Ok(Unit::Milliseconds),
-            "SECONDS" => Ok(Unit::Seconds),
-            "MINUTES" => Ok(Unit::Minutes),
-            "HOURS" => Ok(Unit::Hours),
-            "DAYS" => Ok(Unit::Days),
-            "MONTHS" => Ok(Unit::Months),
-            "YEARS" => Ok(Unit::Years),
-            _ => Err(format!("Unknown unit: {}", s)),
-        }
-    }
-
-    fn all() -> &'static [Unit] {
+    /// The unit's name, as accepted by the formatters' `minimum_unit` and
+    /// `suppress` arguments.
+    pub fn name(self) -> &'static str {
+        match self {
+            Unit::Microseconds => "microseconds",
+            Unit::Milliseconds => "milliseconds",
+            Unit::Seconds => "seconds",
+            Unit::Minutes => "minutes",
+            Unit::Hours => "hours",
+            Unit::Days => "days",
+            Unit::Weeks => "weeks",
+            Unit::Months => "months",
+            Unit::Years => "years",
+        }
+    }
+
+    /// The unit's position from smallest to largest, which is how units
+    /// compare.
+    pub fn rank(self) -> u8 {
+        match self {
+            Unit::Microseconds => 0,
+            Unit::Milliseconds => 1,
+            Unit::Seconds => 2,
+            Unit::Minutes => 3,
+            Unit::Hours => 4,
+            Unit::Days => 5,
+            Unit::Weeks => 6,
+            Unit::Months => 7,
+            Unit::Years => 8,
+        }
+    }
+
+    /// All units, from smallest to largest.
+    pub fn all() -> &'static [Unit] {
         &[
             Unit::Microseconds,
             Unit::Milliseconds,
@@ -44,11 +86,129 @@ impl Unit {
             Unit::Minutes,
             Unit::Hours,
             Unit::Days,
+            Unit::Weeks,
             Unit::Months,
             Unit::Years,
         ]
     }
+}
+
+impl Ord for Unit {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+impl PartialOrd for Unit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Parse a unit name or abbreviation, ignoring case.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::Unit;
+/// assert_eq!("Seconds".parse(), Ok(Unit::Seconds));
+/// assert_eq!("ms".parse(), Ok(Unit::Milliseconds));
+/// assert!("fortnights".parse::<Unit>().is_err());
+/// ```
+impl FromStr for Unit {
+    type Err = UnitParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "microseconds" | "microsecond" | "us" | "\u{b5}s" | "\u{3bc}s" => {
+                Ok(Unit::Microseconds)
+            }
+            "milliseconds" | "millisecond" | "ms" => Ok(Unit::Milliseconds),
+            "seconds" | "second" | "sec" | "s" => Ok(Unit::Seconds),
+            "minutes" | "minute" | "min" => Ok(Unit::Minutes),
+            "hours" | "hour" | "hr" | "h" => Ok(Unit::Hours),
+            "days" | "day" | "d" => Ok(Unit::Days),
+            "weeks" | "week" | "wk" | "w" => Ok(Unit::Weeks),
+            "months" | "month" | "mo" => Ok(Unit::Months),
+            "years" | "year" | "yr" | "y" => Ok(Unit::Years),
+            _ => Err(UnitParseError {
+                input: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// The error from parsing a [`Unit`] that isn't a known name or
+/// abbreviation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnitParseError {
+    input: String,
+}
+
+impl UnitParseError {
+    /// The text that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl fmt::Display for UnitParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown unit: {}", self.input)
+    }
+}
+
+impl std::error::Error for UnitParseError {}
+
+/// An invalid unit argument to [`try_naturaldelta_td`] or
+/// [`try_precisedelta_td`], a duration too long for [`TimeDelta`], an
+/// availability [`naturaldowntime`] and [`natural_uptime`] can't describe,
+/// or a birthdate after the date `naturalage_on` measures to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeError {
+    /// A unit name that isn't one of [`Unit`]'s.
+    UnknownUnit(String),
+    /// A known unit that can't be the minimum unit here.
+    UnsupportedMinimumUnit(String),
+    /// The minimum unit and every unit above it are suppressed.
+    NoUnitLeft,
+    /// A duration beyond [`TimeDelta::MIN`]..=[`TimeDelta::MAX`], or not a
+    /// number.
+    OutOfRange,
+    /// An availability outside 0..=1.
+    AvailabilityOutOfRange,
+    /// A birthdate after the reference date.
+    FutureBirthdate,
+}
+
+impl fmt::Display for TimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeError::UnknownUnit(name) => write!(f, "Unknown unit: {}", name),
+            TimeError::UnsupportedMinimumUnit(name) => {
+                write!(f, "Minimum unit '{}' not supported", name)
+            }
+            TimeError::NoUnitLeft => write!(
+                f,
+                "Minimum unit is suppressed and no suitable replacement was found"
+            ),
+            TimeError::OutOfRange => write!(f, "Duration out of range"),
+            TimeError::AvailabilityOutOfRange => write!(f, "Availability out of range"),
+            TimeError::FutureBirthdate => write!(f, "Birthdate is after the reference date"),
+        }
+    }
+}
 
+impl std::error::Error for TimeError {}
+
+fn parse_unit(name: &str) -> Result<Unit, TimeError> {
+    name.parse()
+        .map_err(|e: UnitParseError| TimeError::UnknownUnit(e.input))
+}
+
+/// Total microseconds in `days`, `seconds` and `microseconds`, which never
+/// overflows an i128.
+fn days_seconds_micros(days: i64, seconds: i64, microseconds: i64) -> i128 {
+    days as i128 * 86_400_000_000 + seconds as i128 * 1_000_000 + microseconds as i128
 }
 
 /// A duration broken into days, seconds, and microseconds (like Python's timedelta).
@@ -60,63 +220,75 @@ pub struct TimeDelta {
 }
 
 impl TimeDelta {
+    /// The longest positive duration, with `i64::MAX` days.
+    pub const MAX: TimeDelta = TimeDelta {
+        days: i64::MAX,
+        seconds: 86_399,
+        microseconds: 999_999,
+    };
+
+    /// The longest negative duration, with `i64::MIN` days.
+    pub const MIN: TimeDelta = TimeDelta {
+        days: i64::MIN,
+        seconds: 0,
+        microseconds: 0,
+    };
+
     /// Create from total seconds (float).
+    ///
+    /// Durations out of range saturate at [`TimeDelta::MIN`] or
+    /// [`TimeDelta::MAX`], and NaN is zero; use [`TimeDelta::try_from_seconds`]
+    /// to catch them.
     pub fn from_seconds(secs: f64) -> Self {
-        let total_us = (secs * 1_000_000.0).round() as i128;
-        let total_us_abs = total_us.unsigned_abs();
-        let sign: i64 = if total_us < 0 { -1 } else { 1 };
-
-        let days = (total_us_abs / (86_400 * 1_000_000)) as i64 * sign;
-        let remaining_us = (total_us_abs % (86_400 * 1_000_000)) as i64;
-        let seconds = (remaining_us / 1_000_000) * if total_us < 0 && remaining_us > 0 { 1 } else { 1 };
-        let microseconds = remaining_us % 1_000_000;
-
-        // Match Python's timedelta normalization where days can be negative
-        // but seconds and microseconds are always 0 <= x < max
-        if total_us < 0 {
-            let abs_days = (total_us_abs / (86_400 * 1_000_000)) as i64;
-            let remaining = total_us_abs % (86_400 * 1_000_000);
-            if remaining > 0 {
-                let d = -(abs_days + 1);
-                let remaining_positive = 86_400 * 1_000_000u128 - remaining;
-                let s = (remaining_positive / 1_000_000) as i64;
-                let us = (remaining_positive % 1_000_000) as i64;
-                TimeDelta { days: d, seconds: s, microseconds: us }
-            } else {
-                TimeDelta { days: -abs_days, seconds: 0, microseconds: 0 }
-            }
-        } else {
-            TimeDelta { days, seconds, microseconds }
+        // The cast saturates, and NaN becomes zero
+        TimeDelta::from_microseconds((secs * 1_000_000.0).round() as i128)
+    }
+
+    /// Create from total seconds (float), failing on NaN, infinities and
+    /// durations out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use speakhuman::time::{TimeDelta, TimeError};
+    /// assert_eq!(TimeDelta::try_from_seconds(90.0), Ok(TimeDelta::from_millis(90_000)));
+    /// assert_eq!(TimeDelta::try_from_seconds(1e300), Err(TimeError::OutOfRange));
+    /// assert_eq!(TimeDelta::try_from_seconds(f64::NAN), Err(TimeError::OutOfRange));
+    /// ```
+    pub fn try_from_seconds(secs: f64) -> Result<Self, TimeError> {
+        let micros = (secs * 1_000_000.0).round();
+        // Every duration in range is well within i128, so the cast is exact
+        if !micros.is_finite() || micros.abs() > 1e30 {
+            return Err(TimeError::OutOfRange);
         }
+        TimeDelta::try_from_microseconds(micros as i128)
     }
 
+    /// Create from days, seconds and microseconds, normalized like Python's
+    /// timedelta so that only `days` is negative.
+    ///
+    /// Durations out of range saturate at [`TimeDelta::MIN`] or
+    /// [`TimeDelta::MAX`].
     pub fn from_days_seconds_micros(days: i64, seconds: i64, microseconds: i64) -> Self {
-        // Normalize like Python's timedelta
-        let total_us = days as i128 * 86_400_000_000i128
-            + seconds as i128 * 1_000_000i128
-            + microseconds as i128;
-
-        if total_us < 0 {
-            let abs_us = (-total_us) as u128;
-            let abs_days = (abs_us / 86_400_000_000) as i64;
-            let remaining = abs_us % 86_400_000_000;
-            if remaining > 0 {
-                let d = -(abs_days + 1);
-                let remaining_positive = 86_400_000_000u128 - remaining;
-                let s = (remaining_positive / 1_000_000) as i64;
-                let us = (remaining_positive % 1_000_000) as i64;
-                TimeDelta { days: d, seconds: s, microseconds: us }
-            } else {
-                TimeDelta { days: -abs_days, seconds: 0, microseconds: 0 }
-            }
-        } else {
-            let total_us = total_us as u128;
-            let days = (total_us / 86_400_000_000) as i64;
-            let remaining = total_us % 86_400_000_000;
-            let seconds = (remaining / 1_000_000) as i64;
-            let microseconds = (remaining % 1_000_000) as i64;
-            TimeDelta { days, seconds, microseconds }
-        }
+        TimeDelta::from_microseconds(days_seconds_micros(days, seconds, microseconds))
+    }
+
+    /// [`TimeDelta::from_days_seconds_micros`], failing on durations out of
+    /// range.
+    ///
+    /// # Examples
+    /// ```
+    /// use speakhuman::time::{TimeDelta, TimeError};
+    /// assert_eq!(
+    ///     TimeDelta::try_from_days_seconds_micros(i64::MAX, 86_400, 0),
+    ///     Err(TimeError::OutOfRange)
+    /// );
+    /// ```
+    pub fn try_from_days_seconds_micros(
+        days: i64,
+        seconds: i64,
+        microseconds: i64,
+    ) -> Result<Self, TimeError> {
+        TimeDelta::try_from_microseconds(days_seconds_micros(days, seconds, microseconds))
     }
 
     /// Total seconds as float.
@@ -126,34 +298,466 @@ impl TimeDelta {
             + self.microseconds as f64 / 1_000_000.0
     }
 
-    /// Absolute value.
+    /// Create from whole weeks, saturating at [`TimeDelta::MIN`] or
+    /// [`TimeDelta::MAX`].
+    pub fn from_weeks(weeks: i64) -> Self {
+        TimeDelta::from_microseconds(weeks as i128 * 7 * 86_400_000_000)
+    }
+
+    /// Create from whole weeks, failing on durations out of range.
+    pub fn try_from_weeks(weeks: i64) -> Result<Self, TimeError> {
+        TimeDelta::try_from_microseconds(weeks as i128 * 7 * 86_400_000_000)
+    }
+
+    /// Create from whole days.
+    ///
+    /// The whole-unit constructors add up to any duration without going
+    /// through float seconds:
+    ///
+    /// ```
+    /// use speakhuman::time::TimeDelta;
+    /// let delta = TimeDelta::from_days(2) + TimeDelta::from_hours(3) + TimeDelta::from_minutes(15);
+    /// assert_eq!(delta, TimeDelta::from_days_seconds_micros(2, 11_700, 0));
+    /// ```
+    pub fn from_days(days: i64) -> Self {
+        TimeDelta::from_microseconds(days as i128 * 86_400_000_000)
+    }
+
+    /// Create from whole hours.
+    pub fn from_hours(hours: i64) -> Self {
+        TimeDelta::from_microseconds(hours as i128 * 3_600_000_000)
+    }
+
+    /// Create from whole minutes.
+    pub fn from_minutes(minutes: i64) -> Self {
+        TimeDelta::from_microseconds(minutes as i128 * 60_000_000)
+    }
+
+    /// Create from whole milliseconds.
+    pub fn from_millis(millis: i64) -> Self {
+        TimeDelta::from_microseconds(millis as i128 * 1_000)
+    }
+
+    /// Create from whole microseconds.
+    pub fn from_micros(micros: i64) -> Self {
+        TimeDelta::from_microseconds(micros as i128)
+    }
+
+    /// Start building a duration from whole units, checked for range once
+    /// at the end.
+    ///
+    /// # Examples
+    /// ```
+    /// use speakhuman::time::{TimeDelta, TimeError};
+    /// let delta = TimeDelta::builder().days(2).hours(3).minutes(15).build();
+    /// assert_eq!(delta, Ok(TimeDelta::from_days_seconds_micros(2, 11_700, 0)));
+    /// let too_long = TimeDelta::builder().weeks(i64::MAX).build();
+    /// assert_eq!(too_long, Err(TimeError::OutOfRange));
+    /// ```
+    pub fn builder() -> TimeDeltaBuilder {
+        TimeDeltaBuilder::default()
+    }
+
+    /// Total microseconds, exactly.
+    fn total_microseconds(&self) -> i128 {
+        days_seconds_micros(self.days, self.seconds, self.microseconds)
+    }
+
+    /// Saturates at [`TimeDelta::MIN`] and [`TimeDelta::MAX`].
+    fn from_microseconds(total_us: i128) -> Self {
+        let total_us = total_us.clamp(
+            TimeDelta::MIN.total_microseconds(),
+            TimeDelta::MAX.total_microseconds(),
+        );
+        TimeDelta::try_from_microseconds(total_us).expect("clamped into range")
+    }
+
+    fn try_from_microseconds(total_us: i128) -> Result<Self, TimeError> {
+        let days = i64::try_from(total_us.div_euclid(86_400_000_000))
+            .map_err(|_| TimeError::OutOfRange)?;
+        let rest = total_us.rem_euclid(86_400_000_000) as i64;
+        Ok(TimeDelta {
+            days,
+            seconds: rest / 1_000_000,
+            microseconds: rest % 1_000_000,
+        })
+    }
+
+    /// Round to a multiple of `granularity`, such as the nearest quarter
+    /// hour, before handing the delta to a formatter.
+    ///
+    /// Negative deltas round by their size, so `Rounding::Floor` moves them
+    /// towards zero too. A zero granularity leaves the delta as it is.
+    ///
+    /// # Examples
+    /// ```
+    /// use speakhuman::time::{precisedelta_td, Rounding, TimeDelta};
+    /// let delta = TimeDelta::from_seconds(3.0 * 3600.0 + 43.0 * 60.0 + 12.0);
+    /// let snapped = delta.snap(TimeDelta::from_minutes(15), Rounding::HalfUp);
+    /// assert_eq!(snapped, TimeDelta::from_minutes(225));
+    /// assert_eq!(
+    ///     precisedelta_td(snapped, "seconds", &[], "%0.2f"),
+    ///     "3 hours and 45 minutes"
+    /// );
+    /// let snapped = delta.snap(TimeDelta::from_minutes(5), Rounding::Floor);
+    /// assert_eq!(snapped, TimeDelta::from_minutes(220));
+    /// ```
+    pub fn snap(&self, granularity: TimeDelta, rounding: Rounding) -> Self {
+        let step = granularity.total_microseconds().abs();
+        if step == 0 {
+            return *self;
+        }
+        let total = self.total_microseconds();
+        let snapped = rounding.divide(total.abs(), step) * step;
+        TimeDelta::from_microseconds(if total < 0 { -snapped } else { snapped })
+    }
+
+    /// Absolute value. [`TimeDelta::MIN`] has none, so it saturates at
+    /// [`TimeDelta::MAX`].
     pub fn abs(&self) -> Self {
-        if self.days < 0 {
-            let total_us = self.days.unsigned_abs() as i128 * 86_400_000_000i128
-                - self.seconds as i128 * 1_000_000i128
-                - self.microseconds as i128;
-            let total_us = total_us.unsigned_abs();
-            let days = (total_us / 86_400_000_000) as i64;
-            let remaining = total_us % 86_400_000_000;
-            let seconds = (remaining / 1_000_000) as i64;
-            let microseconds = (remaining % 1_000_000) as i64;
-            TimeDelta { days, seconds, microseconds }
-        } else {
-            *self
+        TimeDelta::from_microseconds(self.total_microseconds().abs())
+    }
+
+    /// Format as an ISO 8601 duration.
+    ///
+    /// Days are not folded into months or years, whose length varies. The
+    /// time part runs from the largest non-zero component to the smallest,
+    /// keeping any zeros in between. A zero duration is "PT0S" and a negative
+    /// one has a leading minus sign.
+    ///
+    /// # Examples
+    /// ```
+    /// use speakhuman::time::TimeDelta;
+    /// let delta = TimeDelta::from_days_seconds_micros(2, 3633, 123000);
+    /// assert_eq!(delta.to_iso8601(), "P2DT1H0M33.123S");
+    /// assert_eq!(TimeDelta::from_seconds(-300.0).to_iso8601(), "-PT5M");
+    /// ```
+    pub fn to_iso8601(&self) -> String {
+        let total_us = self.total_microseconds();
+        let sign = if total_us < 0 { "-" } else { "" };
+        let total_us = total_us.unsigned_abs();
+        let days = total_us / 86_400_000_000;
+        let secs = (total_us / 1_000_000) % 86_400;
+        let (hours, minutes, whole_seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+        let micros = total_us % 1_000_000;
+
+        let mut seconds = whole_seconds.to_string();
+        if micros > 0 {
+            let fraction = format!("{:06}", micros);
+            seconds = format!("{}.{}", seconds, fraction.trim_end_matches('0'));
         }
+        let time = [
+            (hours > 0, hours.to_string(), 'H'),
+            (minutes > 0, minutes.to_string(), 'M'),
+            (whole_seconds > 0 || micros > 0, seconds, 'S'),
+        ];
+
+        let mut iso = format!("{}P", sign);
+        if days > 0 {
+            iso.push_str(&format!("{}D", days));
+        }
+        let first = time.iter().position(|(nonzero, _, _)| *nonzero);
+        let last = time.iter().rposition(|(nonzero, _, _)| *nonzero);
+        if let (Some(first), Some(last)) = (first, last) {
+            iso.push('T');
+            for (_, value, designator) in &time[first..=last] {
+                iso.push_str(value);
+                iso.push(*designator);
+            }
+        } else if days == 0 {
+            iso.push_str("T0S");
+        }
+        iso
+    }
+}
+
+/// Adds up whole units into a [`TimeDelta`]; see [`TimeDelta::builder`].
+///
+/// Each call adds to the total, so `.hours(1).hours(2)` is three hours.
+/// The sum is exact, and only [`TimeDeltaBuilder::build`] checks it against
+/// [`TimeDelta::MIN`]..=[`TimeDelta::MAX`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TimeDeltaBuilder {
+    total_us: i128,
+}
+
+impl TimeDeltaBuilder {
+    fn add(mut self, count: i64, unit: Unit) -> Self {
+        // Saturating, so even a runaway sum still builds as out of range
+        self.total_us = self
+            .total_us
+            .saturating_add(count as i128 * unit_micros(unit));
+        self
+    }
+
+    /// Add whole weeks.
+    pub fn weeks(self, weeks: i64) -> Self {
+        self.add(weeks, Unit::Weeks)
+    }
+
+    /// Add whole days.
+    pub fn days(self, days: i64) -> Self {
+        self.add(days, Unit::Days)
+    }
+
+    /// Add whole hours.
+    pub fn hours(self, hours: i64) -> Self {
+        self.add(hours, Unit::Hours)
+    }
+
+    /// Add whole minutes.
+    pub fn minutes(self, minutes: i64) -> Self {
+        self.add(minutes, Unit::Minutes)
+    }
+
+    /// Add whole seconds.
+    pub fn seconds(self, seconds: i64) -> Self {
+        self.add(seconds, Unit::Seconds)
+    }
+
+    /// Add whole milliseconds.
+    pub fn millis(self, millis: i64) -> Self {
+        self.add(millis, Unit::Milliseconds)
+    }
+
+    /// Add whole microseconds.
+    pub fn micros(self, micros: i64) -> Self {
+        self.add(micros, Unit::Microseconds)
+    }
+
+    /// The duration, or [`TimeError::OutOfRange`] if the total doesn't fit
+    /// in a [`TimeDelta`].
+    pub fn build(self) -> Result<TimeDelta, TimeError> {
+        TimeDelta::try_from_microseconds(self.total_us)
+    }
+}
+
+// Arithmetic is exact, on whole microseconds, and normalizes the result like
+// `from_days_seconds_micros`.
+
+impl Add for TimeDelta {
+    type Output = TimeDelta;
+
+    fn add(self, rhs: TimeDelta) -> TimeDelta {
+        TimeDelta::from_microseconds(self.total_microseconds() + rhs.total_microseconds())
+    }
+}
+
+impl Sub for TimeDelta {
+    type Output = TimeDelta;
+
+    fn sub(self, rhs: TimeDelta) -> TimeDelta {
+        TimeDelta::from_microseconds(self.total_microseconds() - rhs.total_microseconds())
+    }
+}
+
+impl Neg for TimeDelta {
+    type Output = TimeDelta;
+
+    fn neg(self) -> TimeDelta {
+        TimeDelta::from_microseconds(-self.total_microseconds())
+    }
+}
+
+impl Mul<i64> for TimeDelta {
+    type Output = TimeDelta;
+
+    fn mul(self, rhs: i64) -> TimeDelta {
+        TimeDelta::from_microseconds(self.total_microseconds().saturating_mul(rhs as i128))
+    }
+}
+
+// Compared by length, so unnormalized values built by hand compare correctly.
+
+impl PartialEq for TimeDelta {
+    fn eq(&self, other: &Self) -> bool {
+        self.total_microseconds() == other.total_microseconds()
+    }
+}
+
+impl Eq for TimeDelta {}
+
+impl PartialOrd for TimeDelta {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TimeDelta {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total_microseconds().cmp(&other.total_microseconds())
+    }
+}
+
+/// Sub-microsecond precision is truncated.
+impl From<std::time::Duration> for TimeDelta {
+    fn from(value: std::time::Duration) -> Self {
+        let secs = value.as_secs();
+        TimeDelta::from_days_seconds_micros(
+            (secs / 86_400) as i64,
+            (secs % 86_400) as i64,
+            value.subsec_micros() as i64,
+        )
+    }
+}
+
+/// Every `chrono::Duration` fits, so `TryFrom` comes for free with
+/// `Infallible` as the error. Sub-microsecond precision is truncated.
+#[cfg(feature = "chrono")]
+impl From<chrono::Duration> for TimeDelta {
+    fn from(value: chrono::Duration) -> Self {
+        TimeDelta::from_days_seconds_micros(
+            0,
+            value.num_seconds(),
+            (value.subsec_nanos() / 1000) as i64,
+        )
+    }
+}
+
+/// Sub-microsecond precision is truncated.
+#[cfg(feature = "jiff")]
+impl From<jiff::SignedDuration> for TimeDelta {
+    fn from(value: jiff::SignedDuration) -> Self {
+        TimeDelta::from_microseconds(value.as_micros())
+    }
+}
+
+/// A span's years and months have no fixed length, so they are taken as 365
+/// and 30.5 days, as in [`naturaldelta`]; use `Span::total` with a relative
+/// date for exact calendar arithmetic. Sub-microsecond precision is
+/// truncated.
+#[cfg(feature = "jiff")]
+impl From<jiff::Span> for TimeDelta {
+    fn from(value: jiff::Span) -> Self {
+        let units = [
+            (i64::from(value.get_years()), Unit::Years),
+            (i64::from(value.get_months()), Unit::Months),
+            (i64::from(value.get_weeks()), Unit::Weeks),
+            (i64::from(value.get_days()), Unit::Days),
+            (i64::from(value.get_hours()), Unit::Hours),
+            (value.get_minutes(), Unit::Minutes),
+            (value.get_seconds(), Unit::Seconds),
+            (value.get_milliseconds(), Unit::Milliseconds),
+            (value.get_microseconds(), Unit::Microseconds),
+        ];
+        let micros: i128 = units
+            .iter()
+            .map(|&(count, unit)| i128::from(count) * unit_micros(unit))
+            .sum();
+        TimeDelta::from_microseconds(micros + i128::from(value.get_nanoseconds() / 1000))
+    }
+}
+
+/// Sub-microsecond precision is truncated.
+#[cfg(feature = "time")]
+impl From<::time::Duration> for TimeDelta {
+    fn from(value: ::time::Duration) -> Self {
+        TimeDelta::from_microseconds(value.whole_microseconds())
+    }
+}
+
+/// `std::time::Duration` can't be negative, so the sign is dropped, as
+/// `naturaldelta` does. Durations too long for it saturate at
+/// `Duration::MAX`.
+impl From<TimeDelta> for std::time::Duration {
+    fn from(value: TimeDelta) -> Self {
+        let micros = value.total_microseconds().unsigned_abs();
+        u64::try_from(micros / 1_000_000)
+            .map(|secs| std::time::Duration::new(secs, (micros % 1_000_000) as u32 * 1000))
+            .unwrap_or(std::time::Duration::MAX)
     }
 }
 
 /// Return a natural representation of a timedelta or number of seconds.
 ///
-/// This does not add tense to the result.
+/// This does not add tense to the result. An invalid `minimum_unit` comes
+/// back as the error message; use [`try_naturaldelta_td`] to tell the two
+/// apart.
+///
+/// Besides seconds and smaller units, `minimum_unit` can be `"weeks"`:
+/// anything from a week up to a month (or a year, without `months`) is then
+/// counted in whole weeks instead of days. Deltas under a week read as they
+/// would with `"seconds"`.
 ///
 /// # Examples
 /// ```
 /// use speakhuman::time::{naturaldelta_td, TimeDelta};
 /// let delta = TimeDelta::from_days_seconds_micros(7, 0, 0);
 /// assert_eq!(naturaldelta_td(delta, true, "seconds"), "7 days");
+/// let delta = TimeDelta::from_days(17);
+/// assert_eq!(naturaldelta_td(delta, true, "seconds"), "a month");
+/// assert_eq!(naturaldelta_td(delta, true, "weeks"), "2 weeks");
+/// let delta = TimeDelta::from_hours(1);
+/// assert_eq!(naturaldelta_td(delta, true, "weeks"), "an hour");
+/// ```
+pub fn naturaldelta_td(value: TimeDelta, months: bool, minimum_unit: &str) -> String {
+    try_naturaldelta_td(value, months, minimum_unit).unwrap_or_else(|e| e.to_string())
+}
+
+/// `naturaldelta_td`, failing on an unknown `minimum_unit` or one above
+/// seconds other than weeks.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{try_naturaldelta_td, TimeDelta, TimeError};
+/// let delta = TimeDelta::from_seconds(7200.0);
+/// assert_eq!(try_naturaldelta_td(delta, true, "seconds").unwrap(), "2 hours");
+/// assert_eq!(
+///     try_naturaldelta_td(delta, true, "hours"),
+///     Err(TimeError::UnsupportedMinimumUnit("hours".to_string()))
+/// );
 /// ```
+pub fn try_naturaldelta_td(
+    value: TimeDelta,
+    months: bool,
+    minimum_unit: &str,
+) -> Result<String, TimeError> {
+    let min_unit = match parse_unit(minimum_unit)? {
+        Unit::Weeks => Unit::Weeks,
+        _ => naturaldelta_minimum_unit(minimum_unit)?,
+    };
+    Ok(finish_duration(naturaldelta_symbolic(
+        value, months, min_unit,
+    )))
+}
+
+/// Parse the minimum unit of `naturaldelta`, which must be seconds or smaller.
+fn naturaldelta_minimum_unit(minimum_unit: &str) -> Result<Unit, TimeError> {
+    match parse_unit(minimum_unit)? {
+        unit @ (Unit::Seconds | Unit::Milliseconds | Unit::Microseconds) => Ok(unit),
+        _ => Err(TimeError::UnsupportedMinimumUnit(minimum_unit.to_string())),
+    }
+}
+
+/// `naturaldelta_td` in the current output style, without emoji.
+fn naturaldelta_styled(value: TimeDelta, months: bool, min_unit: Unit) -> String {
+    let text = naturaldelta_symbolic(value, months, min_unit);
+    if verbal::is_verbal() {
+        verbal::spell_numbers_in(&text)
+    } else {
+        text
+    }
+}
+
+/// Put a duration formatter's text in the current output style: numbers
+/// spelled out when verbal, then the duration emoji.
+fn finish_duration(text: String) -> String {
+    let text = if verbal::is_verbal() {
+        verbal::spell_numbers_in(&text)
+    } else {
+        text
+    };
+    emoji::decorate(|table| &table.duration, text)
+}
+
+/// What `naturaldelta` and `precisedelta` say for a delta at
+/// [`TimeDelta::MAX`], which is where overlong durations saturate.
+fn saturated_delta() -> String {
+    let years = TimeDelta::MAX.days / 365;
+    i18n::ngettext("more than %s year", "more than %s years", years)
+        .replace("%s", &intcomma(&years.to_string(), None))
+}
+
 /// Python-style banker's rounding (round half to even).
 fn py_round(x: f64) -> i64 {
     let floor = x.floor();
@@ -170,22 +774,24 @@ fn py_round(x: f64) -> i64 {
     }
 }
 
-pub fn naturaldelta_td(value: TimeDelta, months: bool, minimum_unit: &str) -> String {
-    let min_unit = match Unit::from_str(minimum_unit) {
-        Ok(u) => u,
-        Err(e) => return e,
-    };
-
-    if min_unit != Unit::Seconds && min_unit != Unit::Milliseconds && min_unit != Unit::Microseconds
-    {
-        return format!("Minimum unit '{}' not supported", minimum_unit);
-    }
-
+fn naturaldelta_symbolic(value: TimeDelta, months: bool, min_unit: Unit) -> String {
     let delta = value.abs();
+    if delta == TimeDelta::MAX {
+        return saturated_delta();
+    }
     let years = delta.days / 365;
     let days = delta.days % 365;
     let num_months = py_round((days as f64) / 30.5);
 
+    if min_unit == Unit::Weeks && years == 0 && days >= 7 && (!months || days <= 30) {
+        let weeks = days / 7;
+        if weeks == 1 {
+            return i18n::gettext("a week");
+        }
+        let template = i18n::ngettext("%d week", "%d weeks", weeks);
+        return template.replace("%d", &weeks.to_string());
+    }
+
     if years == 0 && days < 1 {
         if delta.seconds == 0 {
             if min_unit == Unit::Microseconds && delta.microseconds < 1000 {
@@ -307,487 +913,4236 @@ pub fn naturaldelta(seconds: f64, months: bool, minimum_unit: &str) -> String {
     naturaldelta_td(delta, months, minimum_unit)
 }
 
-/// Return a natural representation of a time, with tense.
+/// Like [`naturaldelta_td`], but with up to `precision` units: "1 hour and 5
+/// minutes" rather than "an hour".
 ///
-/// # Arguments
-/// * `delta` - A TimeDelta representing the time difference.
-/// * `future` - Whether the time is in the future.
-/// * `months` - Whether to use month approximations.
-/// * `minimum_unit` - The minimum unit to display.
-pub fn naturaltime_delta(
-    delta: TimeDelta,
-    future: bool,
+/// The units are the largest ones the delta fills, with what is left rounded
+/// into the last, as [`precisedelta_with`] does with `max_components`. Weeks
+/// are never used, and months only if `months` is set. A delta that comes
+/// down to a single unit reads as `naturaldelta_td` gives it.
+///
+/// Fails on a `minimum_unit` that [`try_naturaldelta_td`] rejects.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{naturaldelta_precision, TimeDelta};
+/// let delta = TimeDelta::from_seconds(3930.0);
+/// let precise = |precision| naturaldelta_precision(delta, true, "seconds", precision).unwrap();
+/// assert_eq!(precise(2), "1 hour and 6 minutes");
+/// assert_eq!(precise(1), "an hour");
+/// let delta = TimeDelta::from_days_seconds_micros(400, 7200, 0);
+/// assert_eq!(
+///     naturaldelta_precision(delta, true, "seconds", 3).unwrap(),
+///     "1 year, 1 month and 4 days"
+/// );
+/// ```
+pub fn naturaldelta_precision(
+    value: TimeDelta,
     months: bool,
     minimum_unit: &str,
-) -> String {
-    let delta_str = naturaldelta_td(delta, months, minimum_unit);
-
-    if delta_str == i18n::gettext("a moment") {
-        return i18n::gettext("now");
+    precision: usize,
+) -> Result<String, TimeError> {
+    let min_unit = naturaldelta_minimum_unit(minimum_unit)?;
+    let mut suppress = HashSet::new();
+    if !months {
+        suppress.insert(Unit::Months);
     }
-
-    let ago_template = if future {
-        i18n::gettext("%s from now")
+    let components = if precision > 1 {
+        let options = PrecisedeltaOptions {
+            max_components: Some(precision),
+            ..Default::default()
+        };
+        precisedelta_components(value, min_unit, &suppress, "%0.0f", options)
     } else {
-        i18n::gettext("%s ago")
+        Vec::new()
     };
-
-    ago_template.replace("%s", &delta_str)
+    let text = if components.len() > 1 {
+        natural_list_localized(&components, false)
+    } else {
+        naturaldelta_symbolic(value, months, min_unit)
+    };
+    Ok(finish_duration(text))
 }
 
-/// Return "today", "tomorrow", or "yesterday" for nearby dates,
-/// otherwise format with the given strftime format.
-pub fn naturalday(value: NaiveDate, format: &str) -> String {
-    let today = Local::now().date_naive();
-    let diff = (value - today).num_days();
+/// How [`naturaldelta_rounded`] rounds the count of its unit, and
+/// [`TimeDelta::snap`] the number of steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round down, as billing by whole units does: 1 minute 59 seconds is "a
+    /// minute".
+    Floor,
+    /// Round up, for estimates that shouldn't come in short: 1 minute 1
+    /// second is "2 minutes".
+    Ceil,
+    /// Round to the nearest, halves up.
+    HalfUp,
+    /// Round to the nearest, halves to the even count.
+    HalfEven,
+}
 
-    if diff == 0 {
-        return i18n::gettext("today");
-    }
-    if diff == 1 {
-        return i18n::gettext("tomorrow");
-    }
-    if diff == -1 {
-        return i18n::gettext("yesterday");
+impl Rounding {
+    /// `numerator / denominator`, both non-negative, rounded.
+    fn divide(self, numerator: i128, denominator: i128) -> i128 {
+        let (quotient, rest) = (numerator / denominator, numerator % denominator);
+        let up = match self {
+            Rounding::Floor => false,
+            Rounding::Ceil => rest > 0,
+            Rounding::HalfUp => 2 * rest >= denominator,
+            Rounding::HalfEven => {
+                2 * rest > denominator || (2 * rest == denominator && quotient % 2 == 1)
+            }
+        };
+        quotient + i128::from(up)
     }
-
-    value.format(format).to_string()
 }
 
-/// Like naturalday, but append a year for dates more than ~five months away.
-pub fn naturaldate(value: NaiveDate) -> String {
-    let today = Local::now().date_naive();
-    let diff = (value - today).num_days().unsigned_abs();
+/// Like [`naturaldelta_td`], with every count rounded the same way.
+///
+/// `naturaldelta_td` rounds minutes, hours and months to the nearest and
+/// drops what is left of a second, day or year. Here the unit is picked the
+/// same way, but its count is rounded with `rounding`, moving up to the next
+/// unit when it fills it.
+///
+/// Fails on a `minimum_unit` that [`try_naturaldelta_td`] rejects.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{naturaldelta_rounded, Rounding, TimeDelta};
+/// let delta = TimeDelta::from_seconds(90.0);
+/// let rounded = |rounding| naturaldelta_rounded(delta, true, "seconds", rounding).unwrap();
+/// assert_eq!(rounded(Rounding::Floor), "a minute");
+/// assert_eq!(rounded(Rounding::HalfUp), "2 minutes");
+/// let delta = TimeDelta::from_hours(25);
+/// assert_eq!(naturaldelta_rounded(delta, true, "seconds", Rounding::Ceil).unwrap(), "2 days");
+/// ```
+pub fn naturaldelta_rounded(
+    value: TimeDelta,
+    months: bool,
+    minimum_unit: &str,
+    rounding: Rounding,
+) -> Result<String, TimeError> {
+    let min_unit = naturaldelta_minimum_unit(minimum_unit)?;
+    let text = naturaldelta_rounded_symbolic(value, months, min_unit, rounding);
+    Ok(finish_duration(text))
+}
 
-    if diff >= (5 * 365 / 12) as u64 {
-        naturalday(value, "%b %d %Y")
-    } else {
-        naturalday(value, "%b %d")
+fn naturaldelta_rounded_symbolic(
+    value: TimeDelta,
+    months: bool,
+    min_unit: Unit,
+    rounding: Rounding,
+) -> String {
+    let delta = value.abs();
+    if delta == TimeDelta::MAX {
+        return saturated_delta();
     }
-}
+    let total = delta.total_microseconds();
+    let count = |unit: Unit| rounding.divide(total, unit_micros(unit));
+    let plural = |singular: &str, plural: &str, n: i128| {
+        let n = i64::try_from(n).unwrap_or(i64::MAX);
+        i18n::ngettext(singular, plural, n).replace("%d", &n.to_string())
+    };
+    let day = unit_micros(Unit::Days);
+    let year = unit_micros(Unit::Years);
 
-/// Divide value by divisor with special handling for minimum_unit and suppressed units.
-fn quotient_and_remainder(
-    value: f64,
-    divisor: f64,
-    unit: Unit,
-    minimum_unit: Unit,
-    suppress: &HashSet<Unit>,
-    format: &str,
-) -> (f64, f64) {
-    if unit == minimum_unit {
-        return (rounding_by_fmt(format, value / divisor), 0.0);
+    if total < unit_micros(Unit::Seconds) && min_unit < Unit::Seconds {
+        if min_unit == Unit::Microseconds && total < 1000 {
+            return plural("%d microsecond", "%d microseconds", total);
+        }
+        let ms = count(Unit::Milliseconds);
+        if ms < 1000 {
+            return plural("%d millisecond", "%d milliseconds", ms);
+        }
+        return i18n::gettext("a second");
     }
-
-    if suppress.contains(&unit) {
-        return (0.0, value);
+    if total < unit_micros(Unit::Minutes) {
+        return match count(Unit::Seconds) {
+            0 => i18n::gettext("a moment"),
+            1 => i18n::gettext("a second"),
+            60 => i18n::gettext("a minute"),
+            n => plural("%d second", "%d seconds", n),
+        };
     }
+    if total < unit_micros(Unit::Hours) {
+        return match count(Unit::Minutes) {
+            1 => i18n::gettext("a minute"),
+            60 => i18n::gettext("an hour"),
+            n => plural("%d minute", "%d minutes", n),
+        };
+    }
+    if total < day {
+        return match count(Unit::Hours) {
+            1 => i18n::gettext("an hour"),
+            24 => i18n::gettext("a day"),
+            n => plural("%d hour", "%d hours", n),
+        };
+    }
+    // Months are used from half a month on, as in `naturaldelta_td`, unless
+    // rounding leaves none
+    let month = unit_micros(Unit::Months);
+    let in_months = |span: i128| {
+        let use_months = months && Rounding::HalfEven.divide(span, month) > 0;
+        if use_months {
+            rounding.divide(span, month)
+        } else {
+            0
+        }
+    };
+    if total < year {
+        return match (in_months(total), count(Unit::Days)) {
+            (0, 1) => i18n::gettext("a day"),
+            (0, 365) | (12, _) => i18n::gettext("a year"),
+            (0, days) => plural("%d day", "%d days", days),
+            (1, _) => i18n::gettext("a month"),
+            (num_months, _) => plural("%d month", "%d months", num_months),
+        };
+    }
+    if total < 2 * year {
+        let rest = total - year;
+        return match (in_months(rest), rounding.divide(rest, day)) {
+            (0, 0) => i18n::gettext("a year"),
+            (0, 365) | (12, _) => plural("%d year", "%d years", 2),
+            (0, days) => plural("1 year, %d day", "1 year, %d days", days),
+            (1, _) => i18n::gettext("1 year, 1 month"),
+            (num_months, _) => plural("1 year, %d month", "1 year, %d months", num_months),
+        };
+    }
+    let years = count(Unit::Years);
+    let n = i64::try_from(years).unwrap_or(i64::MAX);
+    i18n::ngettext("%d year", "%d years", n).replace("%d", &intcomma(&years.to_string(), None))
+}
 
-    let q = (value / divisor).floor();
-    let r = value - q * divisor;
-    (q, r.floor().max(0.0))
+/// Units of [`naturaldelta_fuzzy`] as (length in seconds, "a ..." phrase,
+/// singular, plural), smallest first. A unit is used until the delta fills
+/// the next one.
+const FUZZY_UNITS: &[(f64, &str, &str, &str)] = &[
+    (1.0, "a second", "%d second", "%d seconds"),
+    (60.0, "a minute", "%d minute", "%d minutes"),
+    (3600.0, "an hour", "%d hour", "%d hours"),
+    (86_400.0, "a day", "%d day", "%d days"),
+    (7.0 * 86_400.0, "a week", "%d week", "%d weeks"),
+    (30.5 * 86_400.0, "a month", "%d month", "%d months"),
+    (365.0 * 86_400.0, "a year", "%d year", "%d years"),
+];
+
+/// Return a natural representation of a timedelta, qualified by how far it
+/// is from the whole number shown: "about an hour", "over 2 years", "almost
+/// 3 weeks".
+///
+/// Up to a quarter of a unit past a whole number is "about" it, up to three
+/// quarters is "over" it, and beyond that is "almost" the next. Exact values
+/// have no qualifier. A month is 30.5 days and a year 365 days, as in
+/// [`naturaldelta`].
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{naturaldelta_fuzzy, TimeDelta};
+/// assert_eq!(naturaldelta_fuzzy(TimeDelta::from_minutes(70)), "about an hour");
+/// assert_eq!(naturaldelta_fuzzy(TimeDelta::from_days(900)), "over 2 years");
+/// assert_eq!(naturaldelta_fuzzy(TimeDelta::from_days(20)), "almost 3 weeks");
+/// assert_eq!(naturaldelta_fuzzy(TimeDelta::from_hours(3)), "3 hours");
+/// ```
+pub fn naturaldelta_fuzzy(value: TimeDelta) -> String {
+    let text = naturaldelta_fuzzy_symbolic(value);
+    finish_duration(text)
 }
 
-/// Find a suitable minimum unit that is not suppressed.
-fn suitable_minimum_unit(min_unit: Unit, suppress: &HashSet<Unit>) -> Result<Unit, String> {
-    if !suppress.contains(&min_unit) {
-        return Ok(min_unit);
+fn naturaldelta_fuzzy_symbolic(value: TimeDelta) -> String {
+    let seconds = value.abs().total_seconds();
+    if seconds < 1.0 {
+        return i18n::gettext("a moment");
     }
-    for unit in Unit::all() {
-        if *unit > min_unit && !suppress.contains(unit) {
-            return Ok(*unit);
+    let mut index = FUZZY_UNITS
+        .iter()
+        .rposition(|(length, _, _, _)| seconds >= *length)
+        .unwrap_or(0);
+    let amount = seconds / FUZZY_UNITS[index].0;
+    let fraction = amount - amount.floor();
+    let mut count = amount.floor() as i64;
+
+    let template = if fraction < 1e-9 {
+        "%s".to_string()
+    } else if fraction < 0.25 {
+        i18n::gettext("about %s")
+    } else if fraction < 0.75 {
+        i18n::gettext("over %s")
+    } else {
+        count += 1;
+        // "almost 60 minutes" is "almost an hour"
+        if let Some((next, _, _, _)) = FUZZY_UNITS.get(index + 1) {
+            if count as f64 * FUZZY_UNITS[index].0 >= *next {
+                index += 1;
+                count = 1;
+            }
         }
+        i18n::gettext("almost %s")
+    };
+
+    let (_, one, singular, plural) = FUZZY_UNITS[index];
+    let amount = if count == 1 {
+        i18n::gettext(one)
+    } else {
+        i18n::ngettext(singular, plural, count).replace("%d", &intcomma(&count.to_string(), None))
+    };
+    template.replace("%s", &amount)
+}
+
+/// Units [`naturaldelta_halves`] can add a half to, as (unit, one and a
+/// half, n and a half singular, n and a half plural), smallest first.
+const HALF_UNITS: &[(Unit, &str, &str, &str)] = &[
+    (
+        Unit::Minutes,
+        "a minute and a half",
+        "%d and a half minute",
+        "%d and a half minutes",
+    ),
+    (
+        Unit::Hours,
+        "an hour and a half",
+        "%d and a half hour",
+        "%d and a half hours",
+    ),
+    (
+        Unit::Days,
+        "a day and a half",
+        "%d and a half day",
+        "%d and a half days",
+    ),
+    (
+        Unit::Months,
+        "a month and a half",
+        "%d and a half month",
+        "%d and a half months",
+    ),
+    (
+        Unit::Years,
+        "a year and a half",
+        "%d and a half year",
+        "%d and a half years",
+    ),
+];
+
+/// Like [`naturaldelta_td`], but a delta within a tenth of a unit of a half
+/// reads as "an hour and a half" or "2 and a half years".
+///
+/// Deltas under a minute, and the rest, are as `naturaldelta_td` gives them
+/// with months.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{naturaldelta_halves, TimeDelta};
+/// assert_eq!(naturaldelta_halves(TimeDelta::from_minutes(90)), "an hour and a half");
+/// assert_eq!(naturaldelta_halves(TimeDelta::from_days(915)), "2 and a half years");
+/// assert_eq!(naturaldelta_halves(TimeDelta::from_minutes(80)), "an hour");
+/// ```
+pub fn naturaldelta_halves(value: TimeDelta) -> String {
+    let text = naturaldelta_halves_symbolic(value)
+        .unwrap_or_else(|| naturaldelta_symbolic(value, true, Unit::Seconds));
+    finish_duration(text)
+}
+
+fn naturaldelta_halves_symbolic(value: TimeDelta) -> Option<String> {
+    let micros = value.abs().total_microseconds();
+    let &(unit, one, singular, plural) = HALF_UNITS
+        .iter()
+        .rev()
+        .find(|(unit, _, _, _)| micros >= unit_micros(*unit))?;
+    let amount = micros as f64 / unit_micros(unit) as f64;
+    if !(0.4..=0.6).contains(&(amount - amount.floor())) {
+        return None;
     }
-    Err("Minimum unit is suppressed and no suitable replacement was found".to_string())
+    let whole = amount.floor() as i64;
+    Some(if whole == 1 {
+        i18n::gettext(one)
+    } else {
+        i18n::ngettext(singular, plural, whole).replace("%d", &intcomma(&whole.to_string(), None))
+    })
 }
 
-/// Extend suppressed units with all units lower than the minimum unit.
-fn suppress_lower_units(min_unit: Unit, suppress: &HashSet<Unit>) -> HashSet<Unit> {
-    let mut result = suppress.clone();
-    for unit in Unit::all() {
-        if *unit == min_unit {
-            break;
+/// Phrases for how often something happens per unit, as (unit, every one,
+/// once, twice, n times singular, n times plural).
+const FREQUENCY_UNITS: &[(Unit, &str, &str, &str, &str, &str)] = &[
+    (
+        Unit::Microseconds,
+        "every microsecond",
+        "once a microsecond",
+        "twice a microsecond",
+        "%d time per microsecond",
+        "%d times per microsecond",
+    ),
+    (
+        Unit::Milliseconds,
+        "every millisecond",
+        "once a millisecond",
+        "twice a millisecond",
+        "%d time per millisecond",
+        "%d times per millisecond",
+    ),
+    (
+        Unit::Seconds,
+        "every second",
+        "once a second",
+        "twice a second",
+        "%d time per second",
+        "%d times per second",
+    ),
+    (
+        Unit::Minutes,
+        "every minute",
+        "once a minute",
+        "twice a minute",
+        "%d time per minute",
+        "%d times per minute",
+    ),
+    (
+        Unit::Hours,
+        "hourly",
+        "once an hour",
+        "twice an hour",
+        "%d time per hour",
+        "%d times per hour",
+    ),
+    (
+        Unit::Days,
+        "daily",
+        "once a day",
+        "twice a day",
+        "%d time per day",
+        "%d times per day",
+    ),
+    (
+        Unit::Weeks,
+        "weekly",
+        "once a week",
+        "twice a week",
+        "%d time per week",
+        "%d times per week",
+    ),
+    (
+        Unit::Months,
+        "monthly",
+        "once a month",
+        "twice a month",
+        "%d time per month",
+        "%d times per month",
+    ),
+    (
+        Unit::Years,
+        "yearly",
+        "once a year",
+        "twice a year",
+        "%d time per year",
+        "%d times per year",
+    ),
+];
+
+/// Plural forms of each unit, as in `precisedelta`, largest first.
+const UNIT_PLURALS: &[(Unit, &str, &str)] = &[
+    (Unit::Years, "%d year", "%d years"),
+    (Unit::Months, "%d month", "%d months"),
+    (Unit::Weeks, "%d week", "%d weeks"),
+    (Unit::Days, "%d day", "%d days"),
+    (Unit::Hours, "%d hour", "%d hours"),
+    (Unit::Minutes, "%d minute", "%d minutes"),
+    (Unit::Seconds, "%d second", "%d seconds"),
+    (Unit::Milliseconds, "%d millisecond", "%d milliseconds"),
+    (Unit::Microseconds, "%d microsecond", "%d microseconds"),
+];
+
+/// Describe how often something happens that recurs every `interval`:
+/// "hourly", "twice a day", "every 2 weeks".
+///
+/// An interval of exactly one unit gets its adverb ("daily"). One that
+/// fits a larger unit 2 to 12 times is counted per that unit ("3 times per
+/// hour"). Anything else is "every" the interval, in the largest unit that
+/// measures it exactly, or as `naturaldelta` gives it if none does. A
+/// month is 30.5 days and a year 365 days; the sign of `interval` is
+/// ignored.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{naturalfrequency, TimeDelta};
+/// assert_eq!(naturalfrequency(TimeDelta::from_hours(1)), "hourly");
+/// assert_eq!(naturalfrequency(TimeDelta::from_hours(12)), "twice a day");
+/// assert_eq!(naturalfrequency(TimeDelta::from_minutes(20)), "3 times per hour");
+/// assert_eq!(naturalfrequency(TimeDelta::from_weeks(2)), "every 2 weeks");
+/// assert_eq!(naturalfrequency(TimeDelta::from_minutes(90)), "every 90 minutes");
+/// ```
+pub fn naturalfrequency(interval: TimeDelta) -> String {
+    finish_duration(naturalfrequency_symbolic(interval))
+}
+
+/// Describe how often something happens `times` times per `per`: "once a
+/// day", "twice an hour", "3 times per hour".
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{naturalfrequency_count, Unit};
+/// assert_eq!(naturalfrequency_count(1, Unit::Days), "once a day");
+/// assert_eq!(naturalfrequency_count(3, Unit::Hours), "3 times per hour");
+/// assert_eq!(naturalfrequency_count(0, Unit::Weeks), "never");
+/// ```
+pub fn naturalfrequency_count(times: u64, per: Unit) -> String {
+    finish_duration(frequency_count(times, per))
+}
+
+fn frequency_count(times: u64, per: Unit) -> String {
+    let &(_, _, once, twice, singular, plural) = FREQUENCY_UNITS
+        .iter()
+        .find(|entry| entry.0 == per)
+        .expect("every unit has frequency phrases");
+    match times {
+        0 => i18n::gettext("never"),
+        1 => i18n::gettext(once),
+        2 => i18n::gettext(twice),
+        _ => {
+            let n = i64::try_from(times).unwrap_or(i64::MAX);
+            i18n::ngettext(singular, plural, n).replace("%d", &intcomma(&times.to_string(), None))
         }
-        result.insert(*unit);
     }
-    result
 }
 
-/// Round a number according to printf-style format string.
-fn rounding_by_fmt(format: &str, value: f64) -> f64 {
-    let result = printf_format(format, value);
-    result
-        .trim()
-        .parse::<i64>()
-        .map(|i| i as f64)
-        .unwrap_or_else(|_| result.trim().parse::<f64>().unwrap_or(value))
+fn naturalfrequency_symbolic(interval: TimeDelta) -> String {
+    let micros = interval.abs().total_microseconds();
+    if micros == 0 {
+        return i18n::gettext("continuously");
+    }
+    if let Some(entry) = FREQUENCY_UNITS
+        .iter()
+        .find(|entry| unit_micros(entry.0) == micros)
+    {
+        return i18n::gettext(entry.1);
+    }
+    let per_unit = Unit::all().iter().find_map(|&unit| {
+        let size = unit_micros(unit);
+        let times = size / micros;
+        (size % micros == 0 && (2..=12).contains(&times)).then_some((times, unit))
+    });
+    if let Some((times, unit)) = per_unit {
+        return frequency_count(times as u64, unit);
+    }
+
+    // Down to seconds, or below them for intervals under a second
+    let smallest = if micros < unit_micros(Unit::Seconds) {
+        Unit::Microseconds
+    } else {
+        Unit::Seconds
+    };
+    let every = UNIT_PLURALS
+        .iter()
+        .filter(|entry| entry.0 >= smallest)
+        .find(|entry| micros % unit_micros(entry.0) == 0);
+    let amount = match every {
+        Some(&(unit, singular, plural)) => {
+            let n = micros / unit_micros(unit);
+            i18n::ngettext(singular, plural, i64::try_from(n).unwrap_or(i64::MAX))
+                .replace("%d", &intcomma(&n.to_string(), None))
+        }
+        None => naturaldelta_symbolic(interval, true, Unit::Seconds),
+    };
+    i18n::gettext("every %s").replace("%s", &amount)
 }
 
-/// Return a precise representation of a timedelta.
+/// Return a natural representation of the time between two moments,
+/// counting months and years on the calendar.
+///
+/// [`naturaldelta`] takes a month to be 30.5 days and a year 365 days; here a
+/// month runs from a day of the month to the same day of the next (or to its
+/// last day, if shorter), so leap years and month lengths come out right.
+/// Whole months are rounded to the nearest month. Under a month, the result
+/// is `naturaldelta` of the exact duration. The order of `start` and `end`
+/// doesn't matter.
 ///
 /// # Examples
 /// ```
-/// use speakhuman::time::{precisedelta_td, TimeDelta};
-/// let delta = TimeDelta::from_days_seconds_micros(2, 3633, 123000);
-/// assert_eq!(precisedelta_td(delta, "seconds", &[], "%0.2f"), "2 days, 1 hour and 33.12 seconds");
+/// use chrono::NaiveDate;
+/// use speakhuman::time::naturaldelta_between;
+/// let at = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(0, 0, 0).unwrap();
+/// assert_eq!(naturaldelta_between(at(2024, 1, 1), at(2025, 1, 1)), "a year");
+/// assert_eq!(naturaldelta_between(at(2024, 1, 31), at(2024, 2, 29)), "a month");
+/// assert_eq!(naturaldelta_between(at(2024, 3, 1), at(2024, 3, 20)), "19 days");
 /// ```
-pub fn precisedelta_td(
-    value: TimeDelta,
-    minimum_unit: &str,
-    suppress: &[&str],
-    format: &str,
+#[cfg(feature = "chrono")]
+pub fn naturaldelta_between(start: NaiveDateTime, end: NaiveDateTime) -> String {
+    let text = naturaldelta_between_symbolic(start.min(end), start.max(end));
+    finish_duration(text)
+}
+
+/// [`naturaldelta_between`] for time-zone-aware moments, reading the
+/// calendar on the wall clock so that daylight saving time changes don't
+/// shift the result.
+///
+/// From noon to noon the next day is "a day" even across a spring-forward,
+/// when only 23 hours pass. Spans shorter than a day on the wall clock are
+/// measured in elapsed time instead ("an hour" from 1:30 to 3:30 on that
+/// night). Both moments are read in their own offset, so give them in the
+/// same time zone.
+///
+/// # Examples
+/// ```
+/// use chrono::{DateTime, FixedOffset};
+/// use speakhuman::time::naturaldelta_between_tz;
+/// let parse = |s| DateTime::<FixedOffset>::parse_from_rfc3339(s).unwrap();
+/// // New York across the 2024 spring-forward
+/// let start = parse("2024-03-09T12:00:00-05:00");
+/// let end = parse("2024-03-10T12:00:00-04:00");
+/// assert_eq!(naturaldelta_between_tz(start, end), "a day");
+/// ```
+#[cfg(feature = "chrono")]
+pub fn naturaldelta_between_tz<Tz: TimeZone>(start: DateTime<Tz>, end: DateTime<Tz>) -> String {
+    let (start_wall, end_wall) = (start.naive_local(), end.naive_local());
+    if (end_wall - start_wall).abs() < chrono::Duration::days(1) {
+        return naturaldelta_td(TimeDelta::from(end - start), false, "seconds");
+    }
+    naturaldelta_between(start_wall, end_wall)
+}
+
+/// The usual weekend for [`naturalbusinessdelta`], Saturday and Sunday.
+#[cfg(feature = "chrono")]
+pub const SATURDAY_SUNDAY: &[Weekday] = &[Weekday::Sat, Weekday::Sun];
+
+/// Count the business days between two dates: "3 business days".
+///
+/// A business day is one that isn't in `weekend` or `holidays`. Days are
+/// counted after `start` up to and including `end`, so Friday to the next
+/// Monday is one business day. The order of `start` and `end` doesn't matter.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use speakhuman::time::{naturalbusinessdelta, SATURDAY_SUNDAY};
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+/// // Friday to Wednesday
+/// let (friday, wednesday) = (date(2024, 6, 7), date(2024, 6, 12));
+/// assert_eq!(naturalbusinessdelta(friday, wednesday, SATURDAY_SUNDAY, &[]), "3 business days");
+/// let holidays = [date(2024, 6, 10)];
+/// assert_eq!(naturalbusinessdelta(friday, wednesday, SATURDAY_SUNDAY, &holidays), "2 business days");
+/// ```
+#[cfg(feature = "chrono")]
+pub fn naturalbusinessdelta(
+    start: NaiveDate,
+    end: NaiveDate,
+    weekend: &[Weekday],
+    holidays: &[NaiveDate],
 ) -> String {
-    let delta = value.abs();
+    let days = business_days(start, end, weekend, holidays);
+    let text = i18n::ngettext("%d business day", "%d business days", days)
+        .replace("%d", &intcomma(&days.to_string(), None));
+    finish_duration(text)
+}
 
-    let suppress_set: HashSet<Unit> = suppress
+/// Business days after the earlier of `start` and `end`, up to and including
+/// the later one.
+#[cfg(feature = "chrono")]
+fn business_days(
+    start: NaiveDate,
+    end: NaiveDate,
+    weekend: &[Weekday],
+    holidays: &[NaiveDate],
+) -> i64 {
+    let (start, end) = (start.min(end), start.max(end));
+    let is_business_day = |date: NaiveDate| !weekend.contains(&date.weekday());
+    let span = (end - start).num_days();
+    // Whole weeks hold the same number of business days
+    let per_week = (0..7)
+        .filter(|&i| is_business_day(start + Days::new(i)))
+        .count() as i64;
+    let mut days = span / 7 * per_week;
+    let mut date = start + Days::new((span / 7 * 7) as u64);
+    while date < end {
+        date = date + Days::new(1);
+        days += i64::from(is_business_day(date));
+    }
+    let mut holidays: Vec<&NaiveDate> = holidays
         .iter()
-        .filter_map(|s| Unit::from_str(s).ok())
+        .filter(|&&day| start < day && day <= end && is_business_day(day))
         .collect();
+    holidays.sort_unstable();
+    holidays.dedup();
+    days - holidays.len() as i64
+}
+
+/// `start` plus a number of calendar months, clamped to the end of the
+/// month.
+#[cfg(feature = "chrono")]
+fn add_months(start: NaiveDateTime, months: u32) -> NaiveDateTime {
+    start
+        .checked_add_months(Months::new(months))
+        .unwrap_or(NaiveDateTime::MAX)
+}
+
+/// The number of whole calendar months from `start` to `end`, which must not
+/// be earlier.
+#[cfg(feature = "chrono")]
+fn whole_months(start: NaiveDateTime, end: NaiveDateTime) -> u32 {
+    let months = (end.year() - start.year()) * 12 + end.month() as i32 - start.month() as i32;
+    let months = months.max(0) as u32;
+    if add_months(start, months) > end {
+        months - 1
+    } else {
+        months
+    }
+}
+
+#[cfg(feature = "chrono")]
+fn naturaldelta_between_symbolic(start: NaiveDateTime, end: NaiveDateTime) -> String {
+    let add_months = |months: u32| add_months(start, months);
+    let months = whole_months(start, end);
+    if months == 0 {
+        return naturaldelta_symbolic(TimeDelta::from(end - start), false, Unit::Seconds);
+    }
 
-    let min_unit = match Unit::from_str(minimum_unit) {
-        Ok(u) => u,
-        Err(e) => return e,
+    // Round to the nearest month: past the middle of the next one counts
+    let anchor = add_months(months);
+    let rounded = if (end - anchor) * 2 >= add_months(months + 1) - anchor {
+        months + 1
+    } else {
+        months
     };
-    let min_unit = match suitable_minimum_unit(min_unit, &suppress_set) {
-        Ok(u) => u,
-        Err(e) => return e,
+    let years = months / 12;
+
+    if years == 0 {
+        return match rounded {
+            1 => i18n::gettext("a month"),
+            12 => i18n::gettext("a year"),
+            _ => i18n::ngettext("%d month", "%d months", rounded as i64)
+                .replace("%d", &rounded.to_string()),
+        };
+    }
+    if years == 1 {
+        let extra_months = rounded - 12;
+        return match extra_months {
+            0 => {
+                let days = (end - anchor).num_days();
+                if days == 0 {
+                    i18n::gettext("a year")
+                } else {
+                    i18n::ngettext("1 year, %d day", "1 year, %d days", days)
+                        .replace("%d", &days.to_string())
+                }
+            }
+            1 => i18n::gettext("1 year, 1 month"),
+            12 => i18n::ngettext("%d year", "%d years", 2).replace("%d", "2"),
+            _ => i18n::ngettext("1 year, %d month", "1 year, %d months", extra_months as i64)
+                .replace("%d", &extra_months.to_string()),
+        };
+    }
+    i18n::ngettext("%d year", "%d years", years as i64)
+        .replace("%d", "%s")
+        .replace("%s", &intcomma(&years.to_string(), None))
+}
+
+/// Return a natural representation of a time, with tense.
+///
+/// # Arguments
+/// * `delta` - A TimeDelta representing the time difference.
+/// * `future` - Whether the time is in the future.
+/// * `months` - Whether to use month approximations.
+/// * `minimum_unit` - The minimum unit to display.
+///
+/// The phrasing follows the thread's [`relative_time_style`].
+pub fn naturaltime_delta(
+    delta: TimeDelta,
+    future: bool,
+    months: bool,
+    minimum_unit: &str,
+) -> String {
+    let min_unit = match naturaldelta_minimum_unit(minimum_unit) {
+        Ok(min_unit) => min_unit,
+        Err(e) => return e.to_string(),
     };
+    let text = naturaltime_styled(delta, future, months, min_unit);
+    emoji::decorate(|table| &table.duration, text)
+}
 
-    let suppress_set = suppress_lower_units(min_unit, &suppress_set);
+/// [`naturaltime_delta`] without the emoji.
+fn naturaltime_styled(delta: TimeDelta, future: bool, months: bool, min_unit: Unit) -> String {
+    let style = relative_time_style();
+    if style != RelativeTimeStyle::Natural {
+        return intl_relative_time(delta, future, months, style);
+    }
+    let delta_str = naturaldelta_styled(delta, months, min_unit);
 
-    let days = delta.days as f64;
-    let secs = delta.seconds as f64;
-    let usecs = delta.microseconds as f64;
+    if delta_str == i18n::gettext("a moment") {
+        i18n::gettext("now")
+    } else if future {
+        i18n::gettext("%s from now").replace("%s", &delta_str)
+    } else {
+        i18n::gettext("%s ago").replace("%s", &delta_str)
+    }
+}
 
-    let (years, days) = quotient_and_remainder(
-        days,
-        365.0,
-        Unit::Years,
-        min_unit,
-        &suppress_set,
-        format,
-    );
-    let (months, days) = quotient_and_remainder(
-        days,
-        30.5,
-        Unit::Months,
-        min_unit,
-        &suppress_set,
-        format,
-    );
+/// Return a natural representation of a time, taking the tense from the
+/// sign of `delta`.
+///
+/// `delta` is how long ago the time was, as in Python's
+/// `naturaltime(timedelta)`: positive is in the past, negative in the future.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{naturaltime_td, TimeDelta};
+/// assert_eq!(naturaltime_td(TimeDelta::from_hours(3), true, "seconds"), "3 hours ago");
+/// assert_eq!(naturaltime_td(-TimeDelta::from_hours(3), true, "seconds"), "3 hours from now");
+/// ```
+pub fn naturaltime_td(delta: TimeDelta, months: bool, minimum_unit: &str) -> String {
+    naturaltime_delta(delta.abs(), delta.days < 0, months, minimum_unit)
+}
 
-    let secs = days * 24.0 * 3600.0 + secs;
-    let (days, secs) = quotient_and_remainder(
-        secs,
-        24.0 * 3600.0,
-        Unit::Days,
-        min_unit,
-        &suppress_set,
-        format,
-    );
+/// [`naturaltime_td`], along with how long until the text changes, for
+/// live views that re-render relative times on a timer.
+///
+/// `delta` is how long ago the time was, as for `naturaltime_td`; as time
+/// passes it grows. The wait is `None` when the text never changes, which
+/// only happens at [`TimeDelta::MAX`].
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{naturaltime_refresh, TimeDelta};
+/// let (text, wait) = naturaltime_refresh(TimeDelta::from_seconds(198.0), true, "seconds");
+/// assert_eq!(text, "3 minutes ago");
+/// // At 3.5 minutes it rounds to 4
+/// assert_eq!(wait, Some(TimeDelta::from_seconds(12.0)));
+/// ```
+pub fn naturaltime_refresh(
+    delta: TimeDelta,
+    months: bool,
+    minimum_unit: &str,
+) -> (String, Option<TimeDelta>) {
+    let start = delta.total_microseconds();
+    let text_after = |wait: i128| {
+        let delta = TimeDelta::from_microseconds(start.saturating_add(wait));
+        naturaltime_td(delta, months, minimum_unit)
+    };
+    let text = text_after(0);
+    // Each text covers one unbroken stretch of time, so once it has changed
+    // it stays changed: double the wait until it does, then bisect
+    let (mut same, mut changed) = (0, 1);
+    while text_after(changed) == text {
+        if start.saturating_add(changed) > TimeDelta::MAX.total_microseconds() {
+            return (text, None);
+        }
+        same = changed;
+        changed *= 2;
+    }
+    while changed - same > 1 {
+        let mid = same + (changed - same) / 2;
+        if text_after(mid) == text {
+            same = mid;
+        } else {
+            changed = mid;
+        }
+    }
+    (text, Some(TimeDelta::from_microseconds(changed)))
+}
 
-    let (hours, secs) = quotient_and_remainder(
-        secs,
-        3600.0,
-        Unit::Hours,
-        min_unit,
-        &suppress_set,
-        format,
-    );
-    let (minutes, secs) = quotient_and_remainder(
-        secs,
-        60.0,
-        Unit::Minutes,
-        min_unit,
-        &suppress_set,
-        format,
-    );
+/// Return a natural representation of a `jiff::Zoned` relative to now, like
+/// "3 hours ago" or "a day from now".
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "jiff")] {
+/// use speakhuman::time::naturaltime_zoned;
+/// let then = jiff::Zoned::now() - jiff::SignedDuration::from_hours(3);
+/// assert_eq!(naturaltime_zoned(&then), "3 hours ago");
+/// # }
+/// ```
+#[cfg(feature = "jiff")]
+pub fn naturaltime_zoned(value: &jiff::Zoned) -> String {
+    naturaltime_zoned_at(value, jiff::Timestamp::now())
+}
 
-    let usecs = secs * 1e6 + usecs;
-    let (secs, usecs) = quotient_and_remainder(
-        usecs,
-        1e6,
-        Unit::Seconds,
-        min_unit,
-        &suppress_set,
-        format,
-    );
+#[cfg(feature = "jiff")]
+fn naturaltime_zoned_at(value: &jiff::Zoned, now: jiff::Timestamp) -> String {
+    let ago = now.duration_since(value.timestamp());
+    naturaltime_td(ago.into(), true, "seconds")
+}
 
-    let (msecs, usecs) = quotient_and_remainder(
-        usecs,
-        1000.0,
-        Unit::Milliseconds,
-        min_unit,
-        &suppress_set,
-        format,
-    );
+/// Return a natural representation of a `time::OffsetDateTime` relative to
+/// now, like "3 hours ago" or "a day from now".
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "time")] {
+/// use speakhuman::time::naturaltime_offset;
+/// let then = time::OffsetDateTime::now_utc() - time::Duration::hours(3);
+/// assert_eq!(naturaltime_offset(then), "3 hours ago");
+/// # }
+/// ```
+#[cfg(feature = "time")]
+pub fn naturaltime_offset(value: ::time::OffsetDateTime) -> String {
+    naturaltime_offset_at(value, ::time::OffsetDateTime::now_utc())
+}
 
-    // Promotion due to rounding
-    let (mut msecs, mut secs, mut minutes, mut hours, mut days, mut months, mut years) =
-        (msecs, secs, minutes, hours, days, months, years);
+#[cfg(feature = "time")]
+fn naturaltime_offset_at(value: ::time::OffsetDateTime, now: ::time::OffsetDateTime) -> String {
+    naturaltime_td((now - value).into(), true, "seconds")
+}
+
+/// Return a natural representation of a UNIX timestamp, in seconds,
+/// relative to now.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::naturaltime_epoch;
+/// use std::time::{SystemTime, UNIX_EPOCH};
+/// let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64;
+/// assert_eq!(naturaltime_epoch(now - 3600), "an hour ago");
+/// assert_eq!(naturaltime_epoch(now - 2 * 86_400), "2 days ago");
+/// ```
+pub fn naturaltime_epoch(secs: i64) -> String {
+    naturaltime_epoch_millis(secs.saturating_mul(1000))
+}
+
+/// [`naturaltime_epoch`] for a UNIX timestamp in milliseconds.
+pub fn naturaltime_epoch_millis(millis: i64) -> String {
+    let now_millis = match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(since) => since.as_millis() as i64,
+        Err(e) => -(e.duration().as_millis() as i64),
+    };
+    naturaltime_epoch_at(millis, now_millis)
+}
+
+fn naturaltime_epoch_at(millis: i64, now_millis: i64) -> String {
+    let ago = TimeDelta::from_millis(now_millis.saturating_sub(millis));
+    naturaltime_td(ago, true, "seconds")
+}
+
+/// Describe when a file was last modified: "modified 2 hours ago".
+///
+/// The error is the one reading the file's metadata gave.
+///
+/// # Examples
+/// ```no_run
+/// use speakhuman::time::naturalmtime;
+/// use std::path::Path;
+/// println!("{}", naturalmtime(Path::new("Cargo.toml")).unwrap());
+/// ```
+pub fn naturalmtime(path: &Path) -> io::Result<String> {
+    let modified = fs::metadata(path)?.modified()?;
+    Ok(file_time(modified, SystemTime::now(), "modified %s"))
+}
+
+/// Describe when a file was last accessed: "accessed 5 minutes ago".
+///
+/// Many file systems are mounted without updating access times, or only
+/// update them once a day.
+pub fn naturalatime(path: &Path) -> io::Result<String> {
+    let accessed = fs::metadata(path)?.accessed()?;
+    Ok(file_time(accessed, SystemTime::now(), "accessed %s"))
+}
+
+/// Describe when a file was created: "created 3 days ago".
+///
+/// This is the birth time, which is not the Unix `ctime` (the last change
+/// of the file's metadata), and not every platform or file system records
+/// it; where it is missing the error is `ErrorKind::Unsupported`.
+pub fn naturalctime(path: &Path) -> io::Result<String> {
+    let created = fs::metadata(path)?.created()?;
+    Ok(file_time(created, SystemTime::now(), "created %s"))
+}
+
+/// `template`, translated, with when `time` was relative to `now`.
+fn file_time(time: SystemTime, now: SystemTime, template: &str) -> String {
+    let (ago, future) = match now.duration_since(time) {
+        Ok(ago) => (ago, false),
+        Err(e) => (e.duration(), true),
+    };
+    let when = naturaltime_styled(ago.into(), future, true, Unit::Seconds);
+    let text = i18n::gettext(template).replace("%s", &when);
+    emoji::decorate(|table| &table.duration, text)
+}
+
+/// Describe a deadline relative to now: "due in 3 days", "due tomorrow",
+/// "overdue by 2 hours".
+///
+/// A deadline less than a day away is given in hours or minutes; one on the
+/// next calendar day is "due tomorrow". Days are counted in `due`'s time
+/// zone, so pass a `DateTime<Local>` for the user's calendar.
+///
+/// # Examples
+/// ```
+/// use chrono::{Duration, Local};
+/// use speakhuman::time::naturaldeadline;
+/// let due = Local::now() + Duration::minutes(150);
+/// assert_eq!(naturaldeadline(due), "due in 2 hours");
+/// ```
+#[cfg(feature = "chrono")]
+pub fn naturaldeadline<Tz: TimeZone>(due: DateTime<Tz>) -> String {
+    let now = Utc::now().with_timezone(&due.timezone());
+    naturaldeadline_at(due, now)
+}
+
+#[cfg(feature = "chrono")]
+fn naturaldeadline_at<Tz: TimeZone>(due: DateTime<Tz>, now: DateTime<Tz>) -> String {
+    let tomorrow = due.date_naive() == now.date_naive() + Days::new(1);
+    let remaining = TimeDelta::from(due - now);
+    let text = if remaining.days < 0 {
+        let late = naturaldelta_styled(-remaining, true, Unit::Seconds);
+        i18n::gettext("overdue by %s").replace("%s", &late)
+    } else if remaining.days >= 1 && tomorrow {
+        i18n::gettext("due tomorrow")
+    } else {
+        let left = naturaldelta_styled(remaining, true, Unit::Seconds);
+        i18n::gettext("due in %s").replace("%s", &left)
+    };
+    emoji::decorate(|table| &table.duration, text)
+}
+
+/// Estimate the time left on a job from its progress: "about 5 minutes
+/// remaining".
+///
+/// The rate so far is assumed to hold. Under 5 seconds left, or with
+/// everything done, it is "finishing up"; with nothing done yet there is no
+/// rate to go on.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{naturaleta, TimeDelta};
+/// let elapsed = TimeDelta::from_minutes(5);
+/// assert_eq!(naturaleta(50, 100, elapsed), "about 5 minutes remaining");
+/// assert_eq!(naturaleta(20, 100, elapsed), "about 20 minutes remaining");
+/// assert_eq!(naturaleta(999, 1000, elapsed), "finishing up");
+/// assert_eq!(naturaleta(0, 100, elapsed), "estimating time remaining");
+/// ```
+pub fn naturaleta(done: u64, total: u64, elapsed: TimeDelta) -> String {
+    let text = if done == 0 && total > 0 {
+        i18n::gettext("estimating time remaining")
+    } else {
+        let left = i128::from(total.saturating_sub(done));
+        let remaining = elapsed
+            .total_microseconds()
+            .abs()
+            .checked_mul(left)
+            .map_or(TimeDelta::MAX, |micros| {
+                TimeDelta::from_microseconds(micros / i128::from(done.max(1)))
+            });
+        if remaining < TimeDelta::from_seconds(5.0) {
+            i18n::gettext("finishing up")
+        } else {
+            let remaining = naturaldelta_styled(remaining, true, Unit::Seconds);
+            i18n::gettext("about %s remaining").replace("%s", &remaining)
+        }
+    };
+    emoji::decorate(|table| &table.duration, text)
+}
+
+/// How [`naturaltime_delta`] phrases a relative time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RelativeTimeStyle {
+    /// The usual phrasing: "3 days ago", "a minute from now", "now".
+    #[default]
+    Natural,
+    /// English `Intl.RelativeTimeFormat` with `style: "long"`: "in 3 days",
+    /// "2 hours ago".
+    IntlLong,
+    /// English `Intl.RelativeTimeFormat` with `style: "short"`: "in 3 days",
+    /// "2 hr. ago".
+    IntlShort,
+    /// English `Intl.RelativeTimeFormat` with `style: "narrow"`: "in 3d",
+    /// "2h ago".
+    IntlNarrow,
+}
+
+thread_local! {
+    static RELATIVE_TIME_STYLE: Cell<RelativeTimeStyle> =
+        const { Cell::new(RelativeTimeStyle::Natural) };
+}
+
+/// Set how [`naturaltime_delta`] phrases relative times on the current
+/// thread.
+///
+/// The `Intl` styles match what `new Intl.RelativeTimeFormat("en", { style })`
+/// formats for the unit and count `naturaldelta` would pick, with the default
+/// `numeric: "always"`. They are always in English and never spelled out by
+/// the [`verbal`] style, so server-rendered text matches the browser's.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{naturaltime_delta, set_relative_time_style, RelativeTimeStyle, TimeDelta};
+/// let delta = TimeDelta::from_seconds(7200.0);
+/// set_relative_time_style(RelativeTimeStyle::IntlShort);
+/// assert_eq!(naturaltime_delta(delta, false, true, "seconds"), "2 hr. ago");
+/// set_relative_time_style(RelativeTimeStyle::Natural);
+/// assert_eq!(naturaltime_delta(delta, false, true, "seconds"), "2 hours ago");
+/// ```
+pub fn set_relative_time_style(style: RelativeTimeStyle) {
+    RELATIVE_TIME_STYLE.with(|s| s.set(style));
+}
+
+/// The relative time style of the current thread.
+pub fn relative_time_style() -> RelativeTimeStyle {
+    RELATIVE_TIME_STYLE.with(|s| s.get())
+}
+
+/// `Intl.RelativeTimeFormat` units used by the `Intl` styles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IntlUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Month,
+    Year,
+}
+
+impl IntlUnit {
+    /// English names as (long singular, long plural, short singular, short
+    /// plural, narrow).
+    fn names(self) -> [&'static str; 5] {
+        match self {
+            IntlUnit::Second => ["second", "seconds", "sec.", "sec.", "s"],
+            IntlUnit::Minute => ["minute", "minutes", "min.", "min.", "m"],
+            IntlUnit::Hour => ["hour", "hours", "hr.", "hr.", "h"],
+            IntlUnit::Day => ["day", "days", "day", "days", "d"],
+            IntlUnit::Month => ["month", "months", "mo.", "mo.", "mo"],
+            IntlUnit::Year => ["year", "years", "yr.", "yr.", "y"],
+        }
+    }
+}
+
+/// The unit and count `naturaldelta` would describe `delta` with, keeping
+/// only the larger unit of "1 year, 3 months".
+fn intl_unit_and_count(delta: TimeDelta, months: bool) -> (IntlUnit, i64) {
+    let delta = delta.abs();
+    let years = delta.days / 365;
+    let days = delta.days % 365;
+    let num_months = py_round((days as f64) / 30.5);
+
+    if years == 0 && days < 1 {
+        if delta.seconds < 60 {
+            return (IntlUnit::Second, delta.seconds);
+        }
+        if delta.seconds < 3600 {
+            return match py_round((delta.seconds as f64) / 60.0) {
+                60 => (IntlUnit::Hour, 1),
+                minutes => (IntlUnit::Minute, minutes),
+            };
+        }
+        return match py_round((delta.seconds as f64) / 3600.0) {
+            24 => (IntlUnit::Day, 1),
+            hours => (IntlUnit::Hour, hours),
+        };
+    }
+    if years == 0 {
+        if days == 1 || !months || num_months == 0 {
+            return (IntlUnit::Day, days);
+        }
+        if num_months == 12 {
+            return (IntlUnit::Year, 1);
+        }
+        return (IntlUnit::Month, num_months);
+    }
+    if years == 1 && months && num_months == 12 {
+        return (IntlUnit::Year, 2);
+    }
+    (IntlUnit::Year, years)
+}
+
+/// Format like English `Intl.RelativeTimeFormat` with `numeric: "always"`.
+fn intl_relative_time(
+    delta: TimeDelta,
+    future: bool,
+    months: bool,
+    style: RelativeTimeStyle,
+) -> String {
+    let (unit, count) = intl_unit_and_count(delta, months);
+    let [long, long_plural, short, short_plural, narrow] = unit.names();
+    let number = group_thousands(count.to_string(), ",");
+    let amount = match style {
+        RelativeTimeStyle::IntlNarrow => format!("{}{}", number, narrow),
+        RelativeTimeStyle::IntlShort if count == 1 => format!("{} {}", number, short),
+        RelativeTimeStyle::IntlShort => format!("{} {}", number, short_plural),
+        _ if count == 1 => format!("{} {}", number, long),
+        _ => format!("{} {}", number, long_plural),
+    };
+    if future {
+        format!("in {}", amount)
+    } else {
+        format!("{} ago", amount)
+    }
+}
+
+/// Return "today", "tomorrow", or "yesterday" for nearby dates,
+/// otherwise format with the given strftime format.
+///
+/// Month and weekday names (`%b`, `%B`, `%a`, `%A`) are translated into the
+/// active locale.
+///
+/// Anything with a calendar date can be passed: a `NaiveDate`, a
+/// `NaiveDateTime`, or a `DateTime` in any time zone, which is compared by
+/// its date in that zone.
+///
+/// # Examples
+/// ```
+/// use chrono::{Days, Local};
+/// use speakhuman::time::naturalday;
+/// let now = Local::now();
+/// assert_eq!(naturalday(now, "%b %d"), "today");
+/// assert_eq!(naturalday(now.naive_local() + Days::new(1), "%b %d"), "tomorrow");
+/// ```
+#[cfg(feature = "chrono")]
+pub fn naturalday<D: Datelike>(value: D, format: &str) -> String {
+    naturalday_on(value, format, Local::now().date_naive())
+}
+
+/// Like [`naturalday`], with `today` given rather than read from the clock.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use speakhuman::time::naturalday_on;
+/// let june = |d| NaiveDate::from_ymd_opt(2024, 6, d).unwrap();
+/// assert_eq!(naturalday_on(june(11), "%b %d", june(12)), "yesterday");
+/// assert_eq!(naturalday_on(june(20), "%b %d", june(12)), "Jun 20");
+/// ```
+#[cfg(feature = "chrono")]
+pub fn naturalday_on<D: Datelike>(value: D, format: &str, today: NaiveDate) -> String {
+    naturalday_from(calendar_date(&value), format, false, today)
+}
+
+/// Like [`naturalday`], but other dates up to a week away are named by
+/// their weekday: "last Friday", "next Tuesday".
+///
+/// # Examples
+/// ```
+/// use chrono::{Days, Local};
+/// use speakhuman::time::naturalday_relative;
+/// let now = Local::now();
+/// assert_eq!(naturalday_relative(now, "%b %d"), "today");
+/// let in_three_days = naturalday_relative(now + Days::new(3), "%b %d");
+/// assert!(in_three_days.starts_with("next "));
+/// ```
+#[cfg(feature = "chrono")]
+pub fn naturalday_relative<D: Datelike>(value: D, format: &str) -> String {
+    let today = Local::now().date_naive();
+    naturalday_from(calendar_date(&value), format, true, today)
+}
+
+/// The calendar date of `value`, which may also carry a time of day.
+#[cfg(feature = "chrono")]
+fn calendar_date<D: Datelike>(value: &D) -> NaiveDate {
+    NaiveDate::from_num_days_from_ce_opt(value.num_days_from_ce())
+        .expect("a Datelike date is in chrono's range")
+}
+
+/// [`naturalday`] for a `time::Date`.
+#[cfg(all(feature = "time", feature = "chrono"))]
+pub fn naturalday_date(value: ::time::Date, format: &str) -> String {
+    naturalday(naive_date(value), format)
+}
+
+#[cfg(all(feature = "time", feature = "chrono"))]
+fn naive_date(value: ::time::Date) -> NaiveDate {
+    let month = u8::from(value.month()).into();
+    NaiveDate::from_ymd_opt(value.year(), month, value.day().into())
+        .expect("time::Date is within chrono's range")
+}
+
+#[cfg(feature = "chrono")]
+fn naturalday_from(
+    value: NaiveDate,
+    format: &str,
+    relative_weekdays: bool,
+    today: NaiveDate,
+) -> String {
+    let diff = (value - today).num_days();
+
+    if diff == 0 {
+        return i18n::gettext("today");
+    }
+    if diff == 1 {
+        return i18n::gettext("tomorrow");
+    }
+    if diff == -1 {
+        return i18n::gettext("yesterday");
+    }
+    if relative_weekdays && diff.abs() <= 7 {
+        let weekday = value.weekday().num_days_from_monday() as usize;
+        let name = i18n::pgettext("weekday name", WEEKDAY_NAMES[weekday]);
+        let template = if diff < 0 {
+            i18n::gettext("last %s")
+        } else {
+            i18n::gettext("next %s")
+        };
+        return template.replace("%s", &name);
+    }
+
+    format_date(value, format)
+}
+
+/// `value.format(format)`, with month and weekday names translated.
+#[cfg(feature = "chrono")]
+fn format_date(value: NaiveDate, format: &str) -> String {
+    let month = MONTH_NAMES[value.month0() as usize];
+    let weekday = WEEKDAY_NAMES[value.weekday().num_days_from_monday() as usize];
+    let mut localized = String::with_capacity(format.len());
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            localized.push(c);
+            continue;
+        }
+        let name = match chars.next() {
+            Some('a') => i18n::pgettext("abbreviated weekday name", &weekday[..3]),
+            Some('A') => i18n::pgettext("weekday name", weekday),
+            Some('b' | 'h') => i18n::pgettext("abbreviated month name", &month[..3]),
+            Some('B') => i18n::pgettext("month name", month),
+            Some(other) => {
+                localized.push('%');
+                localized.push(other);
+                continue;
+            }
+            None => {
+                localized.push('%');
+                continue;
+            }
+        };
+        localized.push_str(&name.replace('%', "%%"));
+    }
+    value.format(&localized).to_string()
+}
+
+/// Like naturalday, but append a year for dates more than ~five months away.
+#[cfg(feature = "chrono")]
+pub fn naturaldate<D: Datelike>(value: D) -> String {
+    naturaldate_on(value, Local::now().date_naive())
+}
+
+/// Like [`naturaldate`], with `today` given rather than read from the clock.
+#[cfg(feature = "chrono")]
+pub fn naturaldate_on<D: Datelike>(value: D, today: NaiveDate) -> String {
+    naturaldate_from(calendar_date(&value), today)
+}
+
+#[cfg(feature = "chrono")]
+fn naturaldate_from(value: NaiveDate, today: NaiveDate) -> String {
+    let diff = (value - today).num_days().unsigned_abs();
+
+    let format = if diff >= (5 * 365 / 12) as u64 {
+        i18n::pgettext("date format", "%b %d %Y")
+    } else {
+        i18n::pgettext("date format", "%b %d")
+    };
+    naturalday_from(value, &format, false, today)
+}
+
+/// [`naturaldate`] for a UNIX timestamp, in seconds, read in the local time
+/// zone. A timestamp out of chrono's range is returned as is.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::naturaldate_epoch;
+/// assert_eq!(naturaldate_epoch(1_000_040_000), "Sep 09 2001");
+/// ```
+#[cfg(feature = "chrono")]
+pub fn naturaldate_epoch(secs: i64) -> String {
+    match DateTime::from_timestamp(secs, 0) {
+        Some(value) => naturaldate(value.with_timezone(&Local).date_naive()),
+        None => secs.to_string(),
+    }
+}
+
+/// [`naturaldate_epoch`] for a UNIX timestamp in milliseconds.
+#[cfg(feature = "chrono")]
+pub fn naturaldate_epoch_millis(millis: i64) -> String {
+    match DateTime::from_timestamp_millis(millis) {
+        Some(value) => naturaldate(value.with_timezone(&Local).date_naive()),
+        None => millis.to_string(),
+    }
+}
+
+/// Like [`naturaldate`], but dates up to a year away are given relative to
+/// today, the way GitHub shows them: "5 days ago", "last week", "in 3 weeks",
+/// "2 months ago".
+///
+/// Months are counted on the calendar; under a month, whole weeks are
+/// counted. Dates a year or more away are formatted as by `naturaldate`.
+#[cfg(feature = "chrono")]
+pub fn naturaldate_relative(value: NaiveDate) -> String {
+    naturaldate_relative_from(value, Local::now().date_naive())
+}
+
+#[cfg(feature = "chrono")]
+fn naturaldate_relative_from(value: NaiveDate, today: NaiveDate) -> String {
+    let diff = (value - today).num_days();
+    let past = diff < 0;
+    let (earlier, later) = if past { (value, today) } else { (today, value) };
+    let months = whole_months(
+        earlier.and_time(NaiveTime::MIN),
+        later.and_time(NaiveTime::MIN),
+    ) as i64;
+    let days = diff.abs();
+
+    if days <= 1 {
+        return naturalday_from(value, "%b %d", false, today);
+    }
+    if months >= 12 {
+        return naturaldate_from(value, today);
+    }
+    let weeks = days / 7;
+    let (count, singular, plural) = match (days, months, past) {
+        (..=6, _, true) => (days, "%d day ago", "%d days ago"),
+        (..=6, _, false) => (days, "in %d day", "in %d days"),
+        (..=13, 0, true) => return i18n::gettext("last week"),
+        (..=13, 0, false) => return i18n::gettext("next week"),
+        (_, 0, true) => (weeks, "%d week ago", "%d weeks ago"),
+        (_, 0, false) => (weeks, "in %d week", "in %d weeks"),
+        (_, 1, true) => return i18n::gettext("last month"),
+        (_, 1, false) => return i18n::gettext("next month"),
+        (_, _, true) => (months, "%d month ago", "%d months ago"),
+        (_, _, false) => (months, "in %d month", "in %d months"),
+    };
+    i18n::ngettext(singular, plural, count).replace("%d", &count.to_string())
+}
+
+/// Describe the age of someone born on `birthdate`, today: "32 years old",
+/// "18 months old", "3 weeks old".
+///
+/// See [`naturalage_on`] for how the unit is chosen.
+#[cfg(feature = "chrono")]
+pub fn naturalage(birthdate: NaiveDate) -> Result<String, TimeError> {
+    naturalage_on(birthdate, Local::now().date_naive())
+}
+
+/// Describe the age of someone born on `birthdate`, as of `on`.
+///
+/// The unit is the one people use for that age: days for the first two
+/// weeks, weeks until three months, months until two years, and years after
+/// that. Months and years are counted on the calendar, so the age goes up on
+/// the birthday. A birthdate after `on` is [`TimeError::FutureBirthdate`].
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use speakhuman::time::naturalage_on;
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+/// assert_eq!(naturalage_on(date(1992, 6, 12), date(2024, 6, 12)).unwrap(), "32 years old");
+/// assert_eq!(naturalage_on(date(1992, 6, 13), date(2024, 6, 12)).unwrap(), "31 years old");
+/// assert_eq!(naturalage_on(date(2022, 12, 1), date(2024, 6, 12)).unwrap(), "18 months old");
+/// assert_eq!(naturalage_on(date(2024, 5, 20), date(2024, 6, 12)).unwrap(), "3 weeks old");
+/// ```
+#[cfg(feature = "chrono")]
+pub fn naturalage_on(birthdate: NaiveDate, on: NaiveDate) -> Result<String, TimeError> {
+    if birthdate > on {
+        return Err(TimeError::FutureBirthdate);
+    }
+    let days = (on - birthdate).num_days();
+    let midnight = |date: NaiveDate| date.and_time(NaiveTime::MIN);
+    let months = whole_months(midnight(birthdate), midnight(on));
+    let (count, singular, plural) = if days < 14 {
+        (days, "%d day old", "%d days old")
+    } else if months < 3 {
+        (days / 7, "%d week old", "%d weeks old")
+    } else if months < 24 {
+        (months as i64, "%d month old", "%d months old")
+    } else {
+        (months as i64 / 12, "%d year old", "%d years old")
+    };
+    Ok(i18n::ngettext(singular, plural, count).replace("%d", &count.to_string()))
+}
+
+/// English weekday names from Monday, translated with the context "weekday
+/// name".
+#[cfg(feature = "chrono")]
+const WEEKDAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// English month names, translated with the context "month name".
+#[cfg(feature = "chrono")]
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Format a date with an ordinal day: "June 3rd, 2024", or "3rd June 2024"
+/// when the active locale puts the day first.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use speakhuman::time::naturaldate_ordinal;
+/// let date = NaiveDate::from_ymd_opt(2024, 6, 3).unwrap();
+/// assert_eq!(naturaldate_ordinal(date), "June 3rd, 2024");
+/// ```
+#[cfg(feature = "chrono")]
+pub fn naturaldate_ordinal(value: NaiveDate) -> String {
+    let month = i18n::pgettext("month name", MONTH_NAMES[value.month0() as usize]);
+    let day = ordinal(&value.day().to_string());
+    if i18n::day_first() {
+        format!("{} {} {}", day, month, value.year())
+    } else {
+        format!("{} {}, {}", month, day, value.year())
+    }
+}
+
+/// The span of time [`natural_period`] names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Granularity {
+    Day,
+    /// ISO weeks, starting on Monday.
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+/// Name the day, week, month, quarter or year containing `value`, relative
+/// to today: "this week", "last month", "next quarter".
+///
+/// Months and quarters further away but in the current year are "earlier
+/// this year" or "later this year"; anything else is named absolutely
+/// ("June 3rd, 2024", "week 23, 2024", "June 2024", "Q3 2024", "2024").
+///
+/// # Examples
+/// ```
+/// use chrono::{Duration, Local};
+/// use speakhuman::time::{natural_period, Granularity};
+/// let today = Local::now().date_naive();
+/// assert_eq!(natural_period(today, Granularity::Week), "this week");
+/// let last_year = today - Duration::days(366);
+/// assert_eq!(natural_period(last_year, Granularity::Year), "last year");
+/// ```
+#[cfg(feature = "chrono")]
+pub fn natural_period(value: NaiveDate, granularity: Granularity) -> String {
+    natural_period_from(value, granularity, Local::now().date_naive())
+}
+
+#[cfg(feature = "chrono")]
+fn natural_period_from(value: NaiveDate, granularity: Granularity, today: NaiveDate) -> String {
+    let index = |date: NaiveDate| -> i64 {
+        match granularity {
+            Granularity::Day => date.num_days_from_ce() as i64,
+            Granularity::Week => (date.num_days_from_ce() as i64 - 1).div_euclid(7),
+            Granularity::Month => date.year() as i64 * 12 + date.month0() as i64,
+            Granularity::Quarter => date.year() as i64 * 4 + date.month0() as i64 / 3,
+            Granularity::Year => date.year() as i64,
+        }
+    };
+    let offset = index(value) - index(today);
+
+    let relative = match (granularity, offset) {
+        (Granularity::Day, 0) => Some("today"),
+        (Granularity::Day, -1) => Some("yesterday"),
+        (Granularity::Day, 1) => Some("tomorrow"),
+        (Granularity::Week, 0) => Some("this week"),
+        (Granularity::Week, -1) => Some("last week"),
+        (Granularity::Week, 1) => Some("next week"),
+        (Granularity::Month, 0) => Some("this month"),
+        (Granularity::Month, -1) => Some("last month"),
+        (Granularity::Month, 1) => Some("next month"),
+        (Granularity::Quarter, 0) => Some("this quarter"),
+        (Granularity::Quarter, -1) => Some("last quarter"),
+        (Granularity::Quarter, 1) => Some("next quarter"),
+        (Granularity::Year, 0) => Some("this year"),
+        (Granularity::Year, -1) => Some("last year"),
+        (Granularity::Year, 1) => Some("next year"),
+        _ => None,
+    };
+    if let Some(text) = relative {
+        return i18n::gettext(text);
+    }
+    let coarse = matches!(granularity, Granularity::Month | Granularity::Quarter);
+    if coarse && value.year() == today.year() {
+        return if offset < 0 {
+            i18n::gettext("earlier this year")
+        } else {
+            i18n::gettext("later this year")
+        };
+    }
+
+    match granularity {
+        Granularity::Day => naturaldate_ordinal(value),
+        Granularity::Week => {
+            let week = value.iso_week();
+            i18n::gettext("week %s, %s")
+                .replacen("%s", &week.week().to_string(), 1)
+                .replacen("%s", &week.year().to_string(), 1)
+        }
+        Granularity::Month => {
+            let month = i18n::pgettext("month name", MONTH_NAMES[value.month0() as usize]);
+            format!("{} {}", month, value.year())
+        }
+        Granularity::Quarter => natural_quarter(value),
+        Granularity::Year => value.year().to_string(),
+    }
+}
+
+/// Name the calendar quarter `value` falls in: "Q3 2024".
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use speakhuman::time::natural_quarter;
+/// assert_eq!(natural_quarter(NaiveDate::from_ymd_opt(2024, 8, 15).unwrap()), "Q3 2024");
+/// ```
+#[cfg(feature = "chrono")]
+pub fn natural_quarter(value: NaiveDate) -> String {
+    i18n::gettext("Q%s %s")
+        .replacen("%s", &(value.month0() / 3 + 1).to_string(), 1)
+        .replacen("%s", &value.year().to_string(), 1)
+}
+
+/// Name the fiscal quarter `value` falls in, for a fiscal year starting on
+/// the first of `fiscal_year_start`: "FY2025 Q1".
+///
+/// A fiscal year is named for the calendar year it ends in, so with an
+/// October start, October 2024 is in FY2025.
+///
+/// # Examples
+/// ```
+/// use chrono::{Month, NaiveDate};
+/// use speakhuman::time::natural_fiscal_quarter;
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+/// assert_eq!(natural_fiscal_quarter(date(2024, 10, 1), Month::October), "FY2025 Q1");
+/// assert_eq!(natural_fiscal_quarter(date(2024, 9, 30), Month::October), "FY2024 Q4");
+/// assert_eq!(natural_fiscal_quarter(date(2024, 8, 15), Month::January), "FY2024 Q3");
+/// ```
+#[cfg(feature = "chrono")]
+pub fn natural_fiscal_quarter(value: NaiveDate, fiscal_year_start: Month) -> String {
+    let start = fiscal_year_start.number_from_month() - 1;
+    let into_year = (value.month0() + 12 - start) % 12;
+    let year = if start > 0 && value.month0() >= start {
+        value.year() + 1
+    } else {
+        value.year()
+    };
+    i18n::gettext("FY%s Q%s")
+        .replacen("%s", &year.to_string(), 1)
+        .replacen("%s", &(into_year / 3 + 1).to_string(), 1)
+}
+
+/// Name the ISO week containing `value`: "week 34 of 2024".
+///
+/// Weeks start on Monday, and the year is the ISO week-numbering year, so
+/// December 30th, 2024 is in "week 1 of 2025".
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveDate;
+/// use speakhuman::time::natural_iso_week;
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+/// assert_eq!(natural_iso_week(date(2024, 8, 22)), "week 34 of 2024");
+/// assert_eq!(natural_iso_week(date(2024, 12, 30)), "week 1 of 2025");
+/// ```
+#[cfg(feature = "chrono")]
+pub fn natural_iso_week(value: NaiveDate) -> String {
+    let week = value.iso_week();
+    i18n::gettext("week %s of %s")
+        .replacen("%s", &week.week().to_string(), 1)
+        .replacen("%s", &week.year().to_string(), 1)
+}
+
+/// Name the ISO week containing `value` relative to this one: "this week",
+/// "next week", "2 weeks ago (W27)", "in 3 weeks (W38)".
+///
+/// Weeks more than a year away are named with [`natural_iso_week`].
+#[cfg(feature = "chrono")]
+pub fn natural_week(value: NaiveDate) -> String {
+    natural_week_from(value, Local::now().date_naive())
+}
+
+#[cfg(feature = "chrono")]
+fn natural_week_from(value: NaiveDate, today: NaiveDate) -> String {
+    let monday = |date: NaiveDate| (date.num_days_from_ce() as i64 - 1).div_euclid(7);
+    let offset = monday(value) - monday(today);
+    let weeks = offset.abs();
+    let text = match offset {
+        0 => return i18n::gettext("this week"),
+        -1 => return i18n::gettext("last week"),
+        1 => return i18n::gettext("next week"),
+        _ if weeks > 52 => return natural_iso_week(value),
+        _ if offset < 0 => i18n::ngettext("%d week ago", "%d weeks ago", weeks),
+        _ => i18n::ngettext("in %d week", "in %d weeks", weeks),
+    };
+    let text = text.replace("%d", &weeks.to_string());
+    format!("{} (W{:02})", text, value.iso_week().week())
+}
+
+/// Place a year in history relative to now, with coarser steps further
+/// away: "in the 1990s", "mid-18th century", "about 4,500 years ago",
+/// "about 65 million years ago".
+///
+/// Years within a century of now are named by decade ("this decade" for the
+/// current one), those within a millennium by third of a century, and older
+/// ones by their distance, to two significant digits. Future years work the
+/// same way ("in the 2050s", "in about 3,000 years"). Year 0 is 1 BC; for a
+/// date, pass `date.year()`.
+///
+/// # Examples
+/// ```
+/// use chrono::{Datelike, Local};
+/// use speakhuman::time::natural_era;
+/// assert_eq!(natural_era(Local::now().year() as i64), "this decade");
+/// assert_eq!(natural_era(1750), "mid-18th century");
+/// assert_eq!(natural_era(-2500), "about 4,500 years ago");
+/// ```
+#[cfg(feature = "chrono")]
+pub fn natural_era(year: i64) -> String {
+    natural_era_from(year, Local::now().year() as i64)
+}
+
+#[cfg(feature = "chrono")]
+fn natural_era_from(year: i64, current_year: i64) -> String {
+    let distance = current_year.saturating_sub(year);
+    if distance.abs() <= 100 {
+        if year.div_euclid(10) == current_year.div_euclid(10) {
+            return i18n::gettext("this decade");
+        }
+        let decade = year.div_euclid(10) * 10;
+        return i18n::gettext("in the %s").replace("%s", &format!("{}s", decade));
+    }
+    if distance > 0 && distance <= 1000 {
+        let century = (year - 1).div_euclid(100) + 1;
+        let template = match (year - 1).rem_euclid(100) {
+            0..=32 => i18n::gettext("early %s century"),
+            33..=65 => i18n::gettext("mid-%s century"),
+            _ => i18n::gettext("late %s century"),
+        };
+        return template.replace("%s", &ordinal(&century.to_string()));
+    }
+
+    // Two significant digits, then words for millions and up
+    let years = distance.unsigned_abs() as u128;
+    let magnitude = 10u128.pow(years.ilog10().saturating_sub(1));
+    let rounded = (years + magnitude / 2) / magnitude * magnitude;
+    let rounded = rounded.to_string();
+    let amount = if rounded.len() < 7 {
+        intcomma(&rounded, None)
+    } else if rounded.len() % 3 == 1 {
+        // A single digit before the scale word, so keep one decimal
+        intword(&rounded, "%.1f")
+    } else {
+        intword(&rounded, "%.0f")
+    };
+    let template = if distance > 0 {
+        i18n::gettext("about %s years ago")
+    } else {
+        i18n::gettext("in about %s years")
+    };
+    template.replace("%s", &amount)
+}
+
+/// The default parts of the day for [`natural_daypart`], as (hour, minute,
+/// label) of the time each one starts, in order.
+#[cfg(feature = "chrono")]
+pub const DAYPARTS: &[(u32, u32, &str)] = &[
+    (0, 0, "overnight"),
+    (5, 0, "early morning"),
+    (8, 0, "morning"),
+    (10, 0, "mid-morning"),
+    (12, 0, "noon"),
+    (13, 0, "early afternoon"),
+    (15, 0, "late afternoon"),
+    (17, 0, "early evening"),
+    (20, 0, "late evening"),
+    (23, 0, "overnight"),
+];
+
+/// Name the part of the day a clock time falls in, like "mid-morning" or
+/// "late evening".
+///
+/// `dayparts` lists the (hour, minute, label) each part starts at, in order,
+/// such as [`DAYPARTS`]. A time before the first start belongs to the last
+/// part, which runs on past midnight. Labels are translated with the context
+/// "daypart".
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveTime;
+/// use speakhuman::time::{natural_daypart, DAYPARTS};
+/// let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+/// assert_eq!(natural_daypart(time(6, 15), DAYPARTS), "early morning");
+/// assert_eq!(natural_daypart(time(12, 30), DAYPARTS), "noon");
+/// assert_eq!(natural_daypart(time(2, 0), DAYPARTS), "overnight");
+/// ```
+#[cfg(feature = "chrono")]
+pub fn natural_daypart(value: NaiveTime, dayparts: &[(u32, u32, &str)]) -> String {
+    let minute = value.hour() * 60 + value.minute();
+    dayparts
+        .iter()
+        .rev()
+        .find(|(hour, min, _)| hour * 60 + min <= minute)
+        .or(dayparts.last())
+        .map(|(_, _, label)| i18n::pgettext("daypart", label))
+        .unwrap_or_default()
+}
+
+/// Say a clock time the way people speak it: "noon", "quarter past three",
+/// "ten to five".
+///
+/// The time is rounded to the nearest five minutes and seconds are ignored.
+/// With `precise`, a time off a five-minute mark is given as "3:17 PM"
+/// instead. Numbers are spelled in English.
+///
+/// # Examples
+/// ```
+/// use chrono::NaiveTime;
+/// use speakhuman::time::naturalclock;
+/// let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+/// assert_eq!(naturalclock(time(12, 0), false), "noon");
+/// assert_eq!(naturalclock(time(15, 14), false), "quarter past three");
+/// assert_eq!(naturalclock(time(16, 50), false), "ten to five");
+/// assert_eq!(naturalclock(time(15, 17), true), "3:17 PM");
+/// ```
+#[cfg(feature = "chrono")]
+pub fn naturalclock(value: NaiveTime, precise: bool) -> String {
+    let (hour, minute) = (value.hour(), value.minute());
+    if precise && minute % 5 != 0 {
+        let period = if hour < 12 { "AM" } else { "PM" };
+        return format!(
+            "{}:{:02} {}",
+            (hour + 11) % 12 + 1,
+            minute,
+            i18n::pgettext("clock", period)
+        );
+    }
+    // Round to the nearest five minutes, wrapping past midnight
+    let minutes = ((hour * 60 + minute + 2) / 5 * 5) % (24 * 60);
+    let (hour, minute) = (minutes / 60, minutes % 60);
+    let hour_name = |hour: u32| spell_clock_number((hour + 11) % 12 + 1);
+    match minute {
+        0 if hour == 0 => i18n::pgettext("clock", "midnight"),
+        0 if hour == 12 => i18n::pgettext("clock", "noon"),
+        0 => i18n::pgettext("clock", "%s o'clock").replace("%s", &hour_name(hour)),
+        15 => i18n::pgettext("clock", "quarter past %s").replace("%s", &hour_name(hour)),
+        30 => i18n::pgettext("clock", "half past %s").replace("%s", &hour_name(hour)),
+        45 => i18n::pgettext("clock", "quarter to %s").replace("%s", &hour_name(hour + 1)),
+        1..=29 => i18n::pgettext("clock", "%s past %s")
+            .replacen("%s", &spell_clock_number(minute), 1)
+            .replacen("%s", &hour_name(hour), 1),
+        _ => i18n::pgettext("clock", "%s to %s")
+            .replacen("%s", &spell_clock_number(60 - minute), 1)
+            .replacen("%s", &hour_name(hour + 1), 1),
+    }
+}
+
+#[cfg(feature = "chrono")]
+fn spell_clock_number(n: u32) -> String {
+    verbal::spell_number(&n.to_string()).unwrap_or_else(|| n.to_string())
+}
+
+/// Divide `value` into whole units of `size`, given as a fraction so that a
+/// month can be 30.5 days, with special handling for `minimum_unit` and
+/// suppressed units.
+///
+/// Returns the quotient, the rounded fraction of a unit left over at
+/// `minimum_unit` (zero otherwise) and the whole remainder.
+fn quotient_and_remainder(
+    value: i128,
+    size: (i128, i128),
+    unit: Unit,
+    minimum_unit: Unit,
+    suppress: &HashSet<Unit>,
+    format: &str,
+) -> (i128, f64, i128) {
+    let (numerator, denominator) = size;
+    let scaled = value * denominator;
+    if unit == minimum_unit {
+        let (quotient, fraction) =
+            round_fraction(scaled / numerator, scaled % numerator, numerator, format);
+        return (quotient, fraction, 0);
+    }
+
+    if suppress.contains(&unit) {
+        return (0, 0.0, value);
+    }
+
+    let quotient = scaled / numerator;
+    (quotient, 0.0, (scaled - quotient * numerator) / denominator)
+}
+
+/// Format `whole + fraction` with `format`, keeping every digit of a whole
+/// part too large for an f64.
+fn format_amount(whole: i128, fraction: f64, format: &str) -> String {
+    if whole >= 1 << 53 {
+        if let Some(decimals) = printf_format(format, fraction).strip_prefix('0') {
+            return format!("{}{}", whole, decimals);
+        }
+    }
+    printf_format(format, whole as f64 + fraction)
+}
+
+/// Round `whole + numerator / denominator` the way `format` shows it, split
+/// into the whole part and the fraction left.
+fn round_fraction(whole: i128, numerator: i128, denominator: i128, format: &str) -> (i128, f64) {
+    // Past 2^53 an f64 can't hold the whole part, so only the fraction is
+    // rounded
+    let (base, value) = if whole < 1 << 53 {
+        (0, whole as f64 + numerator as f64 / denominator as f64)
+    } else {
+        (whole, numerator as f64 / denominator as f64)
+    };
+    let rounded = rounding_by_fmt(format, value);
+    let carried = rounded.floor();
+    (base + carried as i128, rounded - carried)
+}
+
+/// Find a suitable minimum unit that is not suppressed.
+fn suitable_minimum_unit(min_unit: Unit, suppress: &HashSet<Unit>) -> Result<Unit, TimeError> {
+    if !suppress.contains(&min_unit) {
+        return Ok(min_unit);
+    }
+    for unit in Unit::all() {
+        if *unit > min_unit && !suppress.contains(unit) {
+            return Ok(*unit);
+        }
+    }
+    Err(TimeError::NoUnitLeft)
+}
+
+/// Length of a unit in microseconds, with a month of 30.5 days and a year
+/// of 365.
+fn unit_micros(unit: Unit) -> i128 {
+    const DAY: i128 = 86_400_000_000;
+    match unit {
+        Unit::Microseconds => 1,
+        Unit::Milliseconds => 1_000,
+        Unit::Seconds => 1_000_000,
+        Unit::Minutes => 60_000_000,
+        Unit::Hours => 3_600_000_000,
+        Unit::Days => DAY,
+        Unit::Weeks => 7 * DAY,
+        Unit::Months => 61 * DAY / 2,
+        Unit::Years => 365 * DAY,
+    }
+}
+/// The smallest unit to show when at most `max` components are wanted: the
+/// `max`th shown unit counting down from the largest one `delta` fills.
+fn last_component(delta: TimeDelta, min_unit: Unit, suppress: &HashSet<Unit>, max: usize) -> Unit {
+    let total = delta.total_microseconds();
+    let shown: Vec<Unit> = Unit::all()
+        .iter()
+        .rev()
+        .copied()
+        .filter(|unit| *unit >= min_unit && !suppress.contains(unit))
+        .skip_while(|unit| *unit > min_unit && total < unit_micros(*unit))
+        .collect();
+    shown
+        .get(max.max(1) - 1)
+        .or(shown.last())
+        .copied()
+        .unwrap_or(min_unit)
+}
+
+/// Extend suppressed units with all units lower than the minimum unit.
+fn suppress_lower_units(min_unit: Unit, suppress: &HashSet<Unit>) -> HashSet<Unit> {
+    let mut result = suppress.clone();
+    for unit in Unit::all() {
+        if *unit == min_unit {
+            break;
+        }
+        result.insert(*unit);
+    }
+    result
+}
+
+/// Round a number according to printf-style format string.
+fn rounding_by_fmt(format: &str, value: f64) -> f64 {
+    let result = printf_format(format, value);
+    result
+        .trim()
+        .parse::<i64>()
+        .map(|i| i as f64)
+        .unwrap_or_else(|_| result.trim().parse::<f64>().unwrap_or(value))
+}
+
+/// Return a precise representation of a timedelta.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{precisedelta_td, TimeDelta};
+/// let delta = TimeDelta::from_days_seconds_micros(2, 3633, 123000);
+/// assert_eq!(precisedelta_td(delta, "seconds", &[], "%0.2f"), "2 days, 1 hour and 33.12 seconds");
+/// ```
+///
+/// An invalid unit comes back as the error message; use
+/// [`try_precisedelta_td`] to tell the two apart.
+pub fn precisedelta_td(
+    value: TimeDelta,
+    minimum_unit: &str,
+    suppress: &[&str],
+    format: &str,
+) -> String {
+    precisedelta_with(
+        value,
+        minimum_unit,
+        suppress,
+        format,
+        PrecisedeltaOptions::default(),
+    )
+}
+
+/// `precisedelta_td`, failing on an unknown unit in `minimum_unit` or
+/// `suppress`, or when every unit from `minimum_unit` up is suppressed.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{try_precisedelta_td, TimeDelta, TimeError};
+/// let delta = TimeDelta::from_seconds(90.0);
+/// assert_eq!(
+///     try_precisedelta_td(delta, "seconds", &[], "%0.0f").unwrap(),
+///     "1 minute and 30 seconds"
+/// );
+/// assert_eq!(
+///     try_precisedelta_td(delta, "seconds", &["fortnights"], "%0.0f"),
+///     Err(TimeError::UnknownUnit("fortnights".to_string()))
+/// );
+/// ```
+pub fn try_precisedelta_td(
+    value: TimeDelta,
+    minimum_unit: &str,
+    suppress: &[&str],
+    format: &str,
+) -> Result<String, TimeError> {
+    try_precisedelta_with(
+        value,
+        minimum_unit,
+        suppress,
+        format,
+        PrecisedeltaOptions::default(),
+    )
+}
+
+/// Extra settings for [`precisedelta_with`]. The default gives what
+/// [`precisedelta_td`] does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PrecisedeltaOptions {
+    /// Show only this many units, counting down from the largest one the
+    /// delta fills (suppressed units don't count). The rest is folded into
+    /// the last shown unit and formatted with `format`, as for
+    /// `minimum_unit`.
+    pub max_components: Option<usize>,
+    /// Give the units between the largest and the smallest shown even when
+    /// they are zero: "1 hour, 0 minutes and 5 seconds" rather than "1 hour
+    /// and 5 seconds". Suppressed units are still left out.
+    pub include_zero_units: bool,
+    /// Put a comma before the "and" when joining the components, as
+    /// [`natural_list_localized`](crate::lists::natural_list_localized) does.
+    pub oxford_comma: bool,
+    /// Break days into weeks: "2 weeks and 3 days" rather than "17 days".
+    /// Weeks are also used when `minimum_unit` is weeks.
+    pub weeks: bool,
+}
+
+/// Like [`precisedelta_td`], with the extra settings in `options`.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{precisedelta_with, PrecisedeltaOptions, TimeDelta};
+/// let delta = TimeDelta::from_days_seconds_micros(2, 3633, 123000);
+/// let options = PrecisedeltaOptions {
+///     max_components: Some(2),
+///     ..Default::default()
+/// };
+/// assert_eq!(precisedelta_with(delta, "seconds", &[], "%0.0f", options), "2 days and 1 hour");
+/// let delta = TimeDelta::from_seconds(3605.0);
+/// let options = PrecisedeltaOptions {
+///     include_zero_units: true,
+///     oxford_comma: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     precisedelta_with(delta, "seconds", &[], "%0.0f", options),
+///     "1 hour, 0 minutes, and 5 seconds"
+/// );
+/// ```
+pub fn precisedelta_with(
+    value: TimeDelta,
+    minimum_unit: &str,
+    suppress: &[&str],
+    format: &str,
+    options: PrecisedeltaOptions,
+) -> String {
+    try_precisedelta_with(value, minimum_unit, suppress, format, options)
+        .unwrap_or_else(|e| e.to_string())
+}
+
+/// `precisedelta_with`, failing as [`try_precisedelta_td`] does.
+pub fn try_precisedelta_with(
+    value: TimeDelta,
+    minimum_unit: &str,
+    suppress: &[&str],
+    format: &str,
+    options: PrecisedeltaOptions,
+) -> Result<String, TimeError> {
+    let min_unit = parse_unit(minimum_unit)?;
+    let suppress_set = suppress
+        .iter()
+        .map(|name| parse_unit(name))
+        .collect::<Result<HashSet<_>, _>>()?;
+    let min_unit = suitable_minimum_unit(min_unit, &suppress_set)?;
+    let text = precisedelta_symbolic(value, min_unit, &suppress_set, format, options);
+    Ok(finish_duration(text))
+}
+
+/// `precisedelta_td` in the current output style, without emoji.
+fn precisedelta_styled(
+    value: TimeDelta,
+    min_unit: Unit,
+    suppress_set: &HashSet<Unit>,
+    format: &str,
+    options: PrecisedeltaOptions,
+) -> String {
+    let text = precisedelta_symbolic(value, min_unit, suppress_set, format, options);
+    if verbal::is_verbal() {
+        verbal::spell_numbers_in(&text)
+    } else {
+        text
+    }
+}
+
+/// `min_unit` must already be a unit that isn't suppressed.
+fn precisedelta_symbolic(
+    value: TimeDelta,
+    min_unit: Unit,
+    suppress_set: &HashSet<Unit>,
+    format: &str,
+    options: PrecisedeltaOptions,
+) -> String {
+    let components = precisedelta_components(value, min_unit, suppress_set, format, options);
+    natural_list_localized(&components, options.oxford_comma)
+}
+
+/// The amounts `precisedelta_symbolic` joins, one per unit shown.
+fn precisedelta_components(
+    value: TimeDelta,
+    min_unit: Unit,
+    suppress_set: &HashSet<Unit>,
+    format: &str,
+    options: PrecisedeltaOptions,
+) -> Vec<String> {
+    let PrecisedeltaOptions {
+        max_components,
+        include_zero_units,
+        weeks,
+        ..
+    } = options;
+    let delta = value.abs();
+    if delta == TimeDelta::MAX {
+        return vec![saturated_delta()];
+    }
+
+    let without_weeks;
+    let suppress_set = if weeks || min_unit == Unit::Weeks || suppress_set.contains(&Unit::Weeks) {
+        suppress_set
+    } else {
+        without_weeks = suppress_set.iter().copied().chain([Unit::Weeks]).collect();
+        &without_weeks
+    };
+
+    let min_unit = match max_components {
+        Some(max) => min_unit.max(last_component(delta, min_unit, suppress_set, max)),
+        None => min_unit,
+    };
+
+    let suppress_set = suppress_lower_units(min_unit, suppress_set);
+
+    let days = i128::from(delta.days);
+    let secs = i128::from(delta.seconds);
+    let usecs = i128::from(delta.microseconds);
+    let split = |value, size, unit| {
+        quotient_and_remainder(value, size, unit, min_unit, &suppress_set, format)
+    };
+
+    // Whatever unit is the minimum one may be left with a fraction, which
+    // is only turned into a float when it is formatted
+    let (years, f_years, days) = split(days, (365, 1), Unit::Years);
+    let (months, f_months, days) = split(days, (61, 2), Unit::Months);
+    let (weeks, f_weeks, days) = split(days, (7, 1), Unit::Weeks);
+
+    let secs = days * 86_400 + secs;
+    let (days, f_days, secs) = split(secs, (86_400, 1), Unit::Days);
+    let (hours, f_hours, secs) = split(secs, (3600, 1), Unit::Hours);
+    let (minutes, f_minutes, secs) = split(secs, (60, 1), Unit::Minutes);
+
+    let usecs = secs * 1_000_000 + usecs;
+    let (secs, f_secs, usecs) = split(usecs, (1_000_000, 1), Unit::Seconds);
+    let (msecs, f_msecs, usecs) = split(usecs, (1000, 1), Unit::Milliseconds);
+    let mut fraction =
+        f_years + f_months + f_weeks + f_days + f_hours + f_minutes + f_secs + f_msecs;
+
+    // Promotion due to rounding
+    let (mut msecs, mut secs, mut minutes, mut hours, mut days, mut weeks, mut months, mut years) =
+        (msecs, secs, minutes, hours, days, weeks, months, years);
+
+    if msecs >= 1000 && !suppress_set.contains(&Unit::Seconds) {
+        msecs -= 1000;
+        secs += 1;
+    }
+    if secs >= 60 && !suppress_set.contains(&Unit::Minutes) {
+        secs -= 60;
+        minutes += 1;
+    }
+    if minutes >= 60 && !suppress_set.contains(&Unit::Hours) {
+        minutes -= 60;
+        hours += 1;
+    }
+    if hours >= 24 && !suppress_set.contains(&Unit::Days) {
+        hours -= 24;
+        days += 1;
+    }
+    if days >= 7 && !suppress_set.contains(&Unit::Weeks) {
+        days -= 7;
+        weeks += 1;
+    }
+    if !suppress_set.contains(&Unit::Months) {
+        if min_unit == Unit::Weeks {
+            // The fraction of a week counts towards the month, and what is
+            // left over is below the minimum unit
+            let total = (weeks * 7) as f64 + fraction * 7.0;
+            if total >= 31.0 {
+                months += 1;
+                weeks = ((total - 31.0) / 7.0).floor() as i128;
+                fraction = 0.0;
+            }
+        } else if weeks * 7 + days >= 31 {
+            let rest = weeks * 7 + days - 31;
+            months += 1;
+            if weeks > 0 {
+                weeks = rest / 7;
+            }
+            days = rest - weeks * 7;
+        }
+    }
+    if months >= 12 && !suppress_set.contains(&Unit::Years) {
+        months -= 12;
+        years += 1;
+    }
+
+    let fmts: Vec<(&str, &str, i128, Unit)> = vec![
+        ("%d year", "%d years", years, Unit::Years),
+        ("%d month", "%d months", months, Unit::Months),
+        ("%d week", "%d weeks", weeks, Unit::Weeks),
+        ("%d day", "%d days", days, Unit::Days),
+        ("%d hour", "%d hours", hours, Unit::Hours),
+        ("%d minute", "%d minutes", minutes, Unit::Minutes),
+        ("%d second", "%d seconds", secs, Unit::Seconds),
+        ("%d millisecond", "%d milliseconds", msecs, Unit::Milliseconds),
+        (
+            "%d microsecond",
+            "%d microseconds",
+            usecs,
+            Unit::Microseconds,
+        ),
+    ];
+    let fraction_of = |unit| if unit == min_unit { fraction } else { 0.0 };
+    let non_zero = |whole: i128, unit| whole > 0 || fraction_of(unit) > 1e-9;
+
+    let mut texts: Vec<String> = Vec::new();
+    // Zero units are only filled in between two non-zero ones
+    let last_non_zero = fmts
+        .iter()
+        .rposition(|&(_, _, whole, unit)| non_zero(whole, unit))
+        .unwrap_or(0);
+
+    for (i, &(singular, plural, whole, unit)) in fmts.iter().enumerate() {
+        let zero_between = include_zero_units
+            && !texts.is_empty()
+            && i < last_non_zero
+            && !suppress_set.contains(&unit);
+
+        if non_zero(whole, unit) || (texts.is_empty() && unit == min_unit) || zero_between {
+            let frac = fraction_of(unit);
+            let ngettext_n = if whole == 1 && frac > 1e-9 {
+                2
+            } else {
+                i64::try_from(whole).unwrap_or(i64::MAX)
+            };
+            let fmt_txt = i18n::ngettext(singular, plural, ngettext_n);
+
+            let formatted = if frac > 1e-9 {
+                // The number is formatted with `format` (e.g., "%0.2f seconds")
+                let number_str = format_amount(whole, frac, format);
+                fmt_txt.replace("%d", format).replace(format, &number_str)
+            } else if unit == Unit::Years {
+                fmt_txt.replace("%d", &intcomma(&whole.to_string(), None))
+            } else {
+                fmt_txt.replace("%d", &whole.to_string())
+            };
+
+            texts.push(formatted);
+        }
+
+        if unit == min_unit {
+            break;
+        }
+    }
+
+    texts
+}
+
+/// Convenience: precisedelta from seconds (float).
+pub fn precisedelta(seconds: f64, minimum_unit: &str, suppress: &[&str], format: &str) -> String {
+    let delta = TimeDelta::from_seconds(seconds);
+    precisedelta_td(delta, minimum_unit, suppress, format)
+}
+
+/// Express a whole delta in one unit, as a decimal: "1.5 hours", "0.75
+/// days", "2.25 years".
+///
+/// The amount is rounded half up to `precision` decimal places, at most 8,
+/// and trailing zeros are dropped. A month is 30.5 days and a year 365
+/// days, as in [`precisedelta`]; the sign of `value` is ignored.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{decimaldelta, TimeDelta, Unit};
+/// assert_eq!(decimaldelta(TimeDelta::from_minutes(90), Unit::Hours, 2), "1.5 hours");
+/// assert_eq!(decimaldelta(TimeDelta::from_hours(18), Unit::Days, 2), "0.75 days");
+/// assert_eq!(decimaldelta(TimeDelta::from_days(1000), Unit::Years, 1), "2.7 years");
+/// assert_eq!(decimaldelta(TimeDelta::from_hours(24), Unit::Days, 2), "1 day");
+/// ```
+pub fn decimaldelta(value: TimeDelta, unit: Unit, precision: usize) -> String {
+    let precision = precision.min(8);
+    let scale = 10_i128.pow(precision as u32);
+    let micros = value.total_microseconds().abs();
+    let scaled = Rounding::HalfUp.divide(micros * scale, unit_micros(unit));
+    let (whole, fraction) = (scaled / scale, scaled % scale);
+
+    let mut number = whole.to_string();
+    let fraction = format!("{:0width$}", fraction, width = precision);
+    let fraction = fraction.trim_end_matches('0');
+    if !fraction.is_empty() {
+        number.push_str(&i18n::decimal_separator());
+        number.push_str(fraction);
+    }
+    let &(_, singular, plural) = UNIT_PLURALS
+        .iter()
+        .find(|entry| entry.0 == unit)
+        .expect("every unit has plural forms");
+    let n = if scaled == scale { 1 } else { 2 };
+    let text = i18n::ngettext(singular, plural, n).replace("%d", &number);
+    finish_duration(text)
+}
+
+/// Describe an availability as the downtime it allows, like "99.95% — about
+/// 4 hours and 23 minutes of downtime per year".
+///
+/// `availability` is a fraction between 0 and 1, as for [`naturaldowntime`],
+/// which gives the downtime part.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{natural_uptime, Unit};
+/// assert_eq!(
+///     natural_uptime(0.9995, Unit::Years).unwrap(),
+///     "99.95% — about 4 hours and 23 minutes of downtime per year"
+/// );
+/// assert_eq!(
+///     natural_uptime(0.99, Unit::Weeks).unwrap(),
+///     "99% — about 1 hour and 41 minutes of downtime per week"
+/// );
+/// assert_eq!(
+///     natural_uptime(1.0, Unit::Months).unwrap(),
+///     "100% — no downtime"
+/// );
+/// ```
+pub fn natural_uptime(availability: f64, period: Unit) -> Result<String, TimeError> {
+    let downtime = downtime_per(availability, period)?;
+    let percent = format!("{:.4}", availability * 100.0);
+    let percent = percent.trim_end_matches('0').trim_end_matches('.');
+    let percent = format!("{}%", percent.replace('.', &i18n::decimal_separator()));
+    Ok(match downtime {
+        Some(downtime) => i18n::gettext("%s — about %s")
+            .replacen("%s", &percent, 1)
+            .replacen("%s", &downtime, 1),
+        None => i18n::gettext("%s — no downtime").replace("%s", &percent),
+    })
+}
+
+/// Describe the downtime an availability allows in one `period`: "4 hours
+/// and 23 minutes of downtime per year".
+///
+/// `availability` is a fraction between 0 and 1; anything else is
+/// [`TimeError::AvailabilityOutOfRange`]. A month is 30.5 days and a year
+/// 365 days, as in [`naturaldelta`], and the downtime is rounded to the
+/// minute, or to the second when under a minute.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{naturaldowntime, Unit};
+/// assert_eq!(
+///     naturaldowntime(0.9995, Unit::Years).unwrap(),
+///     "4 hours and 23 minutes of downtime per year"
+/// );
+/// assert_eq!(
+///     naturaldowntime(0.9999, Unit::Days).unwrap(),
+///     "9 seconds of downtime per day"
+/// );
+/// assert_eq!(naturaldowntime(0.99999, Unit::Hours).unwrap(), "no downtime");
+/// ```
+pub fn naturaldowntime(availability: f64, period: Unit) -> Result<String, TimeError> {
+    Ok(downtime_per(availability, period)?.unwrap_or_else(|| i18n::gettext("no downtime")))
+}
+
+/// The "… of downtime per …" text for [`naturaldowntime`], or `None` when
+/// the downtime rounds away.
+fn downtime_per(availability: f64, period: Unit) -> Result<Option<String>, TimeError> {
+    let seconds = unit_micros(period) as f64 / 1e6;
+    let Some(downtime) = allowed_downtime(availability, seconds)? else {
+        return Ok(None);
+    };
+    let template = match period {
+        Unit::Microseconds => i18n::gettext("%s of downtime per microsecond"),
+        Unit::Milliseconds => i18n::gettext("%s of downtime per millisecond"),
+        Unit::Seconds => i18n::gettext("%s of downtime per second"),
+        Unit::Minutes => i18n::gettext("%s of downtime per minute"),
+        Unit::Hours => i18n::gettext("%s of downtime per hour"),
+        Unit::Days => i18n::gettext("%s of downtime per day"),
+        Unit::Weeks => i18n::gettext("%s of downtime per week"),
+        Unit::Months => i18n::gettext("%s of downtime per month"),
+        Unit::Years => i18n::gettext("%s of downtime per year"),
+    };
+    Ok(Some(template.replace("%s", &downtime)))
+}
+
+/// The downtime `availability` allows over `period` seconds, rounded to the
+/// minute, or to the second when under a minute; `None` if it rounds away.
+fn allowed_downtime(availability: f64, period: f64) -> Result<Option<String>, TimeError> {
+    if !(0.0..=1.0).contains(&availability) {
+        return Err(TimeError::AvailabilityOutOfRange);
+    }
+    let downtime = (1.0 - availability) * period;
+    if downtime < 0.5 {
+        return Ok(None);
+    }
+    let minimum_unit = if downtime < 60.0 {
+        Unit::Seconds
+    } else {
+        Unit::Minutes
+    };
+    Ok(Some(precisedelta_styled(
+        TimeDelta::from_seconds(downtime),
+        minimum_unit,
+        &HashSet::new(),
+        "%0.0f",
+        PrecisedeltaOptions::default(),
+    )))
+}
+
+/// Format a duration like a stopwatch: "5:30", "1:02:03".
+///
+/// The duration is rounded to the nearest second. Minutes and seconds are
+/// always shown; hours only when there are any, and they are not wrapped
+/// into days.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{stopwatch, TimeDelta};
+/// assert_eq!(stopwatch(TimeDelta::from_seconds(330.0)), "5:30");
+/// assert_eq!(stopwatch(TimeDelta::from_seconds(3723.4)), "1:02:03");
+/// assert_eq!(stopwatch(TimeDelta::from_seconds(-5.0)), "-0:05");
+/// ```
+pub fn stopwatch(value: TimeDelta) -> String {
+    clockdelta(value, false, false)
+}
+
+/// Format a duration like a media player's clock: "0:45", "1:02:03",
+/// "26:10:09".
+///
+/// Hours are not wrapped into days. With `always_hours` they are shown even
+/// when zero ("0:00:45"), which keeps a column of times aligned. With
+/// `milliseconds` the duration is rounded to the nearest millisecond and
+/// shown with three decimals ("1:02:03.450"); otherwise to the nearest
+/// second.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{clockdelta, TimeDelta};
+/// assert_eq!(clockdelta(TimeDelta::from_seconds(45.0), false, false), "0:45");
+/// assert_eq!(clockdelta(TimeDelta::from_seconds(45.0), true, false), "0:00:45");
+/// assert_eq!(clockdelta(TimeDelta::from_seconds(3723.45), false, true), "1:02:03.450");
+/// assert_eq!(clockdelta(TimeDelta::from_seconds(94_209.0), false, false), "26:10:09");
+/// ```
+pub fn clockdelta(value: TimeDelta, always_hours: bool, milliseconds: bool) -> String {
+    let step = if milliseconds { 1_000 } else { 1_000_000 };
+    let total_us = value.total_microseconds();
+    let rounded = (total_us.unsigned_abs() + step / 2) / step * step;
+    let sign = if total_us < 0 && rounded > 0 { "-" } else { "" };
+
+    let seconds = rounded / 1_000_000;
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    let mut clock = if hours > 0 || always_hours {
+        format!("{}{}:{:02}:{:02}", sign, hours, minutes, seconds)
+    } else {
+        format!("{}{}:{:02}", sign, minutes, seconds)
+    };
+    if milliseconds {
+        clock.push_str(&format!(".{:03}", rounded % 1_000_000 / 1_000));
+    }
+    clock
+}
+
+/// Format an offset from UTC, in seconds east, as "UTC+05:30" or "UTC-08:00".
+/// Seconds are only shown when there are any, and a zero offset is "UTC".
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::natural_utc_offset;
+/// assert_eq!(natural_utc_offset(19_800), "UTC+05:30");
+/// assert_eq!(natural_utc_offset(-8 * 3600), "UTC-08:00");
+/// assert_eq!(natural_utc_offset(0), "UTC");
+/// ```
+pub fn natural_utc_offset(offset: i32) -> String {
+    if offset == 0 {
+        return i18n::gettext("UTC");
+    }
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.unsigned_abs();
+    let (hours, minutes, seconds) = (offset / 3600, offset / 60 % 60, offset % 60);
+    let offset = if seconds == 0 {
+        format!("{}{:02}:{:02}", sign, hours, minutes)
+    } else {
+        format!("{}{:02}:{:02}:{:02}", sign, hours, minutes, seconds)
+    };
+    i18n::gettext("UTC%s").replace("%s", &offset)
+}
+
+/// Compare someone else's UTC offset with yours, both in seconds east: "5
+/// hours ahead of you", "30 minutes behind you", "same time as you".
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::natural_offset_difference;
+/// assert_eq!(natural_offset_difference(19_800, 0), "5 hours and 30 minutes ahead of you");
+/// assert_eq!(natural_offset_difference(-5 * 3600, 3600), "6 hours behind you");
+/// assert_eq!(natural_offset_difference(3600, 3600), "same time as you");
+/// ```
+pub fn natural_offset_difference(theirs: i32, yours: i32) -> String {
+    let difference = i64::from(theirs) - i64::from(yours);
+    if difference.abs() < 60 {
+        return i18n::gettext("same time as you");
+    }
+    let suppress = HashSet::from([Unit::Days, Unit::Weeks, Unit::Months, Unit::Years]);
+    let amount = precisedelta_styled(
+        TimeDelta::from_days_seconds_micros(0, difference.abs(), 0),
+        Unit::Minutes,
+        &suppress,
+        "%0.0f",
+        PrecisedeltaOptions::default(),
+    );
+    let template = if difference > 0 {
+        i18n::gettext("%s ahead of you")
+    } else {
+        i18n::gettext("%s behind you")
+    };
+    template.replace("%s", &amount)
+}
+
+/// When something started, displayed as how long ago that is at the moment
+/// of formatting: "started 2 minutes ago".
+///
+/// Handy in log lines and status bars, where the elapsed time would
+/// otherwise be worked out at every call site.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::HumanElapsed;
+/// let started = HumanElapsed::now();
+/// assert_eq!(started.to_string(), "started just now");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HumanElapsed(pub Instant);
+
+impl HumanElapsed {
+    /// Start counting from now.
+    pub fn now() -> Self {
+        HumanElapsed(Instant::now())
+    }
+
+    /// The time since the start.
+    pub fn elapsed(&self) -> TimeDelta {
+        self.0.elapsed().into()
+    }
+}
+
+impl From<Instant> for HumanElapsed {
+    fn from(value: Instant) -> Self {
+        HumanElapsed(value)
+    }
+}
+
+impl fmt::Display for HumanElapsed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&started_ago(self.elapsed()))
+    }
+}
+
+fn started_ago(elapsed: TimeDelta) -> String {
+    let text = if elapsed < TimeDelta::from_seconds(1.0) {
+        i18n::gettext("started just now")
+    } else {
+        let ago = naturaldelta_styled(elapsed, true, Unit::Seconds);
+        i18n::gettext("started %s ago").replace("%s", &ago)
+    };
+    emoji::decorate(|table| &table.duration, text)
+}
+
+/// Units of [`compactdelta`] as (unit, length in microseconds, abbreviation),
+/// largest first. Months have no unambiguous abbreviation, so they are left
+/// out.
+const COMPACT_UNITS: &[(Unit, u128, &str)] = &[
+    (Unit::Years, 365 * 86_400_000_000, "y"),
+    (Unit::Weeks, 7 * 86_400_000_000, "w"),
+    (Unit::Days, 86_400_000_000, "d"),
+    (Unit::Hours, 3_600_000_000, "h"),
+    (Unit::Minutes, 60_000_000, "m"),
+    (Unit::Seconds, 1_000_000, "s"),
+    (Unit::Milliseconds, 1_000, "ms"),
+    (Unit::Microseconds, 1, "\u{b5}s"),
+];
+
+/// Return an abbreviated representation of a timedelta, such as "2d 1h 33s",
+/// for places with no room for [`precisedelta`].
+///
+/// The duration is rounded to the nearest `minimum_unit`; a minimum unit of
+/// months rounds to weeks. Units with a zero count are skipped. Components
+/// are joined with `separator`, and `unit_separator` goes between each number
+/// and its abbreviation. The abbreviations are translated with the context
+/// "duration abbreviation".
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{compactdelta, TimeDelta, Unit};
+/// let delta = TimeDelta::from_days_seconds_micros(2, 3633, 123000);
+/// assert_eq!(compactdelta(delta, Unit::Seconds, " ", ""), "2d 1h 33s");
+/// assert_eq!(compactdelta(delta, Unit::Milliseconds, ", ", " "), "2 d, 1 h, 33 s, 123 ms");
+/// assert_eq!(compactdelta(TimeDelta::from_seconds(-90.0), Unit::Minutes, " ", ""), "-2m");
+/// ```
+pub fn compactdelta(
+    value: TimeDelta,
+    minimum_unit: Unit,
+    separator: &str,
+    unit_separator: &str,
+) -> String {
+    let last = COMPACT_UNITS
+        .iter()
+        .position(|(unit, _, _)| *unit <= minimum_unit)
+        .unwrap_or(COMPACT_UNITS.len() - 1);
+    let (smallest_unit, step, _) = COMPACT_UNITS[last];
+
+    let total_us = value.total_microseconds();
+    let mut rest = (total_us.unsigned_abs() + step / 2) / step * step;
+    let sign = if total_us < 0 && rest > 0 { "-" } else { "" };
+
+    let mut parts = Vec::new();
+    for (unit, length, abbreviation) in &COMPACT_UNITS[..=last] {
+        let count = rest / length;
+        rest %= length;
+        if count > 0 || (*unit == smallest_unit && parts.is_empty()) {
+            let abbreviation = i18n::pgettext("duration abbreviation", abbreviation);
+            parts.push(format!("{}{}{}", count, unit_separator, abbreviation));
+        }
+    }
+    format!("{}{}", sign, parts.join(separator))
+}
+
+/// Format a delta in at most `max_width` characters, for fixed-width
+/// columns: "2 days and 1 hour", then "2d 1h" and "2d" as the room runs
+/// out.
+///
+/// Each step rounds what it leaves out into the last unit shown. When even
+/// the shortest form is too wide it is returned anyway, so the column
+/// overflows rather than cutting a word in half. The sign of `value` is
+/// ignored.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{fitdelta, TimeDelta};
+/// let delta = TimeDelta::from_seconds(2.0 * 86_400.0 + 3600.0 + 20.0);
+/// assert_eq!(fitdelta(delta, 20), "2 days and 1 hour");
+/// assert_eq!(fitdelta(delta, 8), "2d 1h");
+/// assert_eq!(fitdelta(delta, 3), "2d");
+/// ```
+pub fn fitdelta(value: TimeDelta, max_width: usize) -> String {
+    let value = value.abs();
+    let precise = precisedelta_styled(
+        value,
+        Unit::Seconds,
+        &HashSet::new(),
+        "%0.0f",
+        PrecisedeltaOptions {
+            max_components: Some(2),
+            ..Default::default()
+        },
+    );
+    let no_months = HashSet::from([Unit::Months]);
+    let compact = |max| {
+        let unit = last_component(value, Unit::Seconds, &no_months, max);
+        compactdelta(value, unit, " ", "")
+    };
+    let candidates = [precise, compact(2), compact(1)];
+    let fits = |text: &&String| text.chars().count() <= max_width;
+    let [.., shortest] = &candidates;
+    candidates.iter().find(fits).unwrap_or(shortest).clone()
+}
+
+/// Units of [`naturalduration_short`] as (length in nanoseconds,
+/// abbreviation), smallest first.
+const SHORT_UNITS: &[(u128, &str)] = &[
+    (1, "ns"),
+    (1_000, "\u{b5}s"),
+    (1_000_000, "ms"),
+    (1_000_000_000, "s"),
+];
+
+/// Format a short duration in the one unit that suits it best, the way
+/// [`metric`](crate::number::metric) does for numbers: "234 µs", "1.5 ms",
+/// "12 ns".
+///
+/// The amount keeps three significant digits, without trailing zeros.
+/// Durations of a minute or more are given by [`compactdelta`], to the
+/// second. Anything that converts into a `std::time::Duration` can be
+/// passed, including a [`TimeDelta`], whose sign is dropped.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::{naturalduration_short, TimeDelta};
+/// use std::time::Duration;
+/// assert_eq!(naturalduration_short(Duration::from_micros(234)), "234 µs");
+/// assert_eq!(naturalduration_short(Duration::from_nanos(800_000)), "800 µs");
+/// assert_eq!(naturalduration_short(Duration::from_nanos(12)), "12 ns");
+/// assert_eq!(naturalduration_short(TimeDelta::from_seconds(1.23456)), "1.23 s");
+/// assert_eq!(naturalduration_short(Duration::from_secs(75)), "1m 15s");
+/// ```
+pub fn naturalduration_short<D: Into<std::time::Duration>>(value: D) -> String {
+    let value = value.into();
+    if value.as_secs() >= 60 {
+        return compactdelta(value.into(), Unit::Seconds, " ", "");
+    }
+    let nanos = value.as_nanos();
+    let mut index = SHORT_UNITS
+        .iter()
+        .rposition(|(length, _)| nanos >= *length)
+        .unwrap_or(0);
+    let amount = loop {
+        let amount = nanos as f64 / SHORT_UNITS[index].0 as f64;
+        let decimals = if amount < 10.0 {
+            2
+        } else if amount < 100.0 {
+            1
+        } else {
+            0
+        };
+        let amount = format!("{:.*}", decimals, amount);
+        // 999.7 µs rounds up to 1 ms
+        if amount.parse::<f64>().is_ok_and(|n| n >= 1000.0) && index + 1 < SHORT_UNITS.len() {
+            index += 1;
+        } else {
+            break amount;
+        }
+    };
+    let amount = if amount.contains('.') {
+        amount.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &amount
+    };
+    let abbreviation = i18n::pgettext("duration abbreviation", SHORT_UNITS[index].1);
+    format!(
+        "{} {}",
+        amount.replace('.', &i18n::decimal_separator()),
+        abbreviation
+    )
+}
+
+/// Parse the output of [`naturaldelta`] or [`precisedelta`] back into a
+/// [`TimeDelta`].
+///
+/// Understands "a moment", "a minute", "2 hours", "1,000 years" and
+/// comma/"and"-separated combinations like "1 day, 2 hours and 3.50 seconds".
+/// A month is 30.5 days and a year 365 days, as in the formatters, and
+/// fractional amounts are rounded to the nearest microsecond. Only the English
+/// forms are understood.
+///
+/// This is [`crate::parse::delta`] in lenient mode.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::parse_delta;
+/// assert_eq!(parse_delta("2 hours").unwrap().seconds, 7200);
+/// assert_eq!(parse_delta("a year, 2 days").unwrap().days, 367);
+/// assert_eq!(parse_delta("1 minute and 30.5 seconds").unwrap().microseconds, 500_000);
+/// assert!(parse_delta("2 fortnights").is_err());
+/// ```
+pub fn parse_delta(value: &str) -> Result<TimeDelta, ParseError> {
+    crate::parse::delta(value, Mode::Lenient)
+}
+
+/// Parse the output of [`naturaltime_delta`] back into a [`TimeDelta`].
+///
+/// "X ago" gives a positive delta and "X from now" a negative one, matching
+/// the sign of the timedelta `naturaltime` formats; "now" is zero. Text
+/// without a tense is parsed like [`parse_delta`].
+///
+/// This is [`crate::parse::naturaltime`] in lenient mode.
+///
+/// # Examples
+/// ```
+/// use speakhuman::time::parse_naturaltime;
+/// assert_eq!(parse_naturaltime("30 seconds ago").unwrap().seconds, 30);
+/// let future = parse_naturaltime("an hour from now").unwrap();
+/// assert_eq!(future.total_seconds(), -3600.0);
+/// assert_eq!(parse_naturaltime("now").unwrap().total_seconds(), 0.0);
+/// ```
+pub fn parse_naturaltime(value: &str) -> Result<TimeDelta, ParseError> {
+    crate::parse::naturaltime(value, Mode::Lenient)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseErrorKind;
+
+    #[test]
+    fn test_unit_ordering() {
+        assert!(Unit::Seconds < Unit::Minutes);
+        assert!(Unit::Minutes < Unit::Hours);
+        assert!(Unit::Years > Unit::Days);
+        assert!(Unit::Days < Unit::Weeks && Unit::Weeks < Unit::Months);
+        let mut units = Unit::all().to_vec();
+        units.reverse();
+        units.sort();
+        assert_eq!(units, Unit::all());
+        for (rank, unit) in Unit::all().iter().enumerate() {
+            assert_eq!(usize::from(unit.rank()), rank);
+        }
+        // The discriminants from before weeks are unchanged
+        assert_eq!(Unit::Months as i32, 6);
+        assert_eq!(Unit::Years as i32, 7);
+        assert_eq!(Unit::Weeks as i32, 8);
+    }
+
+    #[test]
+    fn test_unit_from_str() {
+        for unit in Unit::all() {
+            assert_eq!(unit.name().parse(), Ok(*unit));
+            assert_eq!(unit.name().to_uppercase().parse(), Ok(*unit));
+        }
+        for (alias, unit) in [
+            ("us", Unit::Microseconds),
+            ("µs", Unit::Microseconds),
+            ("MS", Unit::Milliseconds),
+            ("s", Unit::Seconds),
+            ("Min", Unit::Minutes),
+            ("h", Unit::Hours),
+            ("week", Unit::Weeks),
+        ] {
+            assert_eq!(alias.parse(), Ok(unit), "{}", alias);
+        }
+        // "m" could be minutes or months
+        let err = "m".parse::<Unit>().unwrap_err();
+        assert_eq!(err.input(), "m");
+        assert_eq!(err.to_string(), "Unknown unit: m");
+        assert_eq!(naturaldelta(60.0, true, "ms"), "a minute");
+    }
+
+    #[test]
+    fn test_timedelta_from_seconds() {
+        let td = TimeDelta::from_seconds(90.0);
+        assert_eq!(td.days, 0);
+        assert_eq!(td.seconds, 90);
+        assert_eq!(td.microseconds, 0);
+    }
+
+    #[test]
+    fn test_timedelta_to_iso8601() {
+        assert_eq!(TimeDelta::from_seconds(0.0).to_iso8601(), "PT0S");
+        assert_eq!(TimeDelta::from_seconds(90.0).to_iso8601(), "PT1M30S");
+        assert_eq!(TimeDelta::from_seconds(0.0005).to_iso8601(), "PT0.0005S");
+        assert_eq!(
+            TimeDelta::from_days_seconds_micros(3, 0, 0).to_iso8601(),
+            "P3D"
+        );
+        assert_eq!(
+            TimeDelta::from_days_seconds_micros(1, 7205, 0).to_iso8601(),
+            "P1DT2H0M5S"
+        );
+        // Negative durations are normalized like Python's timedelta
+        assert_eq!(TimeDelta::from_seconds(-1.5).to_iso8601(), "-PT1.5S");
+    }
+
+    #[test]
+    fn test_timedelta_arithmetic() {
+        let a = TimeDelta::from_days_seconds_micros(1, 86_399, 999_999);
+        let b = TimeDelta::from_days_seconds_micros(0, 0, 1);
+        let sum = a + b;
+        assert_eq!((sum.days, sum.seconds, sum.microseconds), (2, 0, 0));
+        let diff = b - a;
+        assert_eq!((diff.days, diff.seconds, diff.microseconds), (-2, 0, 2));
+        assert_eq!(-diff, a - b);
+        assert_eq!(b * 3_000_000, TimeDelta::from_seconds(3.0));
+        assert_eq!(a * -1, -a);
+        assert_eq!(diff.abs(), -diff);
+    }
+
+    #[test]
+    fn test_timedelta_unit_constructors() {
+        assert_eq!(TimeDelta::from_weeks(2), TimeDelta::from_days(14));
+        assert_eq!(TimeDelta::from_hours(-1), TimeDelta::from_seconds(-3600.0));
+        assert_eq!(TimeDelta::from_minutes(90).to_iso8601(), "PT1H30M");
+        assert_eq!(TimeDelta::from_millis(1500), TimeDelta::from_seconds(1.5));
+        let td = TimeDelta::from_micros(-1);
+        assert_eq!(
+            (td.days, td.seconds, td.microseconds),
+            (-1, 86_399, 999_999)
+        );
+        assert_eq!(
+            precisedelta_td(
+                TimeDelta::from_weeks(1) + TimeDelta::from_millis(5),
+                "milliseconds",
+                &[],
+                "%0.0f"
+            ),
+            "7 days and 5 milliseconds"
+        );
+    }
+
+    #[test]
+    fn test_timedelta_builder() {
+        let td = TimeDelta::builder()
+            .weeks(1)
+            .days(-1)
+            .hours(2)
+            .minutes(3)
+            .seconds(4)
+            .millis(5)
+            .micros(6)
+            .build()
+            .unwrap();
+        assert_eq!(td, TimeDelta::from_days_seconds_micros(6, 7384, 5006));
+        assert_eq!(TimeDelta::builder().build(), Ok(TimeDelta::from_micros(0)));
+        assert_eq!(
+            TimeDelta::builder().hours(1).hours(2).build(),
+            Ok(TimeDelta::from_hours(3))
+        );
+        assert_eq!(
+            TimeDelta::builder().days(i64::MAX).hours(24).build(),
+            Err(TimeError::OutOfRange)
+        );
+        assert_eq!(
+            TimeDelta::builder()
+                .days(i64::MAX)
+                .hours(24)
+                .hours(-1)
+                .build(),
+            Ok(TimeDelta::from_days_seconds_micros(i64::MAX, 82_800, 0))
+        );
+    }
+
+    #[test]
+    fn test_timedelta_ordering() {
+        let minute = TimeDelta::from_seconds(60.0);
+        assert!(TimeDelta::from_seconds(-1.0) < TimeDelta::from_seconds(0.0));
+        assert!(minute * 60 > TimeDelta::from_seconds(3599.999999));
+        // Unnormalized fields compare by length
+        let unnormalized = TimeDelta {
+            days: 0,
+            seconds: 120,
+            microseconds: -60_000_000,
+        };
+        assert_eq!(unnormalized, minute);
+        let mut deltas = vec![minute, -minute, minute * 2];
+        deltas.sort();
+        assert_eq!(deltas, [-minute, minute, minute * 2]);
+    }
+
+    #[test]
+    fn test_timedelta_conversions() {
+        let std = std::time::Duration::new(90_061, 500_000_999);
+        assert_eq!(TimeDelta::from(std).to_iso8601(), "P1DT1H1M1.5S");
+        assert_eq!(
+            std::time::Duration::from(TimeDelta::from(std)).as_nanos(),
+            90_061_500_000_000
+        );
+
+        #[cfg(feature = "chrono")]
+        {
+            let chrono = chrono::Duration::milliseconds(-1500);
+            let td = TimeDelta::from(chrono);
+            assert_eq!(
+                (td.days, td.seconds, td.microseconds),
+                (-1, 86_398, 500_000)
+            );
+            assert_eq!(std::time::Duration::from(td).as_millis(), 1500);
+            assert_eq!(
+                naturaldelta_td(chrono::Duration::hours(3).into(), true, "seconds"),
+                "3 hours"
+            );
+        }
+    }
+
+    #[test]
+    fn test_naturaldelta_basic() {
+        let td = TimeDelta::from_days_seconds_micros(7, 0, 0);
+        assert_eq!(naturaldelta_td(td, true, "seconds"), "7 days");
+    }
+
+    #[test]
+    fn test_naturaldelta_weeks() {
+        let days = |d| TimeDelta::from_days_seconds_micros(d, 0, 0);
+        assert_eq!(naturaldelta_td(days(17), true, "weeks"), "2 weeks");
+        assert_eq!(
+            try_naturaldelta_td(days(17), true, "weeks"),
+            Ok("2 weeks".to_string())
+        );
+        assert_eq!(naturaldelta_td(days(9), true, "weeks"), "a week");
+        assert_eq!(naturaldelta_td(days(30), true, "weeks"), "4 weeks");
+        assert_eq!(naturaldelta_td(days(31), true, "weeks"), "a month");
+        assert_eq!(naturaldelta_td(days(100), false, "weeks"), "14 weeks");
+        assert_eq!(naturaldelta_td(days(400), true, "weeks"), "1 year, 1 month");
+        // Under a week, the days and hours are still given
+        let td = TimeDelta::from_seconds(3600.0);
+        assert_eq!(naturaldelta_td(td, true, "weeks"), "an hour");
+        assert_eq!(naturaldelta_td(days(6), true, "weeks"), "6 days");
+        assert_eq!(naturaldelta_td(days(7), true, "weeks"), "a week");
+        // Without weeks, 17 days still rounds to a month
+        assert_eq!(naturaldelta_td(days(17), true, "seconds"), "a month");
+    }
+
+    #[test]
+    fn test_naturaldelta_moment() {
+        let td = TimeDelta::from_seconds(0.0);
+        assert_eq!(naturaldelta_td(td, true, "seconds"), "a moment");
+    }
+
+    #[test]
+    fn test_naturaldelta_seconds() {
+        let td = TimeDelta::from_seconds(1.0);
+        assert_eq!(naturaldelta_td(td, true, "seconds"), "a second");
+
+        let td = TimeDelta::from_seconds(30.0);
+        assert_eq!(naturaldelta_td(td, true, "seconds"), "30 seconds");
+    }
+
+    #[test]
+    fn test_naturaldelta_minutes() {
+        let td = TimeDelta::from_seconds(120.0);
+        assert_eq!(naturaldelta_td(td, true, "seconds"), "2 minutes");
+    }
+
+    #[test]
+    fn test_naturaldelta_hours() {
+        let td = TimeDelta::from_days_seconds_micros(0, 3600, 0);
+        assert_eq!(naturaldelta_td(td, true, "seconds"), "an hour");
+    }
+
+    #[test]
+    fn test_naturaldelta_years() {
+        let td = TimeDelta::from_days_seconds_micros(365 * 2 + 35, 0, 0);
+        assert_eq!(naturaldelta_td(td, true, "seconds"), "2 years");
+    }
+
+    #[test]
+    fn test_naturaldelta_large_years() {
+        let td = TimeDelta::from_days_seconds_micros(365 * 1141, 0, 0);
+        assert_eq!(naturaldelta_td(td, true, "seconds"), "1,141 years");
+    }
+
+    #[test]
+    fn test_naturaldelta_fuzzy() {
+        let fuzzy = |seconds: f64| naturaldelta_fuzzy(TimeDelta::from_seconds(seconds));
+        assert_eq!(fuzzy(0.5), "a moment");
+        assert_eq!(fuzzy(-1.0), "a second");
+        assert_eq!(fuzzy(100.0), "over a minute");
+        assert_eq!(fuzzy(59.0 * 60.0), "59 minutes");
+        assert_eq!(fuzzy(3599.0), "almost an hour");
+        assert_eq!(fuzzy(3.0 * 86_400.0 + 3600.0), "about 3 days");
+        assert_eq!(fuzzy(6.8 * 86_400.0), "almost a week");
+        assert_eq!(fuzzy(360.0 * 86_400.0), "almost a year");
+        assert_eq!(fuzzy(365.0 * 86_400.0 * 1500.2), "about 1,500 years");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_naturaldelta_between() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let at = |y, m, d| date(y, m, d).and_hms_opt(0, 0, 0).unwrap();
+        let noon = date(2024, 1, 1).and_hms_opt(12, 0, 0).unwrap();
+        // A leap year is 366 days
+        let leap_year = TimeDelta::from_days(366);
+        assert_eq!(naturaldelta_td(leap_year, true, "seconds"), "1 year, 1 day");
+        for (start, end, expected) in [
+            (at(2023, 3, 1), at(2024, 3, 1), "a year"),
+            (at(2024, 1, 1), at(2024, 12, 31), "a year"),
+            (at(2024, 1, 1), at(2024, 12, 15), "11 months"),
+            (at(2025, 1, 1), at(2024, 2, 1), "11 months"),
+            (at(2024, 2, 29), at(2025, 2, 28), "a year"),
+            (at(2024, 1, 1), at(2025, 1, 10), "1 year, 9 days"),
+            (at(2024, 1, 1), at(2025, 3, 20), "1 year, 3 months"),
+            (at(2000, 6, 1), at(2024, 5, 31), "23 years"),
+            (noon, at(2024, 1, 2), "12 hours"),
+        ] {
+            assert_eq!(naturaldelta_between(start, end), expected, "{}", end);
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_naturaldelta_between_tz() {
+        let parse = |s| DateTime::<chrono::FixedOffset>::parse_from_rfc3339(s).unwrap();
+        // New York springs forward on 2024-03-10, a day of 23 hours
+        let start = parse("2024-03-10T00:00:00-05:00");
+        let end = parse("2024-03-11T00:00:00-04:00");
+        assert_eq!(naturaldelta(23.0 * 3600.0, false, "seconds"), "23 hours");
+        assert_eq!(naturaldelta_between_tz(start, end), "a day");
+        // Wall clock says two hours, but only one passed
+        let start = parse("2024-03-10T01:30:00-05:00");
+        let end = parse("2024-03-10T03:30:00-04:00");
+        assert_eq!(naturaldelta_between_tz(start, end), "an hour");
+        let start = parse("2024-03-01T00:00:00-05:00");
+        let end = parse("2024-04-01T00:00:00-04:00");
+        assert_eq!(naturaldelta_between_tz(end, start), "a month");
+    }
+
+    #[test]
+    fn test_naturaltime_past() {
+        let td = TimeDelta::from_seconds(30.0);
+        assert_eq!(
+            naturaltime_delta(td, false, true, "seconds"),
+            "30 seconds ago"
+        );
+    }
+
+    #[test]
+    fn test_naturaltime_future() {
+        let td = TimeDelta::from_seconds(30.0);
+        assert_eq!(
+            naturaltime_delta(td, true, true, "seconds"),
+            "30 seconds from now"
+        );
+    }
+
+    #[test]
+    fn test_naturaltime_intl_styles() {
+        let ago = |seconds: f64| {
+            naturaltime_delta(TimeDelta::from_seconds(seconds), false, true, "seconds")
+        };
+        let from_now = |seconds: f64| {
+            naturaltime_delta(TimeDelta::from_seconds(seconds), true, true, "seconds")
+        };
+        set_relative_time_style(RelativeTimeStyle::IntlLong);
+        assert_eq!(ago(0.5), "0 seconds ago");
+        assert_eq!(from_now(1.0), "in 1 second");
+        assert_eq!(ago(3590.0), "1 hour ago");
+        assert_eq!(from_now(86_400.0 * 3.0), "in 3 days");
+        assert_eq!(ago(86_400.0 * 70.0), "2 months ago");
+        assert_eq!(ago(86_400.0 * 500.0), "1 year ago");
+        assert_eq!(from_now(86_400.0 * 365.0 * 1500.0), "in 1,500 years");
+        set_relative_time_style(RelativeTimeStyle::IntlShort);
+        assert_eq!(ago(120.0), "2 min. ago");
+        assert_eq!(from_now(86_400.0), "in 1 day");
+        assert_eq!(ago(86_400.0 * 360.0), "1 yr. ago");
+        set_relative_time_style(RelativeTimeStyle::IntlNarrow);
+        assert_eq!(ago(30.0), "30s ago");
+        assert_eq!(from_now(86_400.0 * 61.0), "in 2mo");
+        set_relative_time_style(RelativeTimeStyle::Natural);
+        assert_eq!(ago(30.0), "30 seconds ago");
+    }
+
+    #[test]
+    fn test_naturaltime_td() {
+        let td = TimeDelta::from_seconds(-0.5);
+        assert_eq!(naturaltime_td(td, true, "seconds"), "now");
+        assert_eq!(
+            naturaltime_td(td, true, "milliseconds"),
+            "500 milliseconds from now"
+        );
+        let td = TimeDelta::from_days(-400);
+        assert_eq!(
+            naturaltime_td(td, true, "seconds"),
+            "1 year, 1 month from now"
+        );
+    }
+
+    #[test]
+    fn test_naturaltime_now() {
+        let td = TimeDelta::from_seconds(0.0);
+        assert_eq!(naturaltime_delta(td, false, true, "seconds"), "now");
+    }
+
+    #[test]
+    fn test_precisedelta_basic() {
+        let td = TimeDelta::from_days_seconds_micros(2, 3633, 123000);
+        assert_eq!(
+            precisedelta_td(td, "seconds", &[], "%0.2f"),
+            "2 days, 1 hour and 33.12 seconds"
+        );
+    }
+
+    #[test]
+    fn test_precisedelta_single_unit() {
+        let td = TimeDelta::from_seconds(1.0);
+        assert_eq!(precisedelta_td(td, "seconds", &[], "%0.2f"), "1 second");
+
+        let td = TimeDelta::from_seconds(60.0);
+        assert_eq!(precisedelta_td(td, "seconds", &[], "%0.2f"), "1 minute");
+
+        let td = TimeDelta::from_seconds(3600.0);
+        assert_eq!(precisedelta_td(td, "seconds", &[], "%0.2f"), "1 hour");
+    }
+
+    #[test]
+    fn test_precisedelta_suppress() {
+        let td = TimeDelta::from_days_seconds_micros(2, 3633, 123000);
+        assert_eq!(
+            precisedelta_td(td, "seconds", &["days"], "%0.2f"),
+            "49 hours and 33.12 seconds"
+        );
+    }
+
+    #[test]
+    fn test_precisedelta_weeks() {
+        let days = |d| TimeDelta::from_days_seconds_micros(d, 0, 0);
+        let weeks = PrecisedeltaOptions {
+            weeks: true,
+            ..Default::default()
+        };
+        // Weeks are only used when asked for
+        assert_eq!(
+            precisedelta_td(days(17), "seconds", &[], "%0.2f"),
+            "17 days"
+        );
+        assert_eq!(
+            precisedelta_with(days(17), "seconds", &[], "%0.2f", weeks),
+            "2 weeks and 3 days"
+        );
+        assert_eq!(
+            precisedelta_with(days(17), "seconds", &["weeks"], "%0.2f", weeks),
+            "17 days"
+        );
+        assert_eq!(
+            precisedelta_td(days(17), "weeks", &[], "%0.1f"),
+            "2.4 weeks"
+        );
+        assert_eq!(
+            precisedelta_with(days(45), "days", &[], "%0.2f", weeks),
+            "1 month and 2 weeks"
+        );
+        // Rounding up the hours carries into a new week
+        let td = TimeDelta::from_days_seconds_micros(6, 86_399, 0);
+        assert_eq!(
+            precisedelta_with(td, "hours", &[], "%0.0f", weeks),
+            "1 week"
+        );
+        assert_eq!(precisedelta_td(td, "hours", &[], "%0.0f"), "7 days");
+    }
+
+    #[test]
+    fn test_precisedelta_extreme_values() {
+        let td = TimeDelta::from_days_seconds_micros(999_999_999, 86_399, 999_999);
+        assert_eq!(
+            precisedelta_td(td, "microseconds", &[], "%0.2f"),
+            "2,739,726 years, 9 days, 23 hours, 59 minutes, 59 seconds, \
+             999 milliseconds and 999 microseconds"
+        );
+        // Far more years than an f64 counts exactly
+        let td = TimeDelta::from_days_seconds_micros(i64::MAX / 2, 0, 0);
+        assert_eq!(
+            precisedelta_td(td, "days", &[], "%0.2f"),
+            "12,634,756,214,869,555 years, 10 months and 23 days"
+        );
+        assert_eq!(
+            precisedelta_td(td, "years", &[], "%0.1f"),
+            "12634756214869555.9 years"
+        );
+        let td = TimeDelta::from_days_seconds_micros(0, 0, 999_999);
+        assert_eq!(
+            precisedelta_td(td, "milliseconds", &[], "%0.0f"),
+            "1 second"
+        );
+    }
+
+    #[test]
+    fn test_saturation() {
+        let max = TimeDelta::from_days_seconds_micros(i64::MAX, 86_400, 0);
+        assert_eq!(max, TimeDelta::MAX);
+        assert_eq!(TimeDelta::MIN.abs(), TimeDelta::MAX);
+        assert_eq!(-TimeDelta::MIN, TimeDelta::MAX);
+        assert_eq!(TimeDelta::MAX * -3, TimeDelta::MIN);
+        assert_eq!(TimeDelta::from_seconds(f64::NEG_INFINITY), TimeDelta::MIN);
+        assert_eq!(
+            TimeDelta::from_seconds(f64::NAN),
+            TimeDelta::from_seconds(0.0)
+        );
+        assert_eq!(
+            TimeDelta::try_from_weeks(i64::MAX),
+            Err(TimeError::OutOfRange)
+        );
+        assert_eq!(
+            std::time::Duration::from(TimeDelta::MIN),
+            std::time::Duration::MAX
+        );
+
+        let more = "more than 25,269,512,429,739,111 years";
+        assert_eq!(naturaldelta(1e300, true, "seconds"), more);
+        assert_eq!(
+            naturaltime_td(TimeDelta::MIN, true, "seconds"),
+            format!("{} from now", more)
+        );
+        assert_eq!(
+            precisedelta_td(TimeDelta::MIN, "seconds", &[], "%0.2f"),
+            more
+        );
+        // Just short of the limit is still given in full
+        let almost = TimeDelta::MAX - TimeDelta::from_micros(1);
+        assert_eq!(
+            naturaldelta_td(almost, true, "seconds"),
+            "25,269,512,429,739,111 years"
+        );
+    }
+
+    #[test]
+    fn test_precisedelta_max_components() {
+        let up_to = |max| PrecisedeltaOptions {
+            max_components: Some(max),
+            ..Default::default()
+        };
+        let td = TimeDelta::from_days_seconds_micros(400, 3 * 3600 + 5, 0);
+        assert_eq!(
+            precisedelta_with(td, "seconds", &[], "%0.0f", up_to(3)),
+            "1 year, 1 month and 4 days"
+        );
+        // Zero units in between still count
+        let td = TimeDelta::from_days_seconds_micros(2, 90, 0);
+        assert_eq!(
+            precisedelta_with(td, "seconds", &[], "%0.2f", up_to(2)),
+            "2 days and 0.03 hours"
+        );
+        assert_eq!(
+            precisedelta_with(td, "seconds", &["hours"], "%0.1f", up_to(2)),
+            "2 days and 1.5 minutes"
+        );
+        // The minimum unit still applies, and zero means one
+        assert_eq!(
+            precisedelta_with(td, "days", &[], "%0.0f", up_to(5)),
+            "2 days"
+        );
+        let td = TimeDelta::from_seconds(45.5);
+        assert_eq!(
+            precisedelta_with(td, "seconds", &[], "%0.1f", up_to(0)),
+            "45.5 seconds"
+        );
+    }
+
+    #[test]
+    fn test_unit_errors() {
+        let td = TimeDelta::from_seconds(90.0);
+        assert_eq!(
+            try_naturaldelta_td(td, true, "fortnights"),
+            Err(TimeError::UnknownUnit("fortnights".to_string()))
+        );
+        assert_eq!(
+            try_precisedelta_td(td, "months", &["months", "years"], "%0.0f"),
+            Err(TimeError::NoUnitLeft)
+        );
+        // The infallible versions format the error instead
+        assert_eq!(
+            naturaldelta_td(td, true, "days"),
+            "Minimum unit 'days' not supported"
+        );
+        assert_eq!(naturaltime_delta(td, false, true, "x"), "Unknown unit: x");
+        assert_eq!(
+            precisedelta_td(td, "years", &["years"], "%0.0f"),
+            TimeError::NoUnitLeft.to_string()
+        );
+    }
+
+    #[test]
+    fn test_rounding_by_fmt() {
+        assert!((rounding_by_fmt("%.2f", 1.011) - 1.01).abs() < 1e-9);
+        assert!((rounding_by_fmt("%.0f", 1.5) - 2.0).abs() < 1e-9);
+        assert_eq!(rounding_by_fmt("%d", 1.999999999999999) as i64, 1);
+    }
+
+    #[test]
+    fn test_microseconds() {
+        let td = TimeDelta::from_days_seconds_micros(0, 0, 1);
+        assert_eq!(
+            naturaldelta_td(td, true, "microseconds"),
+            "1 microsecond"
+        );
+
+        let td = TimeDelta::from_days_seconds_micros(0, 0, 4);
+        assert_eq!(
+            naturaldelta_td(td, true, "microseconds"),
+            "4 microseconds"
+        );
+    }
+
+    #[test]
+    fn test_milliseconds() {
+        let td = TimeDelta::from_days_seconds_micros(0, 0, 1000);
+        assert_eq!(
+            naturaldelta_td(td, true, "milliseconds"),
+            "1 millisecond"
+        );
+
+        let td = TimeDelta::from_days_seconds_micros(0, 0, 4000);
+        assert_eq!(
+            naturaldelta_td(td, true, "milliseconds"),
+            "4 milliseconds"
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_naturalday_relative_weekdays() {
+        // A Wednesday
+        let today = NaiveDate::from_ymd_opt(2024, 6, 12).unwrap();
+        let day = |offset: i64, relative| {
+            let value = today + chrono::Duration::days(offset);
+            naturalday_from(value, "%b %d", relative, today)
+        };
+        assert_eq!(day(-1, true), "yesterday");
+        assert_eq!(day(-5, true), "last Friday");
+        assert_eq!(day(-7, true), "last Wednesday");
+        assert_eq!(day(-8, true), "Jun 04");
+        assert_eq!(day(2, true), "next Friday");
+        assert_eq!(day(7, true), "next Wednesday");
+        assert_eq!(day(2, false), "Jun 14");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_calendar_date() {
+        let date = NaiveDate::from_ymd_opt(2024, 7, 3).unwrap();
+        assert_eq!(calendar_date(&date), date);
+        assert_eq!(calendar_date(&date.and_hms_opt(23, 59, 59).unwrap()), date);
+        let utc = Utc.with_ymd_and_hms(2024, 7, 3, 23, 30, 0).unwrap();
+        assert_eq!(calendar_date(&utc), date);
+        let tokyo = chrono::FixedOffset::east_opt(9 * 3600).unwrap();
+        assert_eq!(
+            calendar_date(&utc.with_timezone(&tokyo)),
+            date.succ_opt().unwrap()
+        );
+        assert_eq!(calendar_date(&NaiveDate::MIN), NaiveDate::MIN);
+        assert_eq!(calendar_date(&NaiveDate::MAX), NaiveDate::MAX);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_format_date() {
+        let date = NaiveDate::from_ymd_opt(2024, 7, 3).unwrap();
+        assert_eq!(
+            format_date(date, "%a %A %b %h %B %d"),
+            "Wed Wednesday Jul Jul July 03"
+        );
+        assert_eq!(format_date(date, "%%b %-d"), "%b 3");
+
+        if i18n::activate(Some("fr_FR"), None).is_err() {
+            // .mo files not generated
+            return;
+        }
+        let today = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        assert_eq!(naturaldate_from(date, today), "3 juil.");
+        assert_eq!(
+            naturaldate_from(date, today + Days::new(300)),
+            "3 juil. 2024"
+        );
+        assert_eq!(format_date(date, "%A %-d %B"), "mercredi 3 juillet");
+        i18n::deactivate();
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_naturaldate_relative() {
+        let today = NaiveDate::from_ymd_opt(2024, 6, 12).unwrap();
+        let date = |y, m, d| {
+            let value = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+            naturaldate_relative_from(value, today)
+        };
+        assert_eq!(date(2024, 6, 11), "yesterday");
+        assert_eq!(date(2024, 6, 7), "5 days ago");
+        assert_eq!(date(2024, 6, 5), "last week");
+        assert_eq!(date(2024, 7, 1), "in 2 weeks");
+        assert_eq!(date(2024, 7, 12), "next month");
+        assert_eq!(date(2024, 1, 1), "5 months ago");
+        assert_eq!(date(2023, 6, 13), "11 months ago");
+        assert_eq!(date(2023, 6, 12), "Jun 12 2023");
+    }
+
+    #[test]
+    fn test_epoch() {
+        let now = 1_718_000_000_000;
+        assert_eq!(naturaltime_epoch_at(now - 3_600_000, now), "an hour ago");
+        assert_eq!(naturaltime_epoch_at(now + 60_000, now), "a minute from now");
+        assert_eq!(naturaltime_epoch_at(now, now), "now");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_naturaldate_epoch() {
+        // Midday UTC, so the same date in any time zone
+        assert_eq!(naturaldate_epoch_millis(1_000_040_000_000), "Sep 09 2001");
+        assert_eq!(naturaldate_epoch(i64::MAX), i64::MAX.to_string());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_naturaldeadline() {
+        let now = Utc.with_ymd_and_hms(2024, 6, 12, 9, 0, 0).unwrap();
+        let due = |delta| naturaldeadline_at(now + chrono::Duration::minutes(delta), now);
+        assert_eq!(due(0), "due in a moment");
+        assert_eq!(due(150), "due in 2 hours");
+        // Tomorrow morning is still less than a day away
+        assert_eq!(due(20 * 60), "due in 20 hours");
+        assert_eq!(due(30 * 60), "due tomorrow");
+        assert_eq!(due(3 * 24 * 60), "due in 3 days");
+        assert_eq!(due(-120), "overdue by 2 hours");
+        assert_eq!(due(-45 * 24 * 60), "overdue by a month");
 
-    if msecs >= 1000.0 && !suppress_set.contains(&Unit::Seconds) {
-        msecs -= 1000.0;
-        secs += 1.0;
-    }
-    if secs >= 60.0 && !suppress_set.contains(&Unit::Minutes) {
-        secs -= 60.0;
-        minutes += 1.0;
+        // Tomorrow evening in New York is already two days on in UTC
+        let parse = |s| DateTime::<chrono::FixedOffset>::parse_from_rfc3339(s).unwrap();
+        let now = parse("2024-06-12T08:00:00-05:00");
+        let due = parse("2024-06-13T21:00:00-05:00");
+        assert_eq!(naturaldeadline_at(due, now), "due tomorrow");
+        let utc = |t: DateTime<chrono::FixedOffset>| t.with_timezone(&Utc);
+        assert_eq!(naturaldeadline_at(utc(due), utc(now)), "due in a day");
     }
-    if minutes >= 60.0 && !suppress_set.contains(&Unit::Hours) {
-        minutes -= 60.0;
-        hours += 1.0;
+
+    #[test]
+    fn test_precisedelta_zero_units() {
+        let zeros = PrecisedeltaOptions {
+            include_zero_units: true,
+            ..Default::default()
+        };
+        let td = TimeDelta::from_days_seconds_micros(370, 5, 0);
+        assert_eq!(
+            precisedelta_with(td, "seconds", &[], "%0.0f", zeros),
+            "1 year, 0 months, 5 days, 0 hours, 0 minutes and 5 seconds"
+        );
+        assert_eq!(
+            precisedelta_with(td, "seconds", &["weeks", "hours"], "%0.0f", zeros),
+            "1 year, 0 months, 5 days, 0 minutes and 5 seconds"
+        );
+        // Nothing is added after the last non-zero unit
+        let td = TimeDelta::from_hours(2);
+        assert_eq!(
+            precisedelta_with(td, "seconds", &[], "%0.0f", zeros),
+            "2 hours"
+        );
     }
-    if hours >= 24.0 && !suppress_set.contains(&Unit::Days) {
-        hours -= 24.0;
-        days += 1.0;
+
+    #[test]
+    fn test_precisedelta_oxford_comma() {
+        let oxford = PrecisedeltaOptions {
+            oxford_comma: true,
+            ..Default::default()
+        };
+        let td = TimeDelta::from_seconds(3723.0);
+        assert_eq!(
+            precisedelta_with(td, "seconds", &[], "%0.0f", oxford),
+            "1 hour, 2 minutes, and 3 seconds"
+        );
+        let td = TimeDelta::from_seconds(63.0);
+        assert_eq!(
+            precisedelta_with(td, "seconds", &[], "%0.0f", oxford),
+            "1 minute and 3 seconds"
+        );
     }
-    if days >= 31.0 && !suppress_set.contains(&Unit::Months) {
-        days -= 31.0;
-        months += 1.0;
+
+    #[test]
+    fn test_naturaldelta_rounded() {
+        use Rounding::*;
+        let rounded = |seconds: f64, rounding| {
+            naturaldelta_rounded(TimeDelta::from_seconds(seconds), true, "seconds", rounding)
+                .unwrap()
+        };
+        assert_eq!(rounded(0.4, Floor), "a moment");
+        assert_eq!(rounded(0.4, Ceil), "a second");
+        assert_eq!(rounded(59.5, HalfUp), "a minute");
+        assert_eq!(rounded(59.5, Floor), "59 seconds");
+        assert_eq!(rounded(150.0, HalfUp), "3 minutes");
+        assert_eq!(rounded(150.0, HalfEven), "2 minutes");
+        assert_eq!(rounded(3599.0, HalfEven), "an hour");
+        assert_eq!(rounded(23.5 * 3600.0, Floor), "23 hours");
+        assert_eq!(rounded(23.5 * 3600.0, HalfUp), "a day");
+        assert_eq!(rounded(45.0 * 86_400.0, Floor), "a month");
+        assert_eq!(rounded(45.75 * 86_400.0, HalfEven), "2 months");
+        assert_eq!(rounded(20.0 * 86_400.0, Floor), "20 days");
+        assert_eq!(rounded(20.0 * 86_400.0, Ceil), "a month");
+        assert_eq!(rounded(10.5 * 86_400.0, Ceil), "11 days");
+        assert_eq!(rounded(364.0 * 86_400.0, Ceil), "a year");
+        assert_eq!(rounded(380.0 * 86_400.0, Ceil), "1 year, 15 days");
+        assert_eq!(rounded(381.0 * 86_400.0, Ceil), "1 year, 1 month");
+        assert_eq!(rounded(730.0 * 86_400.0 - 1.0, Ceil), "2 years");
+        assert_eq!(rounded(3.6 * 365.0 * 86_400.0, HalfUp), "4 years");
+
+        let delta = TimeDelta::from_days_seconds_micros(1, 3600, 0);
+        let days = |rounding| naturaldelta_rounded(delta, false, "seconds", rounding).unwrap();
+        assert_eq!(days(Floor), "a day");
+        assert_eq!(days(Ceil), "2 days");
+        let delta = TimeDelta::from_micros(1500);
+        assert_eq!(
+            naturaldelta_rounded(delta, true, "milliseconds", HalfEven),
+            Ok("2 milliseconds".to_string())
+        );
+        assert_eq!(
+            naturaldelta_rounded(delta, true, "hours", Ceil),
+            Err(TimeError::UnsupportedMinimumUnit("hours".to_string()))
+        );
+        assert_eq!(
+            naturaldelta_rounded(delta, true, "fortnights", Ceil),
+            Err(TimeError::UnknownUnit("fortnights".to_string()))
+        );
     }
-    if months >= 12.0 && !suppress_set.contains(&Unit::Years) {
-        months -= 12.0;
-        years += 1.0;
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_naturalbusinessdelta() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let monday = date(2024, 6, 10);
+        let business = |end, holidays: &[NaiveDate]| {
+            naturalbusinessdelta(monday, end, SATURDAY_SUNDAY, holidays)
+        };
+        assert_eq!(business(monday, &[]), "0 business days");
+        assert_eq!(business(date(2024, 6, 11), &[]), "1 business day");
+        assert_eq!(business(date(2024, 6, 16), &[]), "4 business days");
+        assert_eq!(business(date(2024, 6, 3), &[]), "5 business days");
+        // Holidays on a weekend, outside the span or listed twice count once
+        let holidays = [
+            date(2024, 6, 12),
+            date(2024, 6, 12),
+            date(2024, 6, 15),
+            date(2024, 6, 10),
+        ];
+        assert_eq!(business(date(2024, 6, 17), &holidays), "4 business days");
+        assert_eq!(business(date(2025, 6, 10), &[]), "261 business days");
+        // A Friday and Saturday weekend
+        let weekend = [Weekday::Fri, Weekday::Sat];
+        assert_eq!(
+            naturalbusinessdelta(monday, date(2024, 6, 16), &weekend, &[]),
+            "4 business days"
+        );
     }
 
-    let fmts: Vec<(&str, &str, f64, Unit)> = vec![
-        ("%d year", "%d years", years, Unit::Years),
-        ("%d month", "%d months", months, Unit::Months),
-        ("%d day", "%d days", days, Unit::Days),
-        ("%d hour", "%d hours", hours, Unit::Hours),
-        ("%d minute", "%d minutes", minutes, Unit::Minutes),
-        ("%d second", "%d seconds", secs, Unit::Seconds),
-        ("%d millisecond", "%d milliseconds", msecs, Unit::Milliseconds),
-        (
-            "%d microsecond",
-            "%d microseconds",
-            usecs,
-            Unit::Microseconds,
-        ),
-    ];
+    #[test]
+    fn test_naturalfrequency() {
+        let every = |seconds| naturalfrequency(TimeDelta::from_seconds(seconds));
+        assert_eq!(every(1.0), "every second");
+        assert_eq!(every(86_400.0), "daily");
+        assert_eq!(every(-604_800.0), "weekly");
+        assert_eq!(every(365.0 * 86_400.0), "yearly");
+        assert_eq!(every(30.0), "twice a minute");
+        assert_eq!(every(300.0), "12 times per hour");
+        assert_eq!(every(0.25), "4 times per second");
+        assert_eq!(every(2.0), "every 2 seconds");
+        assert_eq!(every(2.0 * 86_400.0), "every 2 days");
+        assert_eq!(every(0.0015), "every 1,500 microseconds");
+        assert_eq!(every(730.0 * 86_400.0), "every 2 years");
+        assert_eq!(every(90.5), "every 2 minutes");
+        assert_eq!(every(0.0), "continuously");
+        assert_eq!(naturalfrequency_count(2, Unit::Months), "twice a month");
+        assert_eq!(
+            naturalfrequency_count(1000, Unit::Years),
+            "1,000 times per year"
+        );
+    }
 
-    let mut texts: Vec<String> = Vec::new();
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_naturalclock() {
+        let clock = |h, m, s| naturalclock(NaiveTime::from_hms_opt(h, m, s).unwrap(), false);
+        assert_eq!(clock(0, 0, 0), "midnight");
+        assert_eq!(clock(23, 58, 0), "midnight");
+        assert_eq!(clock(0, 3, 0), "five past twelve");
+        assert_eq!(clock(11, 58, 59), "noon");
+        assert_eq!(clock(9, 0, 0), "nine o'clock");
+        assert_eq!(clock(9, 25, 0), "twenty-five past nine");
+        assert_eq!(clock(9, 31, 0), "half past nine");
+        assert_eq!(clock(9, 35, 0), "twenty-five to ten");
+        assert_eq!(clock(11, 45, 0), "quarter to twelve");
+        assert_eq!(clock(23, 40, 0), "twenty to twelve");
 
-    for (singular, plural, fmt_value, unit) in fmts.iter() {
-        let unit = *unit;
-        let fmt_value = *fmt_value;
+        let precise = |h, m| naturalclock(NaiveTime::from_hms_opt(h, m, 0).unwrap(), true);
+        assert_eq!(precise(0, 7), "12:07 AM");
+        assert_eq!(precise(12, 1), "12:01 PM");
+        assert_eq!(precise(12, 0), "noon");
+        assert_eq!(precise(20, 10), "ten past eight");
+    }
 
-        if fmt_value > 0.0 || (texts.is_empty() && unit == min_unit) {
-            let ngettext_n = if fmt_value > 1.0 && fmt_value < 2.0 {
-                2
-            } else {
-                fmt_value as i64
-            };
-            let mut fmt_txt = i18n::ngettext(singular, plural, ngettext_n);
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_natural_week() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        // A Wednesday
+        let today = date(2024, 7, 17);
+        assert_eq!(natural_week_from(date(2024, 7, 15), today), "this week");
+        assert_eq!(natural_week_from(date(2024, 7, 21), today), "this week");
+        assert_eq!(natural_week_from(date(2024, 7, 14), today), "last week");
+        assert_eq!(natural_week_from(date(2024, 7, 22), today), "next week");
+        assert_eq!(
+            natural_week_from(date(2024, 7, 3), today),
+            "2 weeks ago (W27)"
+        );
+        assert_eq!(
+            natural_week_from(date(2024, 9, 16), today),
+            "in 9 weeks (W38)"
+        );
+        assert_eq!(
+            natural_week_from(date(2025, 7, 17), today),
+            "in 52 weeks (W29)"
+        );
+        assert_eq!(natural_week_from(date(2023, 1, 2), today), "week 1 of 2023");
+        assert_eq!(natural_iso_week(date(2021, 1, 3)), "week 53 of 2020");
+    }
 
-            let frac = fmt_value - (fmt_value as i64) as f64;
-            if unit == min_unit && frac.abs() > 1e-9 {
-                fmt_txt = fmt_txt.replace("%d", format);
-            } else if unit == Unit::Years {
-                let int_check = fmt_value - (fmt_value as i64) as f64;
-                let display_val = if int_check.abs() < 1e-9 {
-                    fmt_value as i64
-                } else {
-                    fmt_value as i64
-                };
-                fmt_txt = fmt_txt.replace("%d", "%s");
-                let formatted = fmt_txt.replace("%s", &intcomma(&display_val.to_string(), None));
-                texts.push(formatted);
-                if unit == min_unit {
-                    break;
-                }
-                continue;
-            }
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_natural_fiscal_quarter() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(natural_quarter(date(2024, 1, 1)), "Q1 2024");
+        assert_eq!(natural_quarter(date(2024, 12, 31)), "Q4 2024");
+        // April starts, as in the UK and India
+        let fiscal = |value| natural_fiscal_quarter(value, Month::April);
+        assert_eq!(fiscal(date(2024, 3, 31)), "FY2024 Q4");
+        assert_eq!(fiscal(date(2024, 4, 1)), "FY2025 Q1");
+        assert_eq!(fiscal(date(2024, 7, 1)), "FY2025 Q2");
+        assert_eq!(fiscal(date(2025, 1, 15)), "FY2025 Q4");
+        assert_eq!(
+            natural_fiscal_quarter(date(2024, 2, 1), Month::February),
+            "FY2025 Q1"
+        );
+    }
 
-            let formatted = if unit == min_unit && frac.abs() > 1e-9 {
-                // The format string was substituted into fmt_txt (e.g., "%0.2f seconds")
-                // We need to format the number part and keep the text
-                let number_str = printf_format(format, fmt_value);
-                fmt_txt.replace(format, &number_str)
-            } else {
-                fmt_txt.replace("%d", &(fmt_value as i64).to_string())
-            };
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn test_jiff() {
+        use jiff::{SignedDuration, Span, ToSpan};
 
-            texts.push(formatted);
-        }
+        assert_eq!(
+            TimeDelta::from(SignedDuration::new(-90, -1_500)),
+            TimeDelta::from_micros(-90_000_001)
+        );
+        assert_eq!(
+            TimeDelta::from(SignedDuration::MAX),
+            TimeDelta::from_days_seconds_micros(0, i64::MAX, 999_999)
+        );
+        assert_eq!(
+            TimeDelta::from(1.month().days(2).hours(3).nanoseconds(2_500)),
+            TimeDelta::from_days_seconds_micros(32, 12 * 3600 + 3 * 3600, 2)
+        );
+        assert_eq!(
+            TimeDelta::from((-1).year().weeks(1)),
+            -TimeDelta::from_days(372)
+        );
+        assert_eq!(TimeDelta::from(Span::new()), TimeDelta::from_seconds(0.0));
 
-        if unit == min_unit {
-            break;
-        }
+        let now: jiff::Timestamp = "2024-05-01T12:00:00Z".parse().unwrap();
+        let zoned: jiff::Zoned = "2024-05-01T08:00:00-03:00[America/Sao_Paulo]"
+            .parse()
+            .unwrap();
+        assert_eq!(naturaltime_zoned_at(&zoned, now), "an hour ago");
+        let zoned: jiff::Zoned = "2024-05-03T12:00:00+00:00[UTC]".parse().unwrap();
+        assert_eq!(naturaltime_zoned_at(&zoned, now), "2 days from now");
     }
 
-    if texts.len() <= 1 {
-        return texts.into_iter().next().unwrap_or_default();
-    }
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_time_crate() {
+        use ::time::{Date, Duration, Month, OffsetDateTime, Time, UtcOffset};
 
-    let head = texts[..texts.len() - 1].join(", ");
-    let tail = &texts[texts.len() - 1];
+        assert_eq!(
+            TimeDelta::from(Duration::new(-90, -1_500)),
+            TimeDelta::from_micros(-90_000_001)
+        );
+        assert_eq!(
+            TimeDelta::from(Duration::MAX),
+            TimeDelta::from_days_seconds_micros(0, i64::MAX, 999_999)
+        );
 
-    let template = i18n::gettext("%s and %s");
-    // Replace first %s with head, second %s with tail
-    let result = template.replacen("%s", &head, 1);
-    result.replacen("%s", tail, 1)
-}
+        let at = |day, hour, offset| {
+            let date = Date::from_calendar_date(2024, Month::May, day).unwrap();
+            let offset = UtcOffset::from_hms(offset, 0, 0).unwrap();
+            OffsetDateTime::new_in_offset(date, Time::from_hms(hour, 0, 0).unwrap(), offset)
+        };
+        let now = at(1, 12, 0);
+        assert_eq!(naturaltime_offset_at(at(1, 8, -3), now), "an hour ago");
+        assert_eq!(naturaltime_offset_at(at(3, 12, 0), now), "2 days from now");
+    }
 
-/// Convenience: precisedelta from seconds (float).
-pub fn precisedelta(seconds: f64, minimum_unit: &str, suppress: &[&str], format: &str) -> String {
-    let delta = TimeDelta::from_seconds(seconds);
-    precisedelta_td(delta, minimum_unit, suppress, format)
-}
+    #[cfg(all(feature = "time", feature = "chrono"))]
+    #[test]
+    fn test_time_crate_date() {
+        use ::time::{Date, Month};
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        assert_eq!(
+            naive_date(Date::from_calendar_date(2024, Month::February, 29).unwrap()),
+            NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+        );
+        assert_eq!(
+            naive_date(Date::MIN),
+            NaiveDate::from_ymd_opt(-9999, 1, 1).unwrap()
+        );
+    }
 
     #[test]
-    fn test_unit_ordering() {
-        assert!(Unit::Seconds < Unit::Minutes);
-        assert!(Unit::Minutes < Unit::Hours);
-        assert!(Unit::Years > Unit::Days);
+    fn test_fitdelta() {
+        let fit = |seconds, width| fitdelta(TimeDelta::from_seconds(seconds), width);
+        assert_eq!(fit(0.0, 20), "0 seconds");
+        assert_eq!(fit(0.0, 5), "0s");
+        assert_eq!(fit(-90.0, 23), "1 minute and 30 seconds");
+        assert_eq!(fit(-90.0, 22), "1m 30s");
+        assert_eq!(fit(5_430.0, 8), "1h 31m");
+        assert_eq!(fit(5_430.0, 4), "2h");
+        assert_eq!(fit(5_430.0, 1), "2h");
+        assert_eq!(fit(372.0 * 86_400.0, 12), "1 year");
+        assert_eq!(fit(372.0 * 86_400.0, 5), "1y");
     }
 
     #[test]
-    fn test_timedelta_from_seconds() {
-        let td = TimeDelta::from_seconds(90.0);
-        assert_eq!(td.days, 0);
-        assert_eq!(td.seconds, 90);
-        assert_eq!(td.microseconds, 0);
+    fn test_naturalduration_short() {
+        use std::time::Duration;
+        let short = |nanos| naturalduration_short(Duration::from_nanos(nanos));
+        assert_eq!(short(0), "0 ns");
+        assert_eq!(short(999), "999 ns");
+        assert_eq!(short(1_000), "1 \u{b5}s");
+        assert_eq!(short(1_500), "1.5 \u{b5}s");
+        assert_eq!(short(12_345), "12.3 \u{b5}s");
+        assert_eq!(short(999_700), "1 ms");
+        assert_eq!(short(999_999_999), "1 s");
+        assert_eq!(short(59_999_000_000), "60 s");
+        assert_eq!(short(60_000_000_000), "1m");
+        assert_eq!(
+            naturalduration_short(-TimeDelta::from_millis(2_050)),
+            "2.05 s"
+        );
     }
 
     #[test]
-    fn test_naturaldelta_basic() {
-        let td = TimeDelta::from_days_seconds_micros(7, 0, 0);
-        assert_eq!(naturaldelta_td(td, true, "seconds"), "7 days");
+    fn test_decimaldelta() {
+        let hours = |seconds, precision| {
+            decimaldelta(TimeDelta::from_seconds(seconds), Unit::Hours, precision)
+        };
+        assert_eq!(hours(0.0, 2), "0 hours");
+        assert_eq!(hours(-5400.0, 2), "1.5 hours");
+        assert_eq!(hours(3600.0, 3), "1 hour");
+        assert_eq!(hours(3601.0, 3), "1 hour");
+        assert_eq!(hours(3602.0, 3), "1.001 hours");
+        assert_eq!(hours(4000.0, 0), "1 hour");
+        assert_eq!(hours(5400.0, 0), "2 hours");
+        assert_eq!(hours(1.0, 20), "0.00027778 hours");
+        assert_eq!(
+            decimaldelta(TimeDelta::from_days(61), Unit::Months, 2),
+            "2 months"
+        );
+        assert_eq!(
+            decimaldelta(TimeDelta::MAX, Unit::Microseconds, 8),
+            "796899343984252629811199999999 microseconds"
+        );
     }
 
     #[test]
-    fn test_naturaldelta_moment() {
-        let td = TimeDelta::from_seconds(0.0);
-        assert_eq!(naturaldelta_td(td, true, "seconds"), "a moment");
+    fn test_naturaldelta_halves() {
+        let halves = |seconds| naturaldelta_halves(TimeDelta::from_seconds(seconds));
+        assert_eq!(halves(45.0), "45 seconds");
+        assert_eq!(halves(90.0), "a minute and a half");
+        assert_eq!(halves(150.0), "2 and a half minutes");
+        assert_eq!(halves(-1.55 * 3600.0), "an hour and a half");
+        assert_eq!(halves(1.65 * 3600.0), "2 hours");
+        assert_eq!(halves(36.0 * 3600.0), "a day and a half");
+        assert_eq!(halves(45.0 * 86_400.0), "a month and a half");
+        assert_eq!(halves(100.0 * 86_400.0), "3 months");
+        assert_eq!(halves(2_500.5 * 365.0 * 86_400.0), "2,500 and a half years");
     }
 
     #[test]
-    fn test_naturaldelta_seconds() {
-        let td = TimeDelta::from_seconds(1.0);
-        assert_eq!(naturaldelta_td(td, true, "seconds"), "a second");
+    fn test_file_times() {
+        let now = UNIX_EPOCH + std::time::Duration::from_secs(1_718_000_000);
+        let at = |secs_ago: i64| {
+            let offset = std::time::Duration::from_secs(secs_ago.unsigned_abs());
+            let time = if secs_ago < 0 {
+                now + offset
+            } else {
+                now - offset
+            };
+            file_time(time, now, "modified %s")
+        };
+        assert_eq!(at(7200), "modified 2 hours ago");
+        assert_eq!(at(0), "modified now");
+        assert_eq!(at(-90), "modified 2 minutes from now");
 
-        let td = TimeDelta::from_seconds(30.0);
-        assert_eq!(naturaldelta_td(td, true, "seconds"), "30 seconds");
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+        assert!(naturalmtime(&path).unwrap().starts_with("modified "));
+        assert!(naturalatime(&path).unwrap().starts_with("accessed "));
+        let missing = path.with_file_name("no-such-file");
+        assert_eq!(
+            naturalmtime(&missing).unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
     }
 
     #[test]
-    fn test_naturaldelta_minutes() {
-        let td = TimeDelta::from_seconds(120.0);
-        assert_eq!(naturaldelta_td(td, true, "seconds"), "2 minutes");
+    fn test_naturaleta() {
+        let minute = TimeDelta::from_minutes(1);
+        assert_eq!(naturaleta(1, 3, minute), "about 2 minutes remaining");
+        assert_eq!(naturaleta(3, 4, minute), "about 20 seconds remaining");
+        assert_eq!(
+            naturaleta(1, 1_000_000, minute),
+            "about 1 year, 11 months remaining"
+        );
+        assert_eq!(naturaleta(100, 100, minute), "finishing up");
+        assert_eq!(naturaleta(101, 100, minute), "finishing up");
+        assert_eq!(naturaleta(0, 0, minute), "finishing up");
+        assert_eq!(
+            naturaleta(0, 1, TimeDelta::MAX),
+            "estimating time remaining"
+        );
+        assert_eq!(
+            naturaleta(1, u64::MAX, TimeDelta::MAX),
+            naturaleta(1, 2, TimeDelta::MAX)
+        );
     }
 
     #[test]
-    fn test_naturaldelta_hours() {
-        let td = TimeDelta::from_days_seconds_micros(0, 3600, 0);
-        assert_eq!(naturaldelta_td(td, true, "seconds"), "an hour");
+    fn test_natural_utc_offset() {
+        assert_eq!(natural_utc_offset(20_700), "UTC+05:45");
+        assert_eq!(natural_utc_offset(-9 * 3600 - 1800), "UTC-09:30");
+        assert_eq!(natural_utc_offset(14 * 3600), "UTC+14:00");
+        assert_eq!(natural_utc_offset(-(17 * 60 + 30)), "UTC-00:17:30");
+        assert_eq!(natural_utc_offset(i32::MIN), "UTC-596523:14:08");
     }
 
     #[test]
-    fn test_naturaldelta_years() {
-        let td = TimeDelta::from_days_seconds_micros(365 * 2 + 35, 0, 0);
-        assert_eq!(naturaldelta_td(td, true, "seconds"), "2 years");
+    fn test_natural_offset_difference() {
+        assert_eq!(natural_offset_difference(0, 1800), "30 minutes behind you");
+        assert_eq!(natural_offset_difference(3600, 0), "1 hour ahead of you");
+        assert_eq!(
+            natural_offset_difference(14 * 3600, -12 * 3600),
+            "26 hours ahead of you"
+        );
+        assert_eq!(natural_offset_difference(30, 0), "same time as you");
+        assert_eq!(
+            natural_offset_difference(i32::MIN, i32::MAX),
+            "1193046 hours and 28 minutes behind you"
+        );
     }
 
     #[test]
-    fn test_naturaldelta_large_years() {
-        let td = TimeDelta::from_days_seconds_micros(365 * 1141, 0, 0);
-        assert_eq!(naturaldelta_td(td, true, "seconds"), "1,141 years");
+    fn test_human_elapsed() {
+        assert_eq!(
+            started_ago(TimeDelta::from_seconds(0.5)),
+            "started just now"
+        );
+        assert_eq!(
+            started_ago(TimeDelta::from_seconds(1.0)),
+            "started a second ago"
+        );
+        assert_eq!(
+            started_ago(TimeDelta::from_minutes(2)),
+            "started 2 minutes ago"
+        );
+
+        let started = Instant::now() - std::time::Duration::from_secs(120);
+        assert_eq!(
+            HumanElapsed::from(started).to_string(),
+            "started 2 minutes ago"
+        );
+        assert!(HumanElapsed(started).elapsed() >= TimeDelta::from_minutes(2));
     }
 
     #[test]
-    fn test_naturaltime_past() {
-        let td = TimeDelta::from_seconds(30.0);
+    fn test_naturaltime_refresh() {
+        let refresh =
+            |seconds| naturaltime_refresh(TimeDelta::from_seconds(seconds), true, "seconds");
+        let wait = |seconds: f64| Some(TimeDelta::from_seconds(seconds));
+        assert_eq!(refresh(0.0), ("now".to_string(), wait(1.0)));
+        assert_eq!(refresh(30.5), ("30 seconds ago".to_string(), wait(0.5)));
+        // 2.5 minutes still rounds to 2
+        assert_eq!(refresh(120.0), ("2 minutes ago".to_string(), wait(31.0)));
+        // 1.5 minutes also rounds to 2
+        let text = "2 minutes from now".to_string();
         assert_eq!(
-            naturaltime_delta(td, false, true, "seconds"),
-            "30 seconds ago"
+            refresh(-120.0),
+            (text, Some(TimeDelta::from_micros(30_000_001)))
+        );
+        assert_eq!(refresh(-0.25), ("now".to_string(), wait(1.25)));
+        assert_eq!(
+            refresh(3.0 * 365.0 * 86_400.0),
+            ("3 years ago".to_string(), wait(365.0 * 86_400.0))
         );
+        let (text, wait) = naturaltime_refresh(TimeDelta::MAX, true, "seconds");
+        assert!(text.starts_with("more than"));
+        assert_eq!(wait, None);
     }
 
     #[test]
-    fn test_naturaltime_future() {
-        let td = TimeDelta::from_seconds(30.0);
+    fn test_timedelta_snap() {
+        let quarter = TimeDelta::from_minutes(15);
+        let snap = |minutes: f64, rounding| {
+            TimeDelta::from_seconds(minutes * 60.0).snap(quarter, rounding)
+        };
+        assert_eq!(snap(7.5, Rounding::HalfUp), quarter);
+        assert_eq!(snap(7.5, Rounding::HalfEven), TimeDelta::from_seconds(0.0));
+        assert_eq!(snap(22.5, Rounding::HalfEven), TimeDelta::from_minutes(30));
+        assert_eq!(snap(1.0, Rounding::Ceil), quarter);
+        assert_eq!(snap(-20.0, Rounding::HalfUp), -quarter);
+        assert_eq!(snap(-20.0, Rounding::Floor), -quarter);
         assert_eq!(
-            naturaltime_delta(td, true, true, "seconds"),
-            "30 seconds from now"
+            TimeDelta::from_seconds(95.5).snap(TimeDelta::from_seconds(0.0), Rounding::Floor),
+            TimeDelta::from_seconds(95.5)
         );
+        assert_eq!(TimeDelta::MAX.snap(quarter, Rounding::Ceil), TimeDelta::MAX);
     }
 
     #[test]
-    fn test_naturaltime_now() {
-        let td = TimeDelta::from_seconds(0.0);
-        assert_eq!(naturaltime_delta(td, false, true, "seconds"), "now");
+    fn test_naturaldelta_precision() {
+        let precise = |seconds, months, precision| {
+            naturaldelta_precision(
+                TimeDelta::from_seconds(seconds),
+                months,
+                "seconds",
+                precision,
+            )
+            .unwrap()
+        };
+        assert_eq!(precise(0.0, true, 2), "a moment");
+        assert_eq!(precise(3600.0, true, 2), "an hour");
+        assert_eq!(precise(3629.0, true, 2), "an hour");
+        assert_eq!(precise(3690.0, true, 2), "1 hour and 2 minutes");
+        assert_eq!(precise(93_784.0, true, 3), "1 day, 2 hours and 3 minutes");
+        assert_eq!(precise(45.0 * 86_400.0, true, 2), "1 month and 14 days");
+        assert_eq!(precise(45.0 * 86_400.0, false, 2), "45 days");
+        assert_eq!(
+            naturaldelta_precision(TimeDelta::from_seconds(1.0), true, "days", 2),
+            Err(TimeError::UnsupportedMinimumUnit("days".to_string()))
+        );
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
-    fn test_precisedelta_basic() {
-        let td = TimeDelta::from_days_seconds_micros(2, 3633, 123000);
+    fn test_naturalage() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let on = date(2024, 6, 12);
+        let age = |born| naturalage_on(born, on).unwrap();
+        assert_eq!(age(on), "0 days old");
+        assert_eq!(age(date(2024, 6, 11)), "1 day old");
+        assert_eq!(age(date(2024, 5, 29)), "2 weeks old");
+        assert_eq!(age(date(2024, 3, 13)), "13 weeks old");
+        assert_eq!(age(date(2024, 3, 12)), "3 months old");
+        assert_eq!(age(date(2022, 6, 12)), "2 years old");
+        // Leap day birthdays come round on February 28th
+        let leap = |on| naturalage_on(date(2020, 2, 29), on).unwrap();
+        assert_eq!(leap(date(2023, 2, 27)), "2 years old");
+        assert_eq!(leap(date(2023, 2, 28)), "3 years old");
         assert_eq!(
-            precisedelta_td(td, "seconds", &[], "%0.2f"),
-            "2 days, 1 hour and 33.12 seconds"
+            naturalage_on(date(2024, 6, 13), on),
+            Err(TimeError::FutureBirthdate)
         );
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
-    fn test_precisedelta_single_unit() {
-        let td = TimeDelta::from_seconds(1.0);
-        assert_eq!(precisedelta_td(td, "seconds", &[], "%0.2f"), "1 second");
+    fn test_naturaldate_ordinal() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+        assert_eq!(naturaldate_ordinal(date(1, 1)), "January 1st, 2024");
+        assert_eq!(naturaldate_ordinal(date(3, 11)), "March 11th, 2024");
+        assert_eq!(naturaldate_ordinal(date(5, 12)), "May 12th, 2024");
+        assert_eq!(naturaldate_ordinal(date(12, 22)), "December 22nd, 2024");
+    }
 
-        let td = TimeDelta::from_seconds(60.0);
-        assert_eq!(precisedelta_td(td, "seconds", &[], "%0.2f"), "1 minute");
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_natural_period() {
+        use Granularity::*;
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        // A Wednesday
+        let today = date(2024, 8, 14);
+        let period = |value, granularity| natural_period_from(value, granularity, today);
 
-        let td = TimeDelta::from_seconds(3600.0);
-        assert_eq!(precisedelta_td(td, "seconds", &[], "%0.2f"), "1 hour");
+        assert_eq!(period(date(2024, 8, 13), Day), "yesterday");
+        assert_eq!(period(date(2024, 8, 12), Day), "August 12th, 2024");
+        assert_eq!(period(date(2024, 8, 16), Day), "August 16th, 2024");
+        assert_eq!(period(date(2023, 6, 3), Day), "June 3rd, 2023");
+        assert_eq!(period(date(2024, 8, 12), Week), "this week");
+        assert_eq!(period(date(2024, 8, 11), Week), "last week");
+        assert_eq!(period(date(2024, 7, 29), Week), "week 31, 2024");
+        assert_eq!(period(date(2024, 12, 31), Week), "week 1, 2025");
+        assert_eq!(period(date(2025, 1, 1), Week), "week 1, 2025");
+        assert_eq!(period(date(2024, 9, 1), Month), "next month");
+        assert_eq!(period(date(2023, 12, 1), Month), "December 2023");
+        assert_eq!(period(date(2024, 11, 1), Month), "later this year");
+        assert_eq!(period(date(2024, 4, 1), Quarter), "last quarter");
+        assert_eq!(period(date(2024, 1, 1), Quarter), "earlier this year");
+        assert_eq!(period(date(2025, 8, 1), Quarter), "Q3 2025");
+        assert_eq!(period(date(2025, 1, 1), Year), "next year");
+        assert_eq!(period(date(2021, 1, 1), Year), "2021");
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
-    fn test_precisedelta_suppress() {
-        let td = TimeDelta::from_days_seconds_micros(2, 3633, 123000);
-        assert_eq!(
-            precisedelta_td(td, "seconds", &["days"], "%0.2f"),
-            "49 hours and 33.12 seconds"
-        );
+    fn test_natural_era() {
+        let era = |year| natural_era_from(year, 2024);
+        assert_eq!(era(2020), "this decade");
+        assert_eq!(era(1995), "in the 1990s");
+        assert_eq!(era(1924), "in the 1920s");
+        assert_eq!(era(2061), "in the 2060s");
+        assert_eq!(era(1900), "late 19th century");
+        assert_eq!(era(1901), "early 20th century");
+        assert_eq!(era(1066), "mid-11th century");
+        assert_eq!(era(1023), "about 1,000 years ago");
+        assert_eq!(era(-65_000_000), "about 65 million years ago");
+        assert_eq!(era(5024), "in about 3,000 years");
+        assert_eq!(era(i64::MIN), "about 9.2 quintillion years ago");
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
-    fn test_rounding_by_fmt() {
-        assert!((rounding_by_fmt("%.2f", 1.011) - 1.01).abs() < 1e-9);
-        assert!((rounding_by_fmt("%.0f", 1.5) - 2.0).abs() < 1e-9);
-        assert_eq!(rounding_by_fmt("%d", 1.999999999999999) as i64, 1);
+    fn test_natural_daypart() {
+        let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        assert_eq!(natural_daypart(time(0, 0), DAYPARTS), "overnight");
+        assert_eq!(natural_daypart(time(9, 59), DAYPARTS), "morning");
+        assert_eq!(natural_daypart(time(10, 0), DAYPARTS), "mid-morning");
+        assert_eq!(natural_daypart(time(23, 30), DAYPARTS), "overnight");
+
+        let shifts = [(6, 0, "day shift"), (18, 0, "night shift")];
+        assert_eq!(natural_daypart(time(3, 0), &shifts), "night shift");
+        assert_eq!(natural_daypart(time(6, 0), &shifts), "day shift");
+        assert_eq!(natural_daypart(time(6, 0), &[]), "");
     }
 
     #[test]
-    fn test_microseconds() {
-        let td = TimeDelta::from_days_seconds_micros(0, 0, 1);
+    fn test_naturaldowntime() {
         assert_eq!(
-            naturaldelta_td(td, true, "microseconds"),
-            "1 microsecond"
+            naturaldowntime(0.99, Unit::Weeks).unwrap(),
+            "1 hour and 41 minutes of downtime per week"
         );
-
-        let td = TimeDelta::from_days_seconds_micros(0, 0, 4);
         assert_eq!(
-            naturaldelta_td(td, true, "microseconds"),
-            "4 microseconds"
+            naturaldowntime(0.0, Unit::Minutes).unwrap(),
+            "1 minute of downtime per minute"
+        );
+        assert_eq!(naturaldowntime(1.0, Unit::Years).unwrap(), "no downtime");
+        assert_eq!(
+            naturaldowntime(-0.1, Unit::Years),
+            Err(TimeError::AvailabilityOutOfRange)
+        );
+        assert_eq!(
+            naturaldowntime(f64::NAN, Unit::Days),
+            Err(TimeError::AvailabilityOutOfRange)
         );
     }
 
     #[test]
-    fn test_milliseconds() {
-        let td = TimeDelta::from_days_seconds_micros(0, 0, 1000);
+    fn test_natural_uptime() {
         assert_eq!(
-            naturaldelta_td(td, true, "milliseconds"),
-            "1 millisecond"
+            natural_uptime(0.99999, Unit::Months).unwrap(),
+            "99.999% — about 26 seconds of downtime per month"
         );
+        assert_eq!(
+            natural_uptime(0.0, Unit::Days).unwrap(),
+            "0% — about 1 day of downtime per day"
+        );
+        assert_eq!(
+            natural_uptime(0.999, Unit::Hours).unwrap(),
+            "99.9% — about 4 seconds of downtime per hour"
+        );
+        assert_eq!(
+            natural_uptime(1.5, Unit::Years),
+            Err(TimeError::AvailabilityOutOfRange)
+        );
+        assert_eq!(
+            natural_uptime(f64::NAN, Unit::Years),
+            Err(TimeError::AvailabilityOutOfRange)
+        );
+    }
 
-        let td = TimeDelta::from_days_seconds_micros(0, 0, 4000);
+    #[test]
+    fn test_stopwatch() {
+        assert_eq!(stopwatch(TimeDelta::from_seconds(0.0)), "0:00");
+        assert_eq!(stopwatch(TimeDelta::from_seconds(59.6)), "1:00");
+        assert_eq!(stopwatch(TimeDelta::from_seconds(-0.4)), "0:00");
         assert_eq!(
-            naturaldelta_td(td, true, "milliseconds"),
-            "4 milliseconds"
+            stopwatch(TimeDelta::from_days_seconds_micros(1, 61, 0)),
+            "24:01:01"
         );
     }
+
+    #[test]
+    fn test_clockdelta() {
+        let clock = |seconds: f64, always_hours, ms| {
+            clockdelta(TimeDelta::from_seconds(seconds), always_hours, ms)
+        };
+        assert_eq!(clock(0.0, true, true), "0:00:00.000");
+        assert_eq!(clock(59.9996, false, true), "1:00.000");
+        assert_eq!(clock(-0.0004, false, true), "0:00.000");
+        assert_eq!(clock(-0.25, false, true), "-0:00.250");
+        assert_eq!(clock(-3600.0, true, false), "-1:00:00");
+    }
+
+    #[test]
+    fn test_compactdelta() {
+        let compact =
+            |seconds: f64, unit| compactdelta(TimeDelta::from_seconds(seconds), unit, " ", "");
+        assert_eq!(compact(0.0, Unit::Seconds), "0s");
+        assert_eq!(compact(0.4, Unit::Seconds), "0s");
+        assert_eq!(compact(-0.4, Unit::Seconds), "0s");
+        assert_eq!(compact(59.6, Unit::Seconds), "1m");
+        assert_eq!(compact(0.0015, Unit::Microseconds), "1ms 500µs");
+        assert_eq!(compact(86_400.0 * 400.0, Unit::Seconds), "1y 5w");
+        // No abbreviation for months
+        assert_eq!(compact(86_400.0 * 45.0, Unit::Months), "6w");
+        assert_eq!(compact(86_400.0 * 45.0, Unit::Years), "0y");
+    }
+
+    #[test]
+    fn test_parse_delta() {
+        let td = parse_delta("a moment").unwrap();
+        assert_eq!(td.total_seconds(), 0.0);
+        let td = parse_delta("an hour").unwrap();
+        assert_eq!(td.total_seconds(), 3600.0);
+        let td = parse_delta("1,000 years").unwrap();
+        assert_eq!(td.days, 365_000);
+        let td = parse_delta("1 month").unwrap();
+        assert_eq!((td.days, td.seconds), (30, 43_200));
+        let td = parse_delta("2 days, 1 hour and 33.12 seconds").unwrap();
+        assert_eq!((td.days, td.seconds, td.microseconds), (2, 3633, 120_000));
+
+        let td = parse_delta("2 weeks and 3 days").unwrap();
+        assert_eq!(td.days, 17);
+
+        let invalid = ["", "hours", "2", "two hours", "-2 hours", "2 fortnights", "2 h,"];
+        for bad in invalid {
+            assert!(parse_delta(bad).is_err(), "{:?}", bad);
+        }
+    }
+
+    #[test]
+    fn test_parse_delta_round_trip() {
+        for secs in [1.0, 30.0, 90.0, 3600.0, 86_400.0, 864_000.0, 5_000_000.0] {
+            let text = naturaldelta(secs, true, "seconds");
+            let parsed = parse_delta(&text).unwrap();
+            assert_eq!(naturaldelta_td(parsed, true, "seconds"), text);
+        }
+    }
+
+    #[test]
+    fn test_parse_naturaltime() {
+        assert_eq!(parse_naturaltime("now").unwrap().total_seconds(), 0.0);
+        let td = parse_naturaltime("3 seconds ago").unwrap();
+        assert_eq!(td.total_seconds(), 3.0);
+        let td = parse_naturaltime("a day from now").unwrap();
+        assert_eq!((td.days, td.seconds), (-1, 0));
+        let td = parse_naturaltime("30 seconds from now").unwrap();
+        assert_eq!((td.days, td.seconds), (-1, 86_370));
+        let err = parse_naturaltime("ago").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::UnknownUnit);
+        assert_eq!(err.input(), "ago");
+    }
 }
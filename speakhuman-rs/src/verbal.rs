@@ -0,0 +1,478 @@
+//! Verbal output: spelled-out numbers and full unit names, for speech.
+//!
+//! When the [`OutputStyle::Verbal`] style is active on the current thread,
+//! `naturalsize`, `metric`, `scientific` and the time formatters produce text
+//! meant to be read aloud by a screen reader or voice assistant: "three point
+//! one four megabytes" instead of "3.14 MB", "two hours and five minutes"
+//! instead of "2 hours and 5 minutes". Numbers are spelled in English.
+//!
+//! The style is per thread, like [`crate::i18n::activate`].
+
+use std::cell::Cell;
+
+/// How formatters write numbers and units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputStyle {
+    /// Digits, unit symbols and superscripts ("3.14 MB", "1.00 x 10³").
+    #[default]
+    Symbolic,
+    /// Spelled-out numbers and full unit words, with no symbols.
+    Verbal,
+}
+
+thread_local! {
+    static STYLE: Cell<OutputStyle> = const { Cell::new(OutputStyle::Symbolic) };
+}
+
+/// Set the output style for the current thread.
+///
+/// # Examples
+/// ```
+/// use speakhuman::verbal::{set_output_style, OutputStyle};
+/// set_output_style(OutputStyle::Verbal);
+/// assert_eq!(speakhuman::naturaldelta(7200.0, true, "seconds"), "two hours");
+/// set_output_style(OutputStyle::Symbolic);
+/// assert_eq!(speakhuman::naturaldelta(7200.0, true, "seconds"), "2 hours");
+/// ```
+pub fn set_output_style(style: OutputStyle) {
+    STYLE.with(|s| s.set(style));
+}
+
+/// The output style of the current thread.
+pub fn output_style() -> OutputStyle {
+    STYLE.with(Cell::get)
+}
+
+pub(crate) fn is_verbal() -> bool {
+    output_style() == OutputStyle::Verbal
+}
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Names of successive powers of a thousand.
+const SCALES: [&str; 12] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+];
+
+/// Spell a decimal number in English words.
+///
+/// Accepts an optional sign, digits grouped with commas or underscores, and a
+/// fractional part, whose digits are read one by one. Integers too large for
+/// the named scales are read digit by digit. Returns `None` if `value` is not
+/// a plain decimal number.
+///
+/// # Examples
+/// ```
+/// use speakhuman::verbal::spell_number;
+/// assert_eq!(spell_number("3.14").unwrap(), "three point one four");
+/// assert_eq!(spell_number("-1,042").unwrap(), "minus one thousand forty-two");
+/// assert_eq!(spell_number("0.05").unwrap(), "zero point zero five");
+/// assert!(spell_number("1e3").is_none());
+/// ```
+pub fn spell_number(value: &str) -> Option<String> {
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+    let integer = integer.replace([',', '_'], "");
+    if (integer.is_empty() && fraction.is_empty())
+        || !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let mut words = Vec::new();
+    let is_zero = integer.bytes().chain(fraction.bytes()).all(|b| b == b'0');
+    if negative && !is_zero {
+        words.push("minus".to_string());
+    }
+    words.push(spell_integer(&integer));
+    if !fraction.is_empty() {
+        words.push("point".to_string());
+        words.extend(
+            fraction
+                .bytes()
+                .map(|b| ONES[(b - b'0') as usize].to_string()),
+        );
+    }
+    Some(words.join(" "))
+}
+
+/// Spell a string of digits as a whole number.
+fn spell_integer(digits: &str) -> String {
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return ONES[0].to_string();
+    }
+    if digits.len() > SCALES.len() * 3 {
+        return digits
+            .bytes()
+            .map(|b| ONES[(b - b'0') as usize])
+            .collect::<Vec<_>>()
+            .join(" ");
+    }
+
+    // Split into groups of three from the right
+    let groups: Vec<usize> = digits
+        .as_bytes()
+        .rchunks(3)
+        .map(|chunk| chunk.iter().fold(0, |n, b| n * 10 + (b - b'0') as usize))
+        .collect();
+    let mut words = Vec::new();
+    for (scale, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        words.push(spell_below_thousand(group));
+        if scale > 0 {
+            words.push(SCALES[scale].to_string());
+        }
+    }
+    words.join(" ")
+}
+
+fn spell_below_thousand(n: usize) -> String {
+    let mut words = Vec::new();
+    if n >= 100 {
+        words.push(format!("{} hundred", ONES[n / 100]));
+    }
+    let rest = n % 100;
+    if rest >= 20 {
+        words.push(match rest % 10 {
+            0 => TENS[rest / 10].to_string(),
+            ones => format!("{}-{}", TENS[rest / 10], ONES[ones]),
+        });
+    } else if rest > 0 {
+        words.push(ONES[rest].to_string());
+    }
+    words.join(" ")
+}
+
+/// Currencies known to [`currency_words`], as (ISO 4217 code, major unit
+/// singular and plural, minor unit singular and plural, minor digits).
+const CURRENCIES: &[(&str, &str, &str, &str, &str, usize)] = &[
+    ("USD", "dollar", "dollars", "cent", "cents", 2),
+    ("CAD", "dollar", "dollars", "cent", "cents", 2),
+    ("AUD", "dollar", "dollars", "cent", "cents", 2),
+    ("NZD", "dollar", "dollars", "cent", "cents", 2),
+    ("EUR", "euro", "euros", "cent", "cents", 2),
+    ("GBP", "pound", "pounds", "penny", "pence", 2),
+    ("CHF", "franc", "francs", "centime", "centimes", 2),
+    ("INR", "rupee", "rupees", "paisa", "paise", 2),
+    ("MXN", "peso", "pesos", "centavo", "centavos", 2),
+    ("BRL", "real", "reais", "centavo", "centavos", 2),
+    ("CNY", "yuan", "yuan", "fen", "fen", 2),
+    ("JPY", "yen", "yen", "", "", 0),
+    ("KRW", "won", "won", "", "", 0),
+];
+
+/// Write a money amount in words, as on a check.
+///
+/// The amount is rounded to the currency's minor unit; a zero minor part is
+/// left out. `currency` is an ISO 4217 code, matched case-insensitively.
+/// Returns an error for unknown currencies and non-finite amounts.
+///
+/// # Examples
+/// ```
+/// use speakhuman::verbal::currency_words;
+/// assert_eq!(
+///     currency_words(1234.56, "USD").unwrap(),
+///     "one thousand two hundred thirty-four dollars and fifty-six cents"
+/// );
+/// assert_eq!(currency_words(1.0, "GBP").unwrap(), "one pound");
+/// assert_eq!(currency_words(0.01, "EUR").unwrap(), "one cent");
+/// assert_eq!(currency_words(500.4, "JPY").unwrap(), "five hundred yen");
+/// assert!(currency_words(1.0, "XYZ").is_err());
+/// ```
+pub fn currency_words(amount: f64, currency: &str) -> Result<String, String> {
+    let &(_, major_one, major_many, minor_one, minor_many, digits) = CURRENCIES
+        .iter()
+        .find(|(code, ..)| code.eq_ignore_ascii_case(currency))
+        .ok_or_else(|| format!("Unknown currency: {:?}", currency))?;
+    if !amount.is_finite() {
+        return Err(format!("Amount is not finite: {}", amount));
+    }
+
+    let formatted = format!("{:.*}", digits, amount.abs());
+    let (major, minor) = formatted.split_once('.').unwrap_or((&formatted, ""));
+    let minor = minor.trim_start_matches('0');
+    let name = |digits: &str, one, many| {
+        let unit = if digits == "1" { one } else { many };
+        format!("{} {}", spell_integer(digits), unit)
+    };
+
+    let mut words = Vec::new();
+    if amount < 0.0 && formatted.bytes().any(|b| (b'1'..=b'9').contains(&b)) {
+        words.push("minus".to_string());
+    }
+    if major != "0" || minor.is_empty() {
+        words.push(name(major, major_one, major_many));
+    }
+    if !minor.is_empty() {
+        if major != "0" {
+            words.push("and".to_string());
+        }
+        words.push(name(minor, minor_one, minor_many));
+    }
+    Ok(words.join(" "))
+}
+
+/// Spell a formatted quantity, dropping trailing fractional zeros ("3.0" is
+/// read "three"). Returns the words and whether the unit should be plural.
+pub(crate) fn spell_quantity(number: &str) -> (String, bool) {
+    let trimmed = match number.split_once('.') {
+        Some((integer, fraction)) if fraction.bytes().all(|b| b == b'0') => integer,
+        Some(_) => number.trim_end_matches('0'),
+        None => number,
+    };
+    let plural = !matches!(trimmed, "1" | "-1" | "+1");
+    let words = spell_number(trimmed).unwrap_or_else(|| number.to_string());
+    (words, plural)
+}
+
+/// Replace every number in `text` with its spelled form ("2 hours and 5
+/// minutes" becomes "two hours and five minutes").
+pub(crate) fn spell_numbers_in(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let starts_number = chars[i].is_ascii_digit()
+            || (chars[i] == '-' && chars.get(i + 1).is_some_and(char::is_ascii_digit));
+        if !starts_number {
+            out.push(chars[i]);
+            i += 1;
+            continue;
+        }
+        let start = i;
+        i += 1;
+        while i < chars.len() {
+            let c = chars[i];
+            let joins_digits =
+                (c == ',' || c == '.') && chars.get(i + 1).is_some_and(char::is_ascii_digit);
+            if c.is_ascii_digit() || joins_digits {
+                i += 1;
+            } else {
+                break;
+            }
+        }
+        let token: String = chars[start..i].iter().collect();
+        out.push_str(&spell_number(&token).unwrap_or(token));
+    }
+    out
+}
+
+/// Read the text of a non-finite value ("NaN", "+Inf", "-Inf") aloud.
+pub(crate) fn spell_not_finite(value: f64) -> String {
+    if value.is_nan() {
+        "not a number".to_string()
+    } else if value < 0.0 {
+        "minus infinity".to_string()
+    } else {
+        "infinity".to_string()
+    }
+}
+
+/// Full name of an SI prefix symbol.
+pub(crate) fn prefix_name(symbol: &str) -> &str {
+    match symbol {
+        "Q" => "quetta",
+        "R" => "ronna",
+        "Y" => "yotta",
+        "Z" => "zetta",
+        "E" => "exa",
+        "P" => "peta",
+        "T" => "tera",
+        "G" => "giga",
+        "M" => "mega",
+        "k" => "kilo",
+        "m" => "milli",
+        "\u{03BC}" => "micro",
+        "n" => "nano",
+        "p" => "pico",
+        "f" => "femto",
+        "a" => "atto",
+        "z" => "zepto",
+        "y" => "yocto",
+        "r" => "ronto",
+        "q" => "quecto",
+        other => other,
+    }
+}
+
+/// Singular and plural names of a unit symbol. Unknown symbols are read as
+/// they are.
+pub(crate) fn unit_name(symbol: &str) -> (&str, &str) {
+    match symbol {
+        "m" => ("meter", "meters"),
+        "g" => ("gram", "grams"),
+        "s" => ("second", "seconds"),
+        "A" => ("ampere", "amperes"),
+        "K" => ("kelvin", "kelvins"),
+        "mol" => ("mole", "moles"),
+        "cd" => ("candela", "candelas"),
+        "Hz" => ("hertz", "hertz"),
+        "N" => ("newton", "newtons"),
+        "Pa" => ("pascal", "pascals"),
+        "J" => ("joule", "joules"),
+        "W" => ("watt", "watts"),
+        "C" => ("coulomb", "coulombs"),
+        "V" => ("volt", "volts"),
+        "F" => ("farad", "farads"),
+        "\u{03A9}" | "\u{2126}" => ("ohm", "ohms"),
+        "S" => ("siemens", "siemens"),
+        "Wb" => ("weber", "webers"),
+        "T" => ("tesla", "teslas"),
+        "H" => ("henry", "henries"),
+        "L" => ("liter", "liters"),
+        "B" => ("byte", "bytes"),
+        "b" => ("bit", "bits"),
+        "eV" => ("electronvolt", "electronvolts"),
+        "°" => ("degree", "degrees"),
+        "°C" => ("degree Celsius", "degrees Celsius"),
+        "°F" => ("degree Fahrenheit", "degrees Fahrenheit"),
+        "′" => ("arcminute", "arcminutes"),
+        "″" => ("arcsecond", "arcseconds"),
+        other => (other, other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spell_integer() {
+        assert_eq!(spell_integer("0"), "zero");
+        assert_eq!(spell_integer("13"), "thirteen");
+        assert_eq!(spell_integer("90"), "ninety");
+        assert_eq!(spell_integer("101"), "one hundred one");
+        assert_eq!(spell_integer("2000034"), "two million thirty-four");
+        assert_eq!(spell_integer(&"1".repeat(40)).split(' ').count(), 40);
+    }
+
+    #[test]
+    fn test_spell_number_rejects() {
+        for bad in ["", "-", ".", "1.2.3", "abc", "1e5", "NaN"] {
+            assert!(spell_number(bad).is_none(), "{:?}", bad);
+        }
+        assert_eq!(spell_number("-0.0").unwrap(), "zero point zero");
+    }
+
+    #[test]
+    fn test_currency_words() {
+        assert_eq!(currency_words(0.0, "usd").unwrap(), "zero dollars");
+        assert_eq!(currency_words(2.999, "USD").unwrap(), "three dollars");
+        assert_eq!(
+            currency_words(-12.05, "EUR").unwrap(),
+            "minus twelve euros and five cents"
+        );
+        assert_eq!(
+            currency_words(1_000_000.02, "GBP").unwrap(),
+            "one million pounds and two pence"
+        );
+        assert!(currency_words(f64::NAN, "USD").is_err());
+    }
+
+    #[test]
+    fn test_spell_quantity() {
+        assert_eq!(spell_quantity("3.0"), ("three".to_string(), true));
+        assert_eq!(spell_quantity("1.00"), ("one".to_string(), false));
+        assert_eq!(spell_quantity("2.50"), ("two point five".to_string(), true));
+    }
+
+    #[test]
+    fn test_spell_numbers_in() {
+        assert_eq!(
+            spell_numbers_in("1 day, 2 hours and 33.12 seconds"),
+            "one day, two hours and thirty-three point one two seconds"
+        );
+        assert_eq!(
+            spell_numbers_in("1,000 years ago"),
+            "one thousand years ago"
+        );
+        assert_eq!(spell_numbers_in("a moment"), "a moment");
+        assert_eq!(spell_numbers_in("1, 2."), "one, two.");
+    }
+
+    #[test]
+    fn test_verbal_formatters() {
+        use crate::{metric, naturalsize, precisedelta, scientific};
+
+        set_output_style(OutputStyle::Verbal);
+        assert_eq!(
+            naturalsize(3_140_000.0, false, false, "%.2f"),
+            "three point one four megabytes"
+        );
+        assert_eq!(naturalsize(1.0, false, false, "%.1f"), "one byte");
+        assert_eq!(naturalsize(1024.0, false, true, "%.1f"), "one kibibyte");
+        assert_eq!(
+            naturalsize(300.0, true, false, "%.1f"),
+            "three hundred bytes"
+        );
+        assert_eq!(metric(1500.0, "V", 3), "one point five kilovolts");
+        assert_eq!(metric(0.001, "m", 3), "one millimeter");
+        assert_eq!(metric(200_000.0, "", 3), "two hundred kilo");
+        assert_eq!(scientific("1000", 2), "one times ten to the power of three");
+        assert_eq!(
+            scientific("-0.3", 2),
+            "minus three times ten to the power of minus one"
+        );
+        assert_eq!(
+            precisedelta(7500.0, "seconds", &[], "%0.2f"),
+            "two hours and five minutes"
+        );
+        assert_eq!(scientific("inf", 2), "infinity");
+        set_output_style(OutputStyle::Symbolic);
+        assert_eq!(metric(1500.0, "V", 3), "1.50 kV");
+    }
+
+    #[test]
+    fn test_style_is_per_thread() {
+        set_output_style(OutputStyle::Verbal);
+        let other = std::thread::spawn(output_style).join().unwrap();
+        assert_eq!(other, OutputStyle::Symbolic);
+        assert_eq!(output_style(), OutputStyle::Verbal);
+        set_output_style(OutputStyle::Symbolic);
+    }
+}
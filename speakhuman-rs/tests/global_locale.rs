@@ -0,0 +1,44 @@
+//! The global locale is process-wide, so it is tested in its own test binary
+//! where it can't leak into the unit tests running in parallel.
+
+use speakhuman::i18n::{
+    activate, activate_global, deactivate, deactivate_global, thousands_separator,
+};
+use std::path::Path;
+use std::thread;
+
+#[test]
+fn test_global_locale() {
+    let locale_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../src/speakhuman/locale");
+    if activate_global(Some("de_DE"), Some(&locale_path)).is_err() {
+        // .mo files not generated
+        return;
+    }
+
+    // Threads that haven't chosen a locale follow the global one
+    assert_eq!(thousands_separator(), ".");
+    thread::spawn(|| assert_eq!(thousands_separator(), "."))
+        .join()
+        .unwrap();
+
+    // A thread's own choice wins over the global one
+    thread::spawn(|| {
+        deactivate();
+        assert_eq!(thousands_separator(), ",");
+    })
+    .join()
+    .unwrap();
+    let path = locale_path.clone();
+    thread::spawn(move || {
+        activate(Some("fr_FR"), Some(&path)).unwrap();
+        assert_eq!(thousands_separator(), "\u{00a0}");
+    })
+    .join()
+    .unwrap();
+
+    deactivate_global();
+    assert_eq!(thousands_separator(), ",");
+    thread::spawn(|| assert_eq!(thousands_separator(), ","))
+        .join()
+        .unwrap();
+}
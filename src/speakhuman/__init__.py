@@ -2,7 +2,9 @@
 
 from __future__ import annotations
 
-from speakhuman.filesize import naturalsize
+from speakhuman.config import Config
+from speakhuman.filesize import naturalsize, naturalsize_many, naturalsize_parse
+from speakhuman.human import Human
 from speakhuman.i18n import activate, deactivate, decimal_separator, thousands_separator
 from speakhuman.lists import natural_list
 from speakhuman.number import (
@@ -10,6 +12,7 @@ from speakhuman.number import (
     clamp,
     fractional,
     intcomma,
+    intcomma_many,
     intword,
     metric,
     ordinal,
@@ -19,6 +22,8 @@ from speakhuman.time import (
     naturaldate,
     naturalday,
     naturaldelta,
+    naturaldelta_many,
+    naturaldelta_parse,
     naturaltime,
     precisedelta,
 )
@@ -28,6 +33,8 @@ from importlib.metadata import version
 __version__ = version("speakhuman")
 
 __all__ = [
+    "Config",
+    "Human",
     "__version__",
     "activate",
     "apnumber",
@@ -36,13 +43,18 @@ __all__ = [
     "decimal_separator",
     "fractional",
     "intcomma",
+    "intcomma_many",
     "intword",
     "metric",
     "natural_list",
     "naturaldate",
     "naturalday",
     "naturaldelta",
+    "naturaldelta_many",
+    "naturaldelta_parse",
     "naturalsize",
+    "naturalsize_many",
+    "naturalsize_parse",
     "naturaltime",
     "ordinal",
     "precisedelta",
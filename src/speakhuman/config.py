@@ -0,0 +1,145 @@
+"""A reusable set of humanizing options."""
+
+from __future__ import annotations
+
+from .i18n import _CURRENT, activate
+
+TYPE_CHECKING = False
+if TYPE_CHECKING:
+    import datetime as dt
+    from collections.abc import Iterable
+    from typing import Any
+
+__all__ = ["Config"]
+
+
+def _call(locale: str | None, name: str, args: tuple[Any, ...]) -> Any:
+    """Call the package-level function `name` with `locale` active.
+
+    The previously active locale is restored afterwards. If `locale` is `None`,
+    the currently active locale is used.
+    """
+    import speakhuman
+
+    func = getattr(speakhuman, name)
+    if locale is None:
+        return func(*args)
+
+    previous = getattr(_CURRENT, "locale", None)
+    activate(locale)
+    try:
+        return func(*args)
+    finally:
+        _CURRENT.locale = previous
+
+
+class _PyConfig:
+    """Default options for the humanizing functions, applied on every call.
+
+    Create one configured humanizer instead of repeating keyword arguments.
+
+    Examples:
+        ```pycon
+        >>> config = Config(binary=True, minimum_unit="milliseconds")
+        >>> config.naturalsize(3000)
+        '2.9 KiB'
+        >>> config.naturaldelta(0.004)
+        '4 milliseconds'
+
+        ```
+
+    Args:
+        size_format (str): Format used by `naturalsize`.
+        binary (bool): Use binary suffixes in `naturalsize`.
+        gnu (bool): Use GNU-style suffixes in `naturalsize`.
+        intword_format (str): Format used by `intword`.
+        delta_format (str): Format used by `precisedelta`.
+        months (bool): Use months in `naturaldelta` and `naturaltime`.
+        minimum_unit (str): Lowest unit used by the time functions.
+        locale (str, None): Locale activated for each call. If `None`, the
+            currently active locale is used.
+    """
+
+    def __init__(
+        self,
+        *,
+        size_format: str = "%.1f",
+        binary: bool = False,
+        gnu: bool = False,
+        intword_format: str = "%.1f",
+        delta_format: str = "%0.2f",
+        months: bool = True,
+        minimum_unit: str = "seconds",
+        locale: str | None = None,
+    ) -> None:
+        self.size_format = size_format
+        self.binary = binary
+        self.gnu = gnu
+        self.intword_format = intword_format
+        self.delta_format = delta_format
+        self.months = months
+        self.minimum_unit = minimum_unit
+        self.locale = locale
+
+    def __repr__(self) -> str:
+        return (
+            f"Config(size_format={self.size_format!r}, binary={self.binary!r}, "
+            f"gnu={self.gnu!r}, intword_format={self.intword_format!r}, "
+            f"delta_format={self.delta_format!r}, months={self.months!r}, "
+            f"minimum_unit={self.minimum_unit!r}, locale={self.locale!r})"
+        )
+
+    def naturalsize(self, value: float | str) -> str:
+        """Like `naturalsize`, using the configured options."""
+        return _call(
+            self.locale,
+            "naturalsize",
+            (value, self.binary, self.gnu, self.size_format),
+        )
+
+    def intcomma(self, value: float | str, ndigits: int | None = None) -> str:
+        """Like `intcomma`, using the configured locale."""
+        return _call(self.locale, "intcomma", (value, ndigits))
+
+    def intword(self, value: float | str) -> str:
+        """Like `intword`, using the configured options."""
+        return _call(self.locale, "intword", (value, self.intword_format))
+
+    def ordinal(self, value: float | str, gender: str = "male") -> str:
+        """Like `ordinal`, using the configured locale."""
+        return _call(self.locale, "ordinal", (value, gender))
+
+    def naturaldelta(self, value: dt.timedelta | float) -> str:
+        """Like `naturaldelta`, using the configured options."""
+        return _call(
+            self.locale, "naturaldelta", (value, self.months, self.minimum_unit)
+        )
+
+    def naturaltime(
+        self,
+        value: dt.datetime | dt.timedelta | float,
+        future: bool = False,
+        when: dt.datetime | None = None,
+    ) -> str:
+        """Like `naturaltime`, using the configured options."""
+        return _call(
+            self.locale,
+            "naturaltime",
+            (value, future, self.months, self.minimum_unit, when),
+        )
+
+    def precisedelta(
+        self, value: dt.timedelta | float | None, suppress: Iterable[str] = ()
+    ) -> str:
+        """Like `precisedelta`, using the configured options."""
+        return _call(
+            self.locale,
+            "precisedelta",
+            (value, self.minimum_unit, suppress, self.delta_format),
+        )
+
+
+try:
+    from speakhuman._speakhuman_rs import Config
+except ImportError:
+    Config = _PyConfig
@@ -4,6 +4,10 @@ from __future__ import annotations
 
 from math import log
 
+TYPE_CHECKING = False
+if TYPE_CHECKING:
+    from collections.abc import Iterable
+
 suffixes = {
     "decimal": (
         " kB",
@@ -99,7 +103,95 @@ def _py_naturalsize(
     return ret
 
 
+def _py_naturalsize_many(
+    values: Iterable[float | str],
+    binary: bool = False,
+    gnu: bool = False,
+    format: str = "%.1f",
+) -> list[str]:
+    """Format every value in `values` like `naturalsize`.
+
+    Examples:
+        ```pycon
+        >>> naturalsize_many([300, 3000, 3000000])
+        ['300 Bytes', '3.0 kB', '3.0 MB']
+
+        ```
+
+    Args:
+        values (iterable): Integers, floats or numeric strings to convert.
+        binary (bool): Passed to `naturalsize`.
+        gnu (bool): Passed to `naturalsize`.
+        format (str): Passed to `naturalsize`.
+
+    Returns:
+        list[str]: One human readable filesize per input value.
+    """
+    return [_py_naturalsize(value, binary, gnu, format) for value in values]
+
+
+def _py_naturalsize_parse(value: str) -> int:
+    """Parse a human-readable filesize back into a number of bytes.
+
+    This is the inverse of `naturalsize` and understands the same suffixes:
+    decimal (kB, MB), binary (KiB, MiB) and GNU (K, M), as well as bare numbers
+    and B/Byte/Bytes. Suffixes are matched case-insensitively and fractional
+    results are rounded to the nearest byte.
+
+    Examples:
+        ```pycon
+        >>> naturalsize_parse("1.5 GiB")
+        1610612736
+        >>> naturalsize_parse("3.0 MB")
+        3000000
+        >>> naturalsize_parse("2.9K")
+        2970
+        >>> naturalsize_parse("300 Bytes")
+        300
+
+        ```
+
+    Args:
+        value (str): Filesize to parse.
+
+    Returns:
+        int: Number of bytes.
+
+    Raises:
+        ValueError: If `value` is not a filesize.
+    """
+    import decimal
+    import re
+
+    match = re.fullmatch(r"\s*([+-]?(?:\d+\.?\d*|\.\d+))\s*([A-Za-z]*)\s*", value)
+    if match is None:
+        msg = f"Invalid size: {value!r}"
+        raise ValueError(msg)
+    number, suffix = match.groups()
+    suffix = suffix.lower()
+
+    if suffix in ("", "b", "byte", "bytes"):
+        multiplier = 1
+    else:
+        exp = suffixes["gnu"].lower().find(suffix[0]) + 1
+        if exp == 0 or suffix[1:] not in ("", "b", "ib"):
+            msg = f"Invalid size: {value!r}"
+            raise ValueError(msg)
+        multiplier = (1000 if suffix[1:] == "b" else 1024) ** exp
+
+    with decimal.localcontext() as ctx:
+        ctx.prec = 100
+        bytes_ = decimal.Decimal(number) * multiplier
+        return int(bytes_.quantize(decimal.Decimal(1), rounding=decimal.ROUND_HALF_UP))
+
+
 try:
-    from speakhuman._speakhuman_rs import naturalsize
+    from speakhuman._speakhuman_rs import (
+        naturalsize,
+        naturalsize_many,
+        naturalsize_parse,
+    )
 except ImportError:
     naturalsize = _py_naturalsize
+    naturalsize_many = _py_naturalsize_many
+    naturalsize_parse = _py_naturalsize_parse
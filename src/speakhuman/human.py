@@ -0,0 +1,70 @@
+"""Humanize values inline in f-strings and `str.format`."""
+
+from __future__ import annotations
+
+from .config import _PyConfig
+
+TYPE_CHECKING = False
+if TYPE_CHECKING:
+    from typing import Any
+
+__all__ = ["Human"]
+
+
+class _PyHuman:
+    """Wrap a value so that its format spec names a humanizing function.
+
+    The spec selects the function (with its default options); any other spec is
+    passed on to the wrapped value.
+
+    Examples:
+        ```pycon
+        >>> f"{Human(1_000_000):intcomma} users"
+        '1,000,000 users'
+        >>> f"{Human(3_000_000):naturalsize} free"
+        '3.0 MB free'
+        >>> f"{Human(3):ordinal} place"
+        '3rd place'
+        >>> f"{Human(0.5):.0%}"
+        '50%'
+
+        ```
+
+    Args:
+        value (Any): The value to format.
+    """
+
+    _CONFIG_FUNCTIONS = (
+        "naturalsize",
+        "intcomma",
+        "intword",
+        "ordinal",
+        "naturaldelta",
+        "naturaltime",
+        "precisedelta",
+    )
+    _PLAIN_FUNCTIONS = ("fractional", "scientific", "metric")
+
+    def __init__(self, value: Any) -> None:
+        self.value = value
+
+    def __format__(self, spec: str) -> str:
+        if spec in self._CONFIG_FUNCTIONS:
+            return getattr(_PyConfig(), spec)(self.value)
+        if spec in self._PLAIN_FUNCTIONS:
+            import speakhuman
+
+            return getattr(speakhuman, spec)(self.value)
+        return format(self.value, spec)
+
+    def __str__(self) -> str:
+        return str(self.value)
+
+    def __repr__(self) -> str:
+        return f"Human({self.value!r})"
+
+
+try:
+    from speakhuman._speakhuman_rs import Human
+except ImportError:
+    Human = _PyHuman
@@ -11,6 +11,7 @@ from .i18n import _pgettext as P_
 
 TYPE_CHECKING = False
 if TYPE_CHECKING:
+    from collections.abc import Iterable
     from typing import TypeAlias
 
     # This type can be better defined by typing.SupportsFloat
@@ -382,7 +383,13 @@ def _py_fractional(value: NumberOrString) -> str:
     return f"{whole_number:.0f} {numerator:.0f}/{denominator:.0f}"
 
 
-def _py_scientific(value: NumberOrString, precision: int = 2) -> str:
+def _py_scientific(
+    value: NumberOrString,
+    precision: int = 2,
+    *,
+    style: str = "unicode",
+    engineering: bool = False,
+) -> str:
     """Return number in string scientific notation z.wq x 10ⁿ.
 
     Examples:
@@ -403,18 +410,31 @@ def _py_scientific(value: NumberOrString, precision: int = 2) -> str:
         'foo'
         >>> scientific(None)
         'None'
+        >>> scientific(33500, style="ascii")
+        '3.35e4'
+        >>> scientific(0.3, 1, style="html")
+        '3.0 × 10<sup>-1</sup>'
+        >>> scientific(33500, engineering=True)
+        '33.5 x 10³'
 
         ```
 
     Args:
         value (int, float, str): Input number.
         precision (int): Number of decimal for first part of the number.
+        style (str): How to write the power of ten: `"unicode"`, `"ascii"`,
+            `"latex"` or `"html"`.
+        engineering (bool): If `True`, keep the exponent a multiple of three,
+            moving the decimal point of the first part to match.
 
     Returns:
         str: Number in scientific notation z.wq x 10ⁿ.
     """
     import math
 
+    if style not in ("unicode", "ascii", "latex", "html"):
+        msg = f"Unknown style '{style}'"
+        raise ValueError(msg)
     exponents = {
         "0": "⁰",
         "1": "¹",
@@ -442,6 +462,20 @@ def _py_scientific(value: NumberOrString, precision: int = 2) -> str:
 
     part2 = re.sub(r"^\+?(\-?)0*(.+)$", r"\1\2", part2)
 
+    if engineering:
+        from decimal import Decimal
+
+        shift = int(part2) % 3
+        part1 = format(Decimal(part1).scaleb(shift), "f")
+        part2 = str(int(part2) - shift)
+
+    if style == "ascii":
+        return f"{part1}e{part2}"
+    if style == "latex":
+        return f"{part1} \\times 10^{{{part2}}}"
+    if style == "html":
+        return f"{part1} × 10<sup>{part2}</sup>"
+
     new_part2 = []
     for char in part2:
         new_part2.append(exponents[char])
@@ -528,7 +562,21 @@ def clamp(
     raise ValueError(msg)
 
 
-def _py_metric(value: float, unit: str = "", precision: int = 3) -> str:
+# SI prefixes keyed by power of 1000
+_METRIC_PREFIXES = {
+    **dict(zip(range(1, 11), "kMGTPEZYRQ")),
+    **dict(zip(range(-1, -11, -1), "mμnpfazyrq")),
+}
+
+
+def _py_metric(
+    value: float,
+    unit: str = "",
+    precision: int = 3,
+    prefixes: str | None = None,
+    *,
+    grouped: bool = False,
+) -> str:
     """Return a value with a metric SI unit-prefix appended.
 
     Examples:
@@ -541,6 +589,10 @@ def _py_metric(value: float, unit: str = "", precision: int = 3) -> str:
         '220 μF'
         >>> metric(1e-14, precision=4)
         '10.00 f'
+        >>> metric(5e9, "W", prefixes="kM")
+        '5000 MW'
+        >>> metric(5e9, "W", prefixes="kM", grouped=True)
+        '5,000 MW'
 
         ```
 
@@ -559,6 +611,11 @@ def _py_metric(value: float, unit: str = "", precision: int = 3) -> str:
         value (int, float): Input number.
         unit (str): Optional base unit.
         precision (int): The number of digits the output should contain.
+        prefixes (str, None): If given, only these SI prefixes are used (e.g.
+            `"kM"`; `"u"` stands for micro). The value is scaled to the largest
+            allowed prefix that fits, or to the bare unit.
+        grouped (bool): If `True`, put commas between the thousands of the
+            integer digits, for prefix sets that leave the number large.
 
     Returns:
         str:
@@ -572,14 +629,31 @@ def _py_metric(value: float, unit: str = "", precision: int = 3) -> str:
     if exponent >= 33 or exponent < -30:
         return scientific(value, precision - 1) + unit
 
-    value /= 10 ** (exponent // 3 * 3)
-    if exponent >= 3:
-        ordinal_ = "kMGTPEZYRQ"[exponent // 3 - 1]
-    elif exponent < 0:
-        ordinal_ = "mμnpfazyrq"[(-exponent - 1) // 3]
+    power = exponent // 3
+    if prefixes is not None:
+        allowed = [
+            p
+            for p, symbol in _METRIC_PREFIXES.items()
+            if symbol in prefixes or (symbol == "μ" and "u" in prefixes)
+        ] + [0]
+        fitting = [p for p in allowed if p <= power]
+        power = max(fitting) if fitting else min(allowed)
+
+    value /= 10 ** (power * 3)
+    ordinal_ = _METRIC_PREFIXES.get(power, "")
+    digits = exponent - power * 3 + 1
+    if digits > max(3, precision):
+        # Round in decimal, or the float noise past `precision` gets printed
+        from decimal import Decimal
+
+        value_ = str(int(Decimal(format(value, f".{max(precision - 1, 0)}e"))))
     else:
-        ordinal_ = ""
-    value_ = format(value, f".{int(max(0, precision - exponent % 3 - 1))}f")
+        value_ = format(value, f".{int(max(0, precision - digits))}f")
+    if grouped:
+        import re
+
+        sign, whole, rest = re.fullmatch(r"(-?)(\d+)(.*)", value_).groups()
+        value_ = f"{sign}{int(whole):,}{rest}"
     if not (unit or ordinal_) or unit in ("°", "′", "″"):
         space = ""
     else:
@@ -599,11 +673,34 @@ def _is_english_locale() -> bool:
     return locale is None or str(locale).startswith("en")
 
 
+def _py_intcomma_many(
+    values: Iterable[NumberOrString], ndigits: int | None = None
+) -> list[str]:
+    """Format every value in `values` like `intcomma`.
+
+    Examples:
+        ```pycon
+        >>> intcomma_many([100, 1000, "1000000"])
+        ['100', '1,000', '1,000,000']
+
+        ```
+
+    Args:
+        values (iterable): Integers, floats or numeric strings to convert.
+        ndigits (int, None): Passed to `intcomma`.
+
+    Returns:
+        list[str]: One comma-separated string per input value.
+    """
+    return [_py_intcomma(value, ndigits) for value in values]
+
+
 try:
     from speakhuman._speakhuman_rs import (
         apnumber as _rs_apnumber,
         fractional,
         intcomma as _rs_intcomma,
+        intcomma_many as _rs_intcomma_many,
         intword as _rs_intword,
         metric,
         ordinal as _rs_ordinal,
@@ -620,6 +717,17 @@ try:
             return _rs_intcomma(value, ndigits)
         return _py_intcomma(value, ndigits)
 
+    def intcomma_many(  # noqa: D103
+        values: Iterable[NumberOrString], ndigits: int | None = None
+    ) -> list[str]:
+        if not _is_english_locale():
+            return _py_intcomma_many(values, ndigits)
+        values = list(values)
+        try:
+            return _rs_intcomma_many(values, ndigits)
+        except (TypeError, ValueError):
+            return _py_intcomma_many(values, ndigits)
+
     def intword(value: NumberOrString, format: str = "%.1f") -> str:  # noqa: D103
         if _is_english_locale():
             return _rs_intword(value, format)
@@ -633,6 +741,7 @@ try:
 except ImportError:
     ordinal = _py_ordinal
     intcomma = _py_intcomma
+    intcomma_many = _py_intcomma_many
     intword = _py_intword
     apnumber = _py_apnumber
     fractional = _py_fractional
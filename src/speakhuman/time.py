@@ -22,6 +22,8 @@ __all__ = [
     "naturaldate",
     "naturalday",
     "naturaldelta",
+    "naturaldelta_many",
+    "naturaldelta_parse",
     "naturaltime",
     "precisedelta",
 ]
@@ -246,7 +248,7 @@ def _py_naturaldelta(
     return _ngettext("%d year", "%d years", years).replace("%d", "%s") % intcomma(years)
 
 
-def naturaltime(
+def _py_naturaltime(
     value: dt.datetime | dt.timedelta | float,
     future: bool = False,
     months: bool = True,
@@ -318,6 +320,7 @@ def _py_naturalday(value: dt.date | dt.datetime, format: str = "%b %d") -> str:
     """
     import datetime as dt
 
+    value = _convert_aware_datetime(value)
     try:
         value = dt.date(value.year, value.month, value.day)
     except AttributeError:
@@ -344,6 +347,7 @@ def _py_naturaldate(value: dt.date | dt.datetime) -> str:
     """Like `naturalday`, but append a year for dates more than ~five months away."""
     import datetime as dt
 
+    value = _convert_aware_datetime(value)
     try:
         value = dt.date(value.year, value.month, value.day)
     except AttributeError:
@@ -687,14 +691,123 @@ def _is_english_locale() -> bool:
     return locale is None or str(locale).startswith("en")
 
 
-# naturalday and naturaldate always use Python because they compare against
-# dt.date.today() which must be mockable (e.g. by freezegun in tests).
-naturalday = _py_naturalday
-naturaldate = _py_naturaldate
+def _py_naturaldelta_many(
+    values: Iterable[dt.timedelta | float],
+    months: bool = True,
+    minimum_unit: str = "seconds",
+) -> list[str]:
+    """Format every timedelta or number of seconds in `values` like `naturaldelta`.
+
+    Examples:
+        ```pycon
+        >>> naturaldelta_many([1, 30, 3600])
+        ['a second', '30 seconds', 'an hour']
+
+        ```
+
+    Args:
+        values (iterable): Timedeltas or numbers of seconds.
+        months (bool): Passed to `naturaldelta`.
+        minimum_unit (str): Passed to `naturaldelta`.
+
+    Returns:
+        list[str]: One natural representation per input value.
+    """
+    return [_py_naturaldelta(value, months, minimum_unit) for value in values]
+
+
+_DELTA_UNIT_MICROSECONDS = {
+    "microsecond": 1,
+    "millisecond": 1_000,
+    "second": 1_000_000,
+    "minute": 60_000_000,
+    "hour": 3_600_000_000,
+    "day": 86_400_000_000,
+    "week": 604_800_000_000,
+    "month": 2_635_200_000_000,
+    "year": 31_536_000_000_000,
+}
+
+
+def _py_naturaldelta_parse(value: str) -> dt.timedelta:
+    """Parse the output of `naturaldelta`, `precisedelta` or `naturaltime`.
+
+    This is the inverse mapping of the formatters: "X ago" gives a positive
+    timedelta, "X from now" a negative one, and "now" or "a moment" zero. A
+    month is 30.5 days and a year 365 days, as when formatting, and fractional
+    amounts are rounded to the nearest microsecond. Only the English forms are
+    understood.
+
+    Examples:
+        ```pycon
+        >>> naturaldelta_parse("2 hours ago")
+        datetime.timedelta(seconds=7200)
+        >>> naturaldelta_parse("a day from now")
+        datetime.timedelta(days=-1)
+        >>> naturaldelta_parse("1 minute and 30.50 seconds")
+        datetime.timedelta(seconds=90, microseconds=500000)
+
+        ```
+
+    Args:
+        value (str): Natural delta or time to parse.
+
+    Returns:
+        datetime.timedelta: The parsed duration.
+
+    Raises:
+        ValueError: If `value` is not a natural delta or time.
+    """
+    import datetime as dt
+    import decimal
+    import re
+
+    def invalid() -> ValueError:
+        return ValueError(f"Invalid delta: {value!r}")
+
+    text = " ".join(value.split())
+    sign = 1
+    if text.lower() == "now":
+        return dt.timedelta(0)
+    if text.lower().endswith(" ago"):
+        text = text[: -len(" ago")]
+    elif text.lower().endswith(" from now"):
+        text = text[: -len(" from now")]
+        sign = -1
+    if text.lower() == "a moment":
+        return dt.timedelta(0)
+
+    total = 0
+    for part in re.split(", | and ", text):
+        match = re.fullmatch(r"(\S+) (.+)", part)
+        if match is None:
+            raise invalid()
+        amount, unit = match.groups()
+        multiplier = _DELTA_UNIT_MICROSECONDS.get(unit.lower().removesuffix("s"))
+        if multiplier is None:
+            raise invalid()
+        if amount.lower() in ("a", "an"):
+            total += multiplier
+            continue
+        amount = amount.replace(",", "").replace("_", "")
+        if not re.fullmatch(r"\d+\.?\d*|\.\d+", amount):
+            raise invalid()
+        with decimal.localcontext() as ctx:
+            ctx.prec = 100
+            micros = decimal.Decimal(amount) * multiplier
+            total += int(micros.quantize(decimal.Decimal(1), decimal.ROUND_HALF_UP))
+
+    return dt.timedelta(microseconds=sign * total)
+
 
 try:
     from speakhuman._speakhuman_rs import (
         naturaldelta as _rs_naturaldelta,
+        naturaldelta_many as _rs_naturaldelta_many,
+        naturaldelta_parse,
+        naturaldate as _rs_naturaldate,
+        naturalday as _rs_naturalday,
+        naturaltime as _rs_naturaltime,
         precisedelta as _rs_precisedelta,
     )
 
@@ -705,15 +818,24 @@ try:
     ) -> str:
         if not _is_english_locale():
             return _py_naturaldelta(value, months, minimum_unit)
-        # Fall back to Python for non-standard minimum_unit (Rust returns
-        # error strings instead of raising ValueError).
-        if minimum_unit not in ("seconds", "milliseconds", "microseconds"):
-            return _py_naturaldelta(value, months, minimum_unit)
         try:
             return _rs_naturaldelta(value, months, minimum_unit)
         except (TypeError, OverflowError):
             return _py_naturaldelta(value, months, minimum_unit)
 
+    def naturaldelta_many(  # noqa: D103
+        values: Iterable[dt.timedelta | float],
+        months: bool = True,
+        minimum_unit: str = "seconds",
+    ) -> list[str]:
+        if not _is_english_locale():
+            return _py_naturaldelta_many(values, months, minimum_unit)
+        values = list(values)
+        try:
+            return _rs_naturaldelta_many(values, months, minimum_unit)
+        except (TypeError, OverflowError):
+            return _py_naturaldelta_many(values, months, minimum_unit)
+
     def precisedelta(  # noqa: D103
         value: dt.timedelta | float | None,
         minimum_unit: str = "seconds",
@@ -722,15 +844,49 @@ try:
     ) -> str:
         if not _is_english_locale():
             return _py_precisedelta(value, minimum_unit, suppress, format)
-        # Fall back to Python when suppress is used, since Rust returns error
-        # strings instead of raising ValueError for bogus combinations.
-        if suppress:
-            return _py_precisedelta(value, minimum_unit, suppress, format)
         try:
             return _rs_precisedelta(value, minimum_unit, list(suppress), format)
         except (TypeError, OverflowError):
             return _py_precisedelta(value, minimum_unit, suppress, format)
 
+    # The current time is read in Python and passed in, so that it can be
+    # mocked (e.g. by freezegun in tests)
+
+    def naturaltime(  # noqa: D103
+        value: dt.datetime | dt.timedelta | float,
+        future: bool = False,
+        months: bool = True,
+        minimum_unit: str = "seconds",
+        when: dt.datetime | None = None,
+    ) -> str:
+        if not _is_english_locale():
+            return _py_naturaltime(value, future, months, minimum_unit, when)
+        try:
+            return _rs_naturaltime(value, future, months, minimum_unit, when or _now())
+        except (TypeError, OverflowError):
+            return _py_naturaltime(value, future, months, minimum_unit, when)
+
+    def naturalday(  # noqa: D103
+        value: dt.date | dt.datetime, format: str = "%b %d"
+    ) -> str:
+        import datetime as dt
+
+        if not _is_english_locale():
+            return _py_naturalday(value, format)
+        return _rs_naturalday(value, format, today=dt.date.today())
+
+    def naturaldate(value: dt.date | dt.datetime) -> str:  # noqa: D103
+        import datetime as dt
+
+        if not _is_english_locale():
+            return _py_naturaldate(value)
+        return _rs_naturaldate(value, today=dt.date.today())
+
 except ImportError:
+    naturaltime = _py_naturaltime
+    naturalday = _py_naturalday
+    naturaldate = _py_naturaldate
     naturaldelta = _py_naturaldelta
+    naturaldelta_many = _py_naturaldelta_many
+    naturaldelta_parse = _py_naturaldelta_parse
     precisedelta = _py_precisedelta
@@ -0,0 +1,57 @@
+"""Tests for the reusable Config object."""
+
+from __future__ import annotations
+
+import datetime as dt
+
+import pytest
+
+import speakhuman
+from speakhuman import config
+
+
+@pytest.mark.parametrize("cls", [speakhuman.Config, config._PyConfig])
+def test_config_defaults(cls: type) -> None:
+    humanizer = cls()
+
+    assert humanizer.naturalsize(3000) == speakhuman.naturalsize(3000)
+    assert humanizer.intcomma(1_000_000) == "1,000,000"
+    assert humanizer.intword(1_234_567) == "1.2 million"
+    assert humanizer.ordinal(3) == "3rd"
+    assert humanizer.naturaldelta(dt.timedelta(hours=2)) == "2 hours"
+    assert humanizer.precisedelta(3.5) == "3.50 seconds"
+
+
+@pytest.mark.parametrize("cls", [speakhuman.Config, config._PyConfig])
+def test_config_options(cls: type) -> None:
+    humanizer = cls(
+        binary=True,
+        size_format="%.3f",
+        intword_format="%.2f",
+        delta_format="%0.1f",
+        minimum_unit="milliseconds",
+    )
+
+    assert humanizer.naturalsize(3000) == "2.930 KiB"
+    assert humanizer.intword(1_234_567) == "1.23 million"
+    assert humanizer.naturaldelta(0.004) == "4 milliseconds"
+    assert humanizer.precisedelta(3.5) == "3 seconds and 500 milliseconds"
+    assert humanizer.naturaltime(dt.timedelta(seconds=3)) == "3 seconds ago"
+
+    humanizer.gnu = True
+    assert humanizer.naturalsize(3000) == "2.930K"
+    assert "gnu=True" in repr(humanizer)
+
+
+@pytest.mark.parametrize("cls", [speakhuman.Config, config._PyConfig])
+def test_config_locale(cls: type) -> None:
+    humanizer = cls(locale="fr_FR")
+
+    try:
+        assert humanizer.intword(1_234_567) == "1.2 millions"
+        assert humanizer.naturaldelta(dt.timedelta(hours=2)) == "2 heures"
+    except FileNotFoundError:
+        pytest.skip("Generate .mo with scripts/generate-translation-binaries.sh")
+
+    # The locale only applies to the call
+    assert speakhuman.intword(1_234_567) == "1.2 million"
@@ -6,6 +6,7 @@ from __future__ import annotations
 import pytest
 
 import speakhuman
+from speakhuman import filesize
 
 
 @pytest.mark.parametrize(
@@ -93,3 +94,49 @@ def test_naturalsize(test_args: list[int] | list[int | bool], expected: str) ->
         test_args[0] = f"-{test_args[0]}"
 
     assert speakhuman.naturalsize(*test_args) == "-" + expected
+
+
+def test_naturalsize_many() -> None:
+    values = [300, 3000, "3000000", 10**28]
+    assert speakhuman.naturalsize_many(values) == [
+        speakhuman.naturalsize(value) for value in values
+    ]
+    assert speakhuman.naturalsize_many(iter([3000]), binary=True) == ["2.9 KiB"]
+    assert speakhuman.naturalsize_many([]) == []
+
+
+@pytest.mark.parametrize(
+    "test_input, expected",
+    [
+        ("300", 300),
+        ("1 Byte", 1),
+        ("300B", 300),
+        (" 1.0 kB ", 1000),
+        ("1kb", 1000),
+        ("31.0 KiB", 31 * 1024),
+        ("1.5 GiB", 1_610_612_736),
+        ("1.0K", 1024),
+        ("-4.0 KiB", -4096),
+        ("1.0 QB", 10**30),
+        ("0.0005 kB", 1),
+        (".5 KiB", 512),
+    ],
+)
+def test_naturalsize_parse(test_input: str, expected: int) -> None:
+    assert speakhuman.naturalsize_parse(test_input) == expected
+    assert filesize._py_naturalsize_parse(test_input) == expected
+
+
+@pytest.mark.parametrize(
+    "test_input", ["", "kB", "1.2.3 kB", "12 furlongs", "1 XB", "1 KiBB", "1e3 kB"]
+)
+def test_naturalsize_parse_invalid(test_input: str) -> None:
+    with pytest.raises(ValueError, match="Invalid size"):
+        speakhuman.naturalsize_parse(test_input)
+    with pytest.raises(ValueError, match="Invalid size"):
+        filesize._py_naturalsize_parse(test_input)
+
+
+@pytest.mark.parametrize("value", [300, 3000, 3_000_000, 10**12])
+def test_naturalsize_parse_round_trip(value: int) -> None:
+    assert speakhuman.naturalsize_parse(speakhuman.naturalsize(value)) == value
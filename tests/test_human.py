@@ -0,0 +1,46 @@
+"""Tests for the Human format wrapper."""
+
+from __future__ import annotations
+
+import datetime as dt
+
+import pytest
+
+import speakhuman
+from speakhuman import human
+
+
+@pytest.mark.parametrize("cls", [speakhuman.Human, human._PyHuman])
+@pytest.mark.parametrize(
+    "spec, value, expected",
+    [
+        ("naturalsize", 3_000_000, "3.0 MB"),
+        ("intcomma", 1_234_567, "1,234,567"),
+        ("intword", 1_234_567, "1.2 million"),
+        ("ordinal", 22, "22nd"),
+        ("naturaldelta", dt.timedelta(hours=2), "2 hours"),
+        ("precisedelta", dt.timedelta(seconds=90), "1 minute and 30 seconds"),
+        ("fractional", 1.5, "1 1/2"),
+        ("scientific", 500, "5.00 x 10²"),
+        ("metric", 1500, "1.50 k"),
+        ("", 1234, "1234"),
+        (">6", 42, "    42"),
+        (".1%", 0.25, "25.0%"),
+    ],
+)
+def test_human_format(cls: type, spec: str, value: object, expected: str) -> None:
+    assert format(cls(value), spec) == expected
+    assert f"{cls(value):{spec}}" == expected
+
+
+@pytest.mark.parametrize("cls", [speakhuman.Human, human._PyHuman])
+def test_human_wrapper(cls: type) -> None:
+    wrapped = cls(1000)
+
+    assert wrapped.value == 1000
+    assert str(wrapped) == "1000"
+    assert repr(wrapped) == "Human(1000)"
+    assert "{:intcomma} files".format(wrapped) == "1,000 files"
+
+    with pytest.raises(ValueError):
+        format(wrapped, "not-a-formatter")
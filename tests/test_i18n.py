@@ -69,6 +69,27 @@ def test_intcomma() -> None:
         assert speakhuman.intcomma(number) == "10,000,000"
 
 
+def test_native_separators() -> None:
+    native = pytest.importorskip("speakhuman._speakhuman_rs")
+
+    speakhuman.i18n.deactivate()
+    assert native.thousands_separator() == speakhuman.thousands_separator()
+    assert native.decimal_separator() == speakhuman.decimal_separator()
+
+    try:
+        speakhuman.i18n.activate("pt_BR")
+        assert native.thousands_separator() == "."
+        assert native.decimal_separator() == ","
+
+    except FileNotFoundError:
+        pytest.skip("Generate .mo with scripts/generate-translation-binaries.sh")
+
+    finally:
+        speakhuman.i18n.deactivate()
+        assert native.thousands_separator() == ","
+        assert native.decimal_separator() == "."
+
+
 def test_naturaldelta() -> None:
     seconds = 1234 * 365 * 24 * 60 * 60
 
@@ -83,6 +83,14 @@ def test_intcomma(
     assert speakhuman.intcomma(*test_args) == expected
 
 
+def test_intcomma_many() -> None:
+    values = [100, "1000", 1_234_567.25, 10**30, None]
+    assert speakhuman.intcomma_many(values) == [
+        speakhuman.intcomma(value) for value in values
+    ]
+    assert speakhuman.intcomma_many(iter([1234.5454545]), 2) == ["1,234.55"]
+
+
 def test_intword_powers() -> None:
     # make sure that powers & human_powers have the same number of items
     assert len(number.powers) == len(number.human_powers)
@@ -220,6 +228,39 @@ def test_scientific(test_args: list[typing.Any], expected: str) -> None:
     assert speakhuman.scientific(*test_args) == expected
 
 
+@pytest.mark.parametrize(
+    "test_args, kwargs, expected",
+    [
+        ([33500], {"style": "ascii"}, "3.35e4"),
+        ([33500], {"style": "latex"}, "3.35 \\times 10^{4}"),
+        ([0.3, 1], {"style": "html"}, "3.0 × 10<sup>-1</sup>"),
+        ([-1000], {"style": "ascii"}, "-1.00e3"),
+        ([33500], {"engineering": True}, "33.5 x 10³"),
+        ([335000], {"engineering": True}, "335 x 10³"),
+        ([1000], {"engineering": True}, "1.00 x 10³"),
+        ([0.0025], {"engineering": True}, "2.50 x 10⁻³"),
+        ([0.025], {"engineering": True}, "25.0 x 10⁻³"),
+        ([33500, 0], {"engineering": True}, "30 x 10³"),
+        ([33500], {"engineering": True, "style": "ascii"}, "33.5e3"),
+        ([math.nan], {"style": "ascii"}, "NaN"),
+        (["foo"], {"engineering": True}, "foo"),
+    ],
+    ids=str,
+)
+def test_scientific_styles(
+    test_args: list[typing.Any], kwargs: dict[str, typing.Any], expected: str
+) -> None:
+    assert speakhuman.scientific(*test_args, **kwargs) == expected
+    assert number._py_scientific(*test_args, **kwargs) == expected
+
+
+def test_scientific_unknown_style() -> None:
+    with pytest.raises(ValueError, match="Unknown style 'markdown'"):
+        speakhuman.scientific(1000, style="markdown")
+    with pytest.raises(ValueError, match="Unknown style 'markdown'"):
+        number._py_scientific(1000, style="markdown")
+
+
 @pytest.mark.parametrize(
     "test_args, expected",
     [
@@ -307,3 +348,44 @@ def test_clamp(test_args: list[typing.Any], expected: str) -> None:
 )
 def test_metric(test_args: list[typing.Any], expected: str) -> None:
     assert speakhuman.metric(*test_args) == expected
+
+
+@pytest.mark.parametrize(
+    "test_args, expected",
+    [
+        ([1500, "V", 3, "kM"], "1.50 kV"),
+        ([5e9, "W", 3, "kM"], "5000 MW"),
+        ([2e8, "B", 3, ""], "200000000 B"),
+        ([0.0025, "m", 3, "k"], "0.00250 m"),
+        ([220e-6, "F", 3, "m"], "0.220 mF"),
+        ([220e-6, "F", 3, "u"], "220 μF"),
+        ([220e-6, "F", 3, "μ"], "220 μF"),
+        ([1e-12, "F", 3, "μ"], "0.00000100 μF"),
+        ([1e40, "", 3, "k"], "1.00 x 10⁴⁰"),
+        ([1e30, "V", 3, "kM"], "1000000000000000000000000 MV"),
+        ([1.23456e20, "V", 3, "kM"], "123000000000000 MV"),
+        ([12345, "B", 2, ""], "12000 B"),
+    ],
+    ids=str,
+)
+def test_metric_prefixes(test_args: list[typing.Any], expected: str) -> None:
+    assert speakhuman.metric(*test_args) == expected
+    assert number._py_metric(*test_args) == expected
+
+
+@pytest.mark.parametrize(
+    "test_args, expected",
+    [
+        ([5e9, "W", 3, "kM"], "5,000 MW"),
+        ([2e8, "B", 3, ""], "200,000,000 B"),
+        ([1e30, "V", 3, "kM"], "1,000,000,000,000,000,000,000,000 MV"),
+        ([-12345, "B", 2, ""], "-12,000 B"),
+        ([1500, "V", 3, "kM"], "1.50 kV"),
+        ([1500, "V", 3, None], "1.50 kV"),
+        ([-0.0025, "m", 3, "k"], "-0.00250 m"),
+    ],
+    ids=str,
+)
+def test_metric_grouped(test_args: list[typing.Any], expected: str) -> None:
+    assert speakhuman.metric(*test_args, grouped=True) == expected
+    assert number._py_metric(*test_args, grouped=True) == expected
@@ -0,0 +1,63 @@
+"""Stress tests calling the formatters from many threads at once.
+
+These matter most on free-threaded CPython, where the threads really do run in
+parallel.
+"""
+
+from __future__ import annotations
+
+import datetime as dt
+from concurrent.futures import ThreadPoolExecutor
+
+import pytest
+
+import speakhuman
+
+THREADS = 16
+ROUNDS = 200
+
+
+def _format_all(i: int) -> list[str]:
+    return [
+        speakhuman.naturalsize(i * 1_000_003),
+        speakhuman.intcomma(i * 1_000_003),
+        speakhuman.intword(i * 1_000_003_000),
+        speakhuman.ordinal(i),
+        speakhuman.naturaldelta(dt.timedelta(seconds=i * 37)),
+        speakhuman.precisedelta(dt.timedelta(seconds=i * 37)),
+        speakhuman.scientific(i * 1.5),
+        speakhuman.metric(i * 1.5, "V"),
+        *speakhuman.naturalsize_many(range(i, i + 5)),
+    ]
+
+
+def test_formatters_from_many_threads() -> None:
+    expected = [_format_all(i) for i in range(ROUNDS)]
+
+    with ThreadPoolExecutor(max_workers=THREADS) as pool:
+        for _ in range(THREADS):
+            assert list(pool.map(_format_all, range(ROUNDS))) == expected
+
+
+def test_locale_per_thread() -> None:
+    def format_in(locale: str | None) -> list[str]:
+        speakhuman.activate(locale)
+        try:
+            return [speakhuman.intcomma(1_234_567.5) for _ in range(ROUNDS)]
+        finally:
+            speakhuman.deactivate()
+
+    try:
+        speakhuman.activate("de_DE")
+    except FileNotFoundError:
+        pytest.skip("Generate .mo with scripts/generate-translation-binaries.sh")
+    finally:
+        speakhuman.deactivate()
+
+    locales = ["de_DE", None] * (THREADS // 2)
+    with ThreadPoolExecutor(max_workers=THREADS) as pool:
+        results = list(pool.map(format_in, locales))
+
+    for locale, result in zip(locales, results):
+        expected = "1.234.567,5" if locale == "de_DE" else "1,234,567.5"
+        assert result == [expected] * ROUNDS
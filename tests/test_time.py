@@ -138,6 +138,52 @@ def test_naturaldelta(test_input: float | dt.timedelta, expected: str) -> None:
         assert speakhuman.naturaldelta(-test_input) == expected
 
 
+def test_naturaldelta_many() -> None:
+    values = [1, 90.0, dt.timedelta(hours=1), dt.timedelta(days=400)]
+    assert speakhuman.naturaldelta_many(values) == [
+        speakhuman.naturaldelta(value) for value in values
+    ]
+    assert speakhuman.naturaldelta_many(
+        iter([dt.timedelta(milliseconds=4)]), minimum_unit="milliseconds"
+    ) == ["4 milliseconds"]
+
+
+@pytest.mark.parametrize(
+    "test_input, expected",
+    [
+        ("now", dt.timedelta(0)),
+        ("a moment", dt.timedelta(0)),
+        ("a second ago", dt.timedelta(seconds=1)),
+        ("30 seconds from now", dt.timedelta(seconds=-30)),
+        ("an hour", dt.timedelta(hours=1)),
+        ("1 year, 4 months ago", dt.timedelta(days=365 + 122)),
+        ("1,000 years", dt.timedelta(days=365_000)),
+        ("2 days, 1 hour and 33.12 seconds", dt.timedelta(2, 3633, 120_000)),
+        ("4 milliseconds", dt.timedelta(milliseconds=4)),
+        (" 2  Hours AGO ", dt.timedelta(hours=2)),
+    ],
+)
+def test_naturaldelta_parse(test_input: str, expected: dt.timedelta) -> None:
+    assert speakhuman.naturaldelta_parse(test_input) == expected
+    assert time._py_naturaldelta_parse(test_input) == expected
+
+
+@pytest.mark.parametrize(
+    "test_input", ["", "ago", "2", "two hours", "-2 hours", "2 fortnights", "2 hours,"]
+)
+def test_naturaldelta_parse_invalid(test_input: str) -> None:
+    with pytest.raises(ValueError, match="Invalid delta"):
+        speakhuman.naturaldelta_parse(test_input)
+    with pytest.raises(ValueError, match="Invalid delta"):
+        time._py_naturaldelta_parse(test_input)
+
+
+@pytest.mark.parametrize("seconds", [1, 30, 90, 3600, 86_400, 864_000, 5_000_000])
+def test_naturaldelta_parse_round_trip(seconds: int) -> None:
+    text = speakhuman.naturaltime(dt.timedelta(seconds=seconds))
+    assert speakhuman.naturaltime(speakhuman.naturaldelta_parse(text)) == text
+
+
 @freeze_time(FROZEN_DATE)
 @pytest.mark.parametrize(
     "test_input, expected",
@@ -300,6 +346,29 @@ def test_naturaldate(test_input: dt.date, expected: str) -> None:
     assert speakhuman.naturaldate(test_input) == expected
 
 
+def test_aware_datetimes_use_the_local_day() -> None:
+    # An aware datetime names the local calendar day of the same instant, here
+    # one whose own date is a day off from the local one
+    local_now = dt.datetime.now().astimezone()
+    shift = dt.timedelta(hours=12 if local_now.hour >= 12 else -12)
+    offset = typing.cast("dt.timedelta", local_now.utcoffset()) + shift
+    if abs(offset) >= dt.timedelta(hours=24):
+        pytest.skip("No UTC offset puts this instant on another day")
+    value = local_now.astimezone(dt.timezone(offset))
+    assert value.utcoffset() != dt.timedelta(0)
+    assert value.date() != local_now.date()
+
+    for naturalday in (speakhuman.naturalday, time._py_naturalday):
+        assert naturalday(value) == "today"
+    for naturaldate in (speakhuman.naturaldate, time._py_naturaldate):
+        assert naturaldate(value) == "today"
+    for naturaltime in (speakhuman.naturaltime, time._py_naturaltime):
+        assert naturaltime(value - dt.timedelta(hours=2)) == "2 hours ago"
+        assert naturaltime(value, when=value + dt.timedelta(minutes=5)) == (
+            "5 minutes ago"
+        )
+
+
 @pytest.mark.parametrize(
     "seconds, expected",
     [
@@ -802,6 +871,34 @@ def test_precisedelta_bogus_call() -> None:
         speakhuman.naturaldelta(1, minimum_unit="years")
 
 
+def test_bogus_units() -> None:
+    with pytest.raises(KeyError):
+        speakhuman.naturaldelta(1, minimum_unit="fortnights")
+    with pytest.raises(KeyError):
+        speakhuman.precisedelta(1, suppress=["fortnights"])
+    with pytest.raises(KeyError):
+        speakhuman.precisedelta(1, minimum_unit="fortnights")
+    with pytest.raises(ValueError, match="Minimum unit 'hours' not supported"):
+        speakhuman.naturaldelta_many([1], minimum_unit="hours")
+    with pytest.raises(ValueError, match="Minimum unit 'days' not supported"):
+        speakhuman.naturaltime(1, minimum_unit="days")
+
+
+@pytest.mark.parametrize("unit", ["weeks", "ms", "sec"])
+def test_units_python_does_not_know(unit: str) -> None:
+    # The native functions take the same unit names as the Python ones
+    for naturaldelta in (speakhuman.naturaldelta, time._py_naturaldelta):
+        with pytest.raises(KeyError):
+            naturaldelta(1, minimum_unit=unit)
+    for precisedelta in (speakhuman.precisedelta, time._py_precisedelta):
+        with pytest.raises(KeyError):
+            precisedelta(1, minimum_unit=unit)
+        with pytest.raises(KeyError):
+            precisedelta(1, suppress=[unit])
+    with pytest.raises(KeyError):
+        speakhuman.naturaldelta_many([1], minimum_unit=unit)
+
+
 def test_time_unit() -> None:
     years, minutes = time.Unit["YEARS"], time.Unit["MINUTES"]
     assert minutes < years